@@ -31,29 +31,86 @@ use crate::handlers::{
         crate::handlers::home::get_home::exec,
         crate::handlers::artists::get_artist_publications::exec,
         crate::handlers::artists::create_artists::exec,
+        crate::handlers::artists::edit_artist::exec,
         crate::handlers::affiliated_artists::create_affiliated_artists::exec,
         crate::handlers::affiliated_artists::remove_affiliated_artists::exec,
         crate::handlers::torrents::download_dottorrent_file::exec,
         crate::handlers::torrents::create_torrent::exec,
+        crate::handlers::torrents::cross_seed_torrent::exec,
         crate::handlers::torrents::edit_torrent::exec,
+        crate::handlers::torrents::move_torrent::exec,
         crate::handlers::torrents::get_registered_torrents::exec,
         crate::handlers::torrents::get_upload_information::exec,
         crate::handlers::torrents::get_top_torrents::exec,
         crate::handlers::torrents::delete_torrent::exec,
         crate::handlers::torrents::create_torrent_report::exec,
+        crate::handlers::torrents::get_torrent_reports::exec,
+        crate::handlers::torrents::claim_torrent_report::exec,
+        crate::handlers::torrents::resolve_torrent_report::exec,
+        crate::handlers::torrents::parse_mediainfo::exec,
+        crate::handlers::torrents::get_torrent_logs::exec,
+        crate::handlers::torrents::get_torrent_review_queue::exec,
+        crate::handlers::torrents::create_torrent_review::exec,
+        crate::handlers::torrents::get_torrent_reviewer_stats::exec,
+        crate::handlers::torrents::trump_torrent::exec,
+        crate::handlers::torrents::get_trump_rules::exec,
+        crate::handlers::torrents::edit_trump_rule::exec,
+        crate::handlers::torrent_clients::create_torrent_client::exec,
+        crate::handlers::torrent_clients::get_torrent_clients::exec,
+        crate::handlers::torrent_clients::edit_torrent_client::exec,
+        crate::handlers::torrent_clients::remove_torrent_client::exec,
+        crate::handlers::torrent_clients::get_seen_torrent_clients::exec,
+        crate::handlers::tracker_flags::get_tracker_flags::exec,
+        crate::handlers::tracker_flags::resolve_tracker_flag::exec,
+        crate::handlers::ip_bans::create_ip_ban::exec,
+        crate::handlers::ip_bans::get_ip_bans::exec,
+        crate::handlers::ip_bans::remove_ip_ban::exec,
+        crate::handlers::upload_rules::create_upload_rule::exec,
+        crate::handlers::upload_rules::get_upload_rules::exec,
+        crate::handlers::upload_rules::remove_upload_rule::exec,
         crate::handlers::edition_groups::create_edition_group::exec,
+        crate::handlers::edition_groups::edit_edition_group::exec,
+        crate::handlers::edition_groups::split_edition_group::exec,
         crate::handlers::invitations::create_invitation::exec,
         crate::handlers::master_groups::create_master_group::exec,
         crate::handlers::series::create_series::exec,
         crate::handlers::series::get_series::exec,
+        crate::handlers::series::edit_series::exec,
+        crate::handlers::revisions::get_revisions::exec,
+        crate::handlers::revisions::revert_revision::exec,
+        crate::handlers::site_logs::get_site_logs::exec,
+        crate::handlers::saved_torrent_searches::create_saved_torrent_search::exec,
+        crate::handlers::saved_torrent_searches::get_saved_torrent_searches::exec,
+        crate::handlers::saved_torrent_searches::remove_saved_torrent_search::exec,
+        crate::handlers::feeds::get_torrents_feed::exec,
+        crate::handlers::feeds::get_subscriptions_feed::exec,
+        crate::handlers::feeds::get_bookmarks_feed::exec,
+        crate::handlers::feeds::get_notifications_feed::exec,
+        crate::handlers::feeds::get_saved_torrent_search_feed::exec,
+        crate::handlers::feeds::download_feed_torrent::exec,
+        crate::handlers::torznab::get_torznab::exec,
+        crate::handlers::notification_filters::create_notification_filter::exec,
+        crate::handlers::notification_filters::get_notification_filters::exec,
+        crate::handlers::notification_filters::remove_notification_filter::exec,
+        crate::handlers::notifications::get_notifications::exec,
+        crate::handlers::notifications::get_notifications_stream::exec,
+        crate::handlers::notifications::remove_notification::exec,
+        crate::handlers::notifications::mark_notifications_as_read::exec,
+        crate::handlers::notifications::mark_all_notifications_as_read::exec,
+        crate::handlers::notifications::get_notification_preferences::exec,
+        crate::handlers::notifications::edit_notification_preferences::exec,
         crate::handlers::subscriptions::create_subscription_forum_thread_posts::exec,
         crate::handlers::subscriptions::remove_subscription_forum_thread_posts::exec,
         crate::handlers::subscriptions::create_subscription_title_group_torrents::exec,
         crate::handlers::subscriptions::remove_subscription_title_group_torrents::exec,
-        crate::handlers::notifications::get_notifications_forum_thread_posts::exec,
+        crate::handlers::subscriptions::create_subscription_artist_torrents::exec,
+        crate::handlers::subscriptions::remove_subscription_artist_torrents::exec,
+        crate::handlers::subscriptions::create_subscription_series_torrents::exec,
+        crate::handlers::subscriptions::remove_subscription_series_torrents::exec,
         crate::handlers::title_groups::create_title_group_comment::exec,
         crate::handlers::title_groups::create_title_group::exec,
         crate::handlers::title_groups::edit_title_group::exec,
+        crate::handlers::title_groups::merge_title_groups::exec,
         crate::handlers::title_groups::get_title_group::exec,
         crate::handlers::title_groups::get_title_group_info_lite::exec,
         crate::handlers::search::search_torrents::exec,
@@ -75,6 +132,21 @@ use crate::handlers::{
         crate::handlers::forum::get_forum_thread_posts::exec,
         crate::handlers::forum::create_forum_thread::exec,
         crate::handlers::forum::create_forum_post::exec,
+        crate::handlers::forum::edit_forum_post::exec,
+        crate::handlers::forum::get_forum_post_edits::exec,
+        crate::handlers::forum::delete_forum_post::exec,
+        crate::handlers::forum::restore_forum_post::exec,
+        crate::handlers::forum::edit_forum_thread::exec,
+        crate::handlers::forum::split_forum_thread::exec,
+        crate::handlers::forum::merge_forum_threads::exec,
+        crate::handlers::forum::create_forum_category::exec,
+        crate::handlers::forum::edit_forum_category::exec,
+        crate::handlers::forum::delete_forum_category::exec,
+        crate::handlers::forum::create_forum_sub_category::exec,
+        crate::handlers::forum::edit_forum_sub_category::exec,
+        crate::handlers::forum::delete_forum_sub_category::exec,
+        crate::handlers::forum::create_forum_poll_vote::exec,
+        crate::handlers::forum::mark_forum_sub_category_as_read::exec,
         crate::handlers::wiki::create_wiki_article::exec,
         crate::handlers::wiki::get_wiki_article::exec,
         crate::handlers::conversations::create_conversation::exec,
@@ -1,4 +1,7 @@
+use std::str::FromStr;
+
 use crate::OpenSignups;
+use arcadia_storage::models::user::UserClass;
 use envconfig::Envconfig;
 use reqwest::Url;
 
@@ -14,10 +17,22 @@ pub struct Env {
     pub open_signups: OpenSignups,
     #[envconfig(from = "ARCADIA_FRONTEND_URL")]
     pub frontend_url: Url,
+    // public URL of this API, for links used outside of the frontend (feeds, etc.)
+    #[envconfig(from = "ARCADIA_API_URL")]
+    pub api_url: Option<Url>,
     #[envconfig(from = "ARCADIA_GLOBAL_UPLOAD_FACTOR")]
     pub global_upload_factor: i16,
     #[envconfig(from = "ARCADIA_GLOBAL_DOWNLOAD_FACTOR")]
     pub global_download_factor: i16,
+    // uploads of these classes are not announceable until approved by staff
+    #[envconfig(from = "ARCADIA_HOLD_UPLOADS_FROM_CLASSES", default = "")]
+    pub hold_uploads_from_classes: UserClasses,
+    // amount of notification filters a user can create, per class
+    #[envconfig(
+        from = "ARCADIA_NOTIFICATION_FILTERS_PER_CLASS",
+        default = "newbie=10,staff=100"
+    )]
+    pub notification_filters_per_class: UserClassLimits,
     #[envconfig(nested)]
     pub tracker: TrackerConfig,
     #[envconfig(nested)]
@@ -26,6 +41,9 @@ pub struct Env {
     pub redis: RedisConfig,
     #[envconfig(from = "TMDB_API_KEY")]
     pub tmdb_api_key: Option<String>,
+    // header the reverse proxy puts the client ip in, the ip of the connection is used without it
+    #[envconfig(from = "REVERSE_PROXY_CLIENT_IP_HEADER_NAME")]
+    pub reverse_proxy_client_ip_header_name: Option<String>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -34,6 +52,69 @@ pub enum Error {
     EnvVariableParseError(String),
 }
 
+// comma separated list of user classes, ex: "newbie,tracker"
+#[derive(Debug, Clone, Default)]
+pub struct UserClasses(pub Vec<UserClass>);
+
+impl UserClasses {
+    pub fn contains(&self, class: &UserClass) -> bool {
+        self.0.contains(class)
+    }
+}
+
+impl FromStr for UserClasses {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|class| !class.is_empty())
+            .map(|class| {
+                serde_json::from_value(serde_json::Value::String(class.to_lowercase())).map_err(
+                    |_| Error::EnvVariableParseError("ARCADIA_HOLD_UPLOADS_FROM_CLASSES".into()),
+                )
+            })
+            .collect::<std::result::Result<_, _>>()
+            .map(Self)
+    }
+}
+
+// comma separated list of limits per user class, ex: "newbie=10,staff=100"
+#[derive(Debug, Clone, Default)]
+pub struct UserClassLimits(pub Vec<(UserClass, i64)>);
+
+impl UserClassLimits {
+    // classes which are not listed have a limit of 0
+    pub fn get(&self, class: &UserClass) -> i64 {
+        self.0
+            .iter()
+            .find(|(limited_class, _)| limited_class == class)
+            .map_or(0, |(_, limit)| *limit)
+    }
+}
+
+impl FromStr for UserClassLimits {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|limit| !limit.is_empty())
+            .map(|limit| {
+                let parse_error =
+                    || Error::EnvVariableParseError(format!("invalid class limit '{limit}'"));
+                let (class, limit) = limit.split_once('=').ok_or_else(parse_error)?;
+                let class =
+                    serde_json::from_value(serde_json::Value::String(class.trim().to_lowercase()))
+                        .map_err(|_| parse_error())?;
+                let limit = limit.trim().parse().map_err(|_| parse_error())?;
+                Ok((class, limit))
+            })
+            .collect::<std::result::Result<_, _>>()
+            .map(Self)
+    }
+}
+
 #[derive(Envconfig, Clone)]
 pub struct ActixConfig {
     #[envconfig(from = "ACTIX_HOST", default = "127.0.0.1")]
@@ -60,11 +141,26 @@ pub struct TrackerConfig {
     pub url: Url,
     #[envconfig(from = "ARCADIA_TRACKER_URL_INTERNAL")]
     pub url_internal: Url,
+    // written in the info dict of uploaded torrents, defaults to the tracker's name
+    #[envconfig(from = "ARCADIA_TRACKER_SOURCE")]
+    pub source: Option<String>,
 
     #[envconfig(from = "ARCADIA_TRACKER_API_KEY")]
     pub api_key: String,
 }
 
+impl Env {
+    pub fn api_url(&self) -> &Url {
+        self.api_url.as_ref().unwrap_or(&self.frontend_url)
+    }
+}
+
+impl TrackerConfig {
+    pub fn source(&self) -> &str {
+        self.source.as_deref().unwrap_or(&self.name)
+    }
+}
+
 #[derive(Envconfig, Clone)]
 pub struct SmtpConfig {
     #[envconfig(from = "SMTP_HOST")]
@@ -0,0 +1,41 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_storage::{
+    models::{
+        artist::{Artist, EditedArtist},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+
+#[utoipa::path(
+    put,
+    operation_id = "Edit artist",
+    tag = "Artist",
+    path = "/api/artists",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully edited the artist", body=Artist),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<EditedArtist>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let artist = arc.pool.find_artist(form.id).await?;
+
+    if user.class != UserClass::Staff && artist.created_by_id != user.sub {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let updated_artist = arc.pool.update_artist(&form, artist.id, user.sub).await?;
+    Ok(HttpResponse::Ok().json(updated_artist))
+}
@@ -1,8 +1,11 @@
 use crate::{
-    services::auth::{AUTH_TOKEN_LONG_DURATION, AUTH_TOKEN_SHORT_DURATION, REFRESH_TOKEN_DURATION},
+    services::{
+        auth::{AUTH_TOKEN_LONG_DURATION, AUTH_TOKEN_SHORT_DURATION, REFRESH_TOKEN_DURATION},
+        ip_ban_service::client_ip,
+    },
     Arcadia,
 };
-use actix_web::{web, HttpResponse};
+use actix_web::{web, HttpRequest, HttpResponse};
 use arcadia_common::error::{Error, Result};
 use arcadia_storage::{
     models::user::{Claims, Login, LoginResponse},
@@ -23,7 +26,14 @@ use jsonwebtoken::{encode, EncodingKey, Header};
 pub async fn exec<R: RedisPoolInterface + 'static>(
     arc: web::Data<Arcadia<R>>,
     user_login: web::Json<Login>,
+    req: HttpRequest,
 ) -> Result<HttpResponse> {
+    let client_ip = client_ip(&req, arc.reverse_proxy_client_ip_header_name.as_deref())?;
+
+    if arc.pool.is_ip_banned(client_ip).await? {
+        return Err(Error::IpBanned);
+    }
+
     let user = arc.pool.find_user_with_password(&user_login).await?;
 
     if user.banned {
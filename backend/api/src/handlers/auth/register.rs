@@ -1,4 +1,7 @@
-use crate::{services::email_service::EmailService, Arcadia};
+use crate::{
+    services::{email_service::EmailService, ip_ban_service::client_ip},
+    Arcadia,
+};
 use actix_web::{web, HttpRequest, HttpResponse};
 use arcadia_common::error::{Error, Result};
 use arcadia_shared::tracker::models::user::APIInsertUser;
@@ -8,7 +11,6 @@ use arcadia_storage::{
         user::{Register, User},
     },
     redis::RedisPoolInterface,
-    sqlx::types::ipnetwork::IpNetwork,
 };
 use argon2::{
     password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
@@ -38,6 +40,12 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
     req: HttpRequest,
     query: web::Query<RegisterQuery>,
 ) -> Result<HttpResponse> {
+    let client_ip = client_ip(&req, arc.reverse_proxy_client_ip_header_name.as_deref())?;
+
+    if arc.pool.is_ip_banned(client_ip).await? {
+        return Err(Error::IpBanned);
+    }
+
     let invitation: Invitation;
     if !arc.is_open_signups() {
         let invitation_key = query
@@ -58,12 +66,6 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         invitation = Invitation::default();
     }
 
-    let client_ip = req
-        .connection_info()
-        .realip_remote_addr()
-        .and_then(|ip| ip.parse::<IpNetwork>().ok())
-        .unwrap();
-
     let salt = SaltString::generate(&mut OsRng);
 
     // Argon2 with default params (Argon2id v19)
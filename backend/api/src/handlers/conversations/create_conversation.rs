@@ -1,11 +1,16 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata, services::push_service::push_notifications, Arcadia,
+};
 use actix_web::{
     web::{Data, Json},
     HttpResponse,
 };
 use arcadia_common::error::Result;
 use arcadia_storage::{
-    models::conversation::{Conversation, UserCreatedConversation},
+    models::{
+        conversation::{Conversation, UserCreatedConversation},
+        notification::NotificationType,
+    },
     redis::RedisPoolInterface,
 };
 
@@ -31,6 +36,12 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         .pool
         .create_conversation(&mut conversation, user.sub)
         .await?;
+    push_notifications(
+        &arc,
+        &[NotificationType::ConversationMessage],
+        conversation.id,
+    )
+    .await;
 
     Ok(HttpResponse::Created().json(conversation))
 }
@@ -1,11 +1,18 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::push_service::{self, push_notifications, PushEvent, PushMessage},
+    Arcadia,
+};
 use actix_web::{
     web::{Data, Json},
     HttpResponse,
 };
 use arcadia_common::error::Result;
 use arcadia_storage::{
-    models::conversation::{ConversationMessage, UserCreatedConversationMessage},
+    models::{
+        conversation::{ConversationMessage, UserCreatedConversationMessage},
+        notification::NotificationType,
+    },
     redis::RedisPoolInterface,
 };
 
@@ -31,5 +38,22 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         .create_conversation_message(&message, user.sub)
         .await?;
 
+    let mut user_ids = arc
+        .pool
+        .find_conversation_participant_ids(message.conversation_id)
+        .await?;
+    user_ids.retain(|id| *id != user.sub);
+    let push_message = PushMessage {
+        user_ids,
+        event: PushEvent::ConversationMessage(message.clone()),
+    };
+    push_service::publish(arc.redis_pool.as_ref(), &push_message).await;
+    push_notifications(
+        &arc,
+        &[NotificationType::ConversationMessage],
+        message.conversation_id,
+    )
+    .await;
+
     Ok(HttpResponse::Created().json(message))
 }
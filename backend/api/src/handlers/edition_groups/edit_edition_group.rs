@@ -0,0 +1,44 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_storage::{
+    models::{
+        edition_group::{EditedEditionGroup, EditionGroup},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+
+#[utoipa::path(
+    put,
+    operation_id = "Edit edition group",
+    tag = "Edition Group",
+    path = "/api/edition-groups",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully edited the edition group", body=EditionGroup),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<EditedEditionGroup>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let edition_group = arc.pool.find_edition_group(form.id).await?;
+
+    if user.class != UserClass::Staff && edition_group.created_by_id != user.sub {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let updated_edition_group = arc
+        .pool
+        .update_edition_group(&form, edition_group.id, user.sub)
+        .await?;
+    Ok(HttpResponse::Ok().json(updated_edition_group))
+}
@@ -1,7 +1,14 @@
 pub mod create_edition_group;
-use actix_web::web::{post, resource, ServiceConfig};
+pub mod edit_edition_group;
+pub mod split_edition_group;
+use actix_web::web::{post, put, resource, ServiceConfig};
 use arcadia_storage::redis::RedisPoolInterface;
 
 pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
-    cfg.service(resource("").route(post().to(self::create_edition_group::exec::<R>)));
+    cfg.service(
+        resource("")
+            .route(post().to(self::create_edition_group::exec::<R>))
+            .route(put().to(self::edit_edition_group::exec::<R>)),
+    );
+    cfg.service(resource("/split").route(post().to(self::split_edition_group::exec::<R>)));
 }
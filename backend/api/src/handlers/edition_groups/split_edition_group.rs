@@ -0,0 +1,51 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_storage::{
+    models::{
+        edition_group::{EditionGroup, EditionGroupSplit},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+
+#[utoipa::path(
+    post,
+    operation_id = "Split edition group",
+    tag = "Edition Group",
+    path = "/api/edition-groups/split",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 201, description = "Successfully moved the torrents to a copy of the edition group", body=EditionGroup),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<EditionGroupSplit>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    if form.torrent_ids.is_empty() {
+        return Err(Error::BadRequest(
+            "at least one torrent must be moved to the new edition group".into(),
+        ));
+    }
+
+    let edition_group = arc.pool.find_edition_group(form.edition_group_id).await?;
+
+    let new_edition_group = arc
+        .pool
+        .split_edition_group(&edition_group, &form.torrent_ids, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Created().json(new_edition_group))
+}
@@ -0,0 +1,46 @@
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_storage::redis::RedisPoolInterface;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::{handlers::torrents::download_dottorrent_file::dottorrent_response, Arcadia};
+use arcadia_common::error::Result;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DownloadFeedTorrentQuery {
+    torrent_id: i32,
+    passkey: String,
+}
+
+#[utoipa::path(
+    get,
+    operation_id = "Download .torrent file from a feed",
+    tag = "Feed",
+    path = "/api/feeds/download",
+    params (DownloadFeedTorrentQuery),
+    responses(
+        (status = 200, description = "Successfully downloaded the torrent file"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<DownloadFeedTorrentQuery>,
+    arc: Data<Arcadia<R>>,
+) -> Result<HttpResponse> {
+    let user = arc.pool.find_user_with_passkey(&query.passkey).await?;
+
+    let torrent = arc
+        .pool
+        .get_torrent(
+            user.id,
+            query.torrent_id,
+            &arc.tracker.name,
+            arc.frontend_url.as_ref(),
+            arc.tracker.url.as_ref(),
+        )
+        .await?;
+
+    Ok(dottorrent_response(torrent))
+}
@@ -0,0 +1,48 @@
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_storage::{models::feed::TorrentFeedFilter, redis::RedisPoolInterface};
+
+use crate::{
+    services::feed_service::{torrent_feed_response, FeedLinks, FeedQuery, FEED_LENGTH},
+    Arcadia,
+};
+use arcadia_common::error::Result;
+
+#[utoipa::path(
+    get,
+    operation_id = "Get bookmarks feed",
+    tag = "Feed",
+    path = "/api/feeds/bookmarks",
+    params (FeedQuery),
+    responses(
+        (status = 200, description = "RSS or Atom feed of the newest torrents in the title groups the user bookmarked"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<FeedQuery>,
+    arc: Data<Arcadia<R>>,
+) -> Result<HttpResponse> {
+    let user = arc.pool.find_user_with_passkey(&query.passkey).await?;
+
+    let torrents = arc
+        .pool
+        .find_feed_torrents(&TorrentFeedFilter {
+            bookmarked_by_id: Some(user.id),
+            limit: FEED_LENGTH,
+            ..Default::default()
+        })
+        .await?;
+
+    Ok(torrent_feed_response(
+        query.format,
+        &format!("{} - Bookmarks", arc.tracker.name),
+        &torrents,
+        &FeedLinks {
+            frontend_url: &arc.frontend_url,
+            api_url: arc.api_url(),
+            passkey: &query.passkey,
+        },
+    ))
+}
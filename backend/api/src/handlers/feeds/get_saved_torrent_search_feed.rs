@@ -0,0 +1,61 @@
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_storage::{models::feed::TorrentFeedFilter, redis::RedisPoolInterface};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::{
+    services::feed_service::{torrent_feed_response, FeedFormat, FeedLinks, FEED_LENGTH},
+    Arcadia,
+};
+use arcadia_common::error::Result;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SavedTorrentSearchFeedQuery {
+    passkey: String,
+    saved_search_id: i64,
+    #[serde(default)]
+    format: FeedFormat,
+}
+
+#[utoipa::path(
+    get,
+    operation_id = "Get saved torrent search feed",
+    tag = "Feed",
+    path = "/api/feeds/saved-searches",
+    params (SavedTorrentSearchFeedQuery),
+    responses(
+        (status = 200, description = "RSS or Atom feed of the newest torrents matching one of the user's saved searches"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<SavedTorrentSearchFeedQuery>,
+    arc: Data<Arcadia<R>>,
+) -> Result<HttpResponse> {
+    let user = arc.pool.find_user_with_passkey(&query.passkey).await?;
+    let saved_search = arc
+        .pool
+        .find_saved_torrent_search(query.saved_search_id, user.id)
+        .await?;
+
+    let torrents = arc
+        .pool
+        .find_feed_torrents(&TorrentFeedFilter {
+            limit: FEED_LENGTH,
+            ..TorrentFeedFilter::from(&saved_search.search.0)
+        })
+        .await?;
+
+    Ok(torrent_feed_response(
+        query.format,
+        &format!("{} - {}", arc.tracker.name, saved_search.name),
+        &torrents,
+        &FeedLinks {
+            frontend_url: &arc.frontend_url,
+            api_url: arc.api_url(),
+            passkey: &query.passkey,
+        },
+    ))
+}
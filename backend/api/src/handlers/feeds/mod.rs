@@ -0,0 +1,25 @@
+pub mod download_feed_torrent;
+pub mod get_bookmarks_feed;
+pub mod get_notifications_feed;
+pub mod get_saved_torrent_search_feed;
+pub mod get_subscriptions_feed;
+pub mod get_torrents_feed;
+
+use actix_web::web::{get, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+// these routes are authenticated with the passkey in the query, not with a jwt
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(resource("/torrents").route(get().to(self::get_torrents_feed::exec::<R>)));
+    cfg.service(
+        resource("/subscriptions").route(get().to(self::get_subscriptions_feed::exec::<R>)),
+    );
+    cfg.service(
+        resource("/notifications").route(get().to(self::get_notifications_feed::exec::<R>)),
+    );
+    cfg.service(resource("/bookmarks").route(get().to(self::get_bookmarks_feed::exec::<R>)));
+    cfg.service(
+        resource("/saved-searches").route(get().to(self::get_saved_torrent_search_feed::exec::<R>)),
+    );
+    cfg.service(resource("/download").route(get().to(self::download_feed_torrent::exec::<R>)));
+}
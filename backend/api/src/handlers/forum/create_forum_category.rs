@@ -0,0 +1,39 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        forum::{ForumCategory, UserCreatedForumCategory},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Create forum category",
+    tag = "Forum",
+    path = "/api/forum/category",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully created the forum category", body=ForumCategory),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<UserCreatedForumCategory>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let category = arc.pool.create_forum_category(&form, user.sub).await?;
+
+    Ok(HttpResponse::Created().json(category))
+}
@@ -0,0 +1,51 @@
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::forum_service::{check_forum_access, ForumAccess},
+    Arcadia,
+};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::forum::{ForumPoll, UserCreatedForumPollVote},
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Create forum poll vote",
+    tag = "Forum",
+    path = "/api/forum/poll/vote",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 201, description = "Successfully voted, returns the poll with its results", body=ForumPoll),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    vote: Json<UserCreatedForumPollVote>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let forum_thread_id = arc
+        .pool
+        .find_forum_poll_thread_id(vote.forum_poll_id)
+        .await?;
+    let forum_thread = arc.pool.find_forum_thread_raw(forum_thread_id).await?;
+    let sub_category = arc
+        .pool
+        .find_forum_sub_category(forum_thread.forum_sub_category_id)
+        .await?;
+    check_forum_access(&sub_category, &user.class, ForumAccess::Read)?;
+    if forum_thread.locked {
+        return Err(Error::ForumThreadLocked);
+    }
+
+    arc.pool.create_forum_poll_vote(&vote, user.sub).await?;
+    let poll = arc.pool.find_forum_poll(forum_thread_id, user.sub).await?;
+
+    Ok(HttpResponse::Created().json(poll))
+}
@@ -1,11 +1,22 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::{
+        forum_service::{check_forum_access, ForumAccess},
+        push_service::push_notifications,
+    },
+    Arcadia,
+};
 use actix_web::{
     web::{Data, Json},
     HttpResponse,
 };
-use arcadia_common::error::Result;
+use arcadia_common::error::{Error, Result};
 use arcadia_storage::{
-    models::forum::{ForumPost, UserCreatedForumPost},
+    models::{
+        forum::{ForumPost, UserCreatedForumPost},
+        notification::NotificationType,
+        user::UserClass,
+    },
     redis::RedisPoolInterface,
 };
 
@@ -26,7 +37,35 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
     arc: Data<Arcadia<R>>,
     user: Authdata,
 ) -> Result<HttpResponse> {
+    let forum_thread = arc
+        .pool
+        .find_forum_thread_raw(forum_post.forum_thread_id)
+        .await?;
+    let sub_category = arc
+        .pool
+        .find_forum_sub_category(forum_thread.forum_sub_category_id)
+        .await?;
+    check_forum_access(&sub_category, &user.class, ForumAccess::Post)?;
+    if forum_thread.locked && user.class != UserClass::Staff {
+        return Err(Error::ForumThreadLocked);
+    }
+
     let forum_post = arc.pool.create_forum_post(&forum_post, user.sub).await?;
+    push_notifications(
+        &arc,
+        &[
+            NotificationType::ForumPostMention,
+            NotificationType::ForumPostQuote,
+        ],
+        forum_post.id,
+    )
+    .await;
+    push_notifications(
+        &arc,
+        &[NotificationType::ForumThreadPost],
+        forum_post.forum_thread_id,
+    )
+    .await;
 
     Ok(HttpResponse::Created().json(forum_post))
 }
@@ -1,4 +1,8 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::forum_service::{check_forum_access, validate_forum_poll, ForumAccess},
+    Arcadia,
+};
 use actix_web::{
     web::{Data, Json},
     HttpResponse,
@@ -26,6 +30,15 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
     arc: Data<Arcadia<R>>,
     user: Authdata,
 ) -> Result<HttpResponse> {
+    let sub_category = arc
+        .pool
+        .find_forum_sub_category(forum_thread.forum_sub_category_id)
+        .await?;
+    check_forum_access(&sub_category, &user.class, ForumAccess::CreateThread)?;
+    if let Some(poll) = &forum_thread.poll {
+        validate_forum_poll(poll)?;
+    }
+
     let forum_thread = arc
         .pool
         .create_forum_thread(&mut forum_thread, user.sub)
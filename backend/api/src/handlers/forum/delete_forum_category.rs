@@ -0,0 +1,43 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{models::user::UserClass, redis::RedisPoolInterface};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DeleteForumCategoryQuery {
+    pub id: i32,
+}
+
+#[utoipa::path(
+    delete,
+    operation_id = "Delete forum category",
+    tag = "Forum",
+    path = "/api/forum/category",
+    params(DeleteForumCategoryQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully deleted the forum category"),
+        (status = 409, description = "The forum category is not empty"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<DeleteForumCategoryQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    arc.pool.delete_forum_category(query.id).await?;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
@@ -0,0 +1,42 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{models::user::UserClass, redis::RedisPoolInterface};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DeleteForumPostQuery {
+    pub id: i64,
+}
+
+#[utoipa::path(
+    delete,
+    operation_id = "Delete forum post",
+    tag = "Forum",
+    path = "/api/forum/post",
+    params(DeleteForumPostQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully deleted the forum post, it can be restored"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<DeleteForumPostQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    arc.pool.delete_forum_post(query.id, user.sub).await?;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
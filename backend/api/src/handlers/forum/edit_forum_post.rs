@@ -0,0 +1,62 @@
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::forum_service::{check_forum_access, ForumAccess},
+    Arcadia,
+};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        forum::{EditedForumPost, ForumPost},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "Edit forum post",
+    tag = "Forum",
+    path = "/api/forum/post",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully edited the forum post", body=ForumPost),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<EditedForumPost>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let forum_post = arc.pool.find_forum_post(form.id).await?;
+
+    if user.class != UserClass::Staff {
+        if forum_post.deleted_at.is_some() {
+            return Err(Error::ForumPostNotFound(forum_post.id));
+        }
+        if forum_post.created_by_id != user.sub || forum_post.sticky != form.sticky {
+            return Err(Error::InsufficientPrivileges);
+        }
+        let forum_thread = arc
+            .pool
+            .find_forum_thread_raw(forum_post.forum_thread_id)
+            .await?;
+        let sub_category = arc
+            .pool
+            .find_forum_sub_category(forum_thread.forum_sub_category_id)
+            .await?;
+        check_forum_access(&sub_category, &user.class, ForumAccess::Post)?;
+        if forum_thread.locked {
+            return Err(Error::ForumThreadLocked);
+        }
+    }
+
+    let updated_post = arc.pool.update_forum_post(&form, user.sub).await?;
+
+    Ok(HttpResponse::Ok().json(updated_post))
+}
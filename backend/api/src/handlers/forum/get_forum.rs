@@ -1,4 +1,4 @@
-use crate::Arcadia;
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
 use actix_web::{web::Data, HttpResponse};
 use arcadia_common::error::Result;
 use arcadia_storage::{
@@ -16,9 +16,14 @@ use serde_json::json;
         (status = 200, description = "Returns an overview of the forum", body=ForumOverview),
     )
 )]
-pub async fn exec<R: RedisPoolInterface + 'static>(arc: Data<Arcadia<R>>) -> Result<HttpResponse> {
-    //TODO: restrict access to some sub_categories based on forbidden_classes
-    let forum_categories = arc.pool.find_forum_cateogries_hierarchy().await?;
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let forum_categories = arc
+        .pool
+        .find_forum_cateogries_hierarchy(&user.class)
+        .await?;
     let search_forum_threads_form = ForumSearchQuery {
         thread_name: None,
         page_size: 5,
@@ -26,7 +31,7 @@ pub async fn exec<R: RedisPoolInterface + 'static>(arc: Data<Arcadia<R>>) -> Res
     };
     let latest_posts_in_threads = arc
         .pool
-        .search_forum_threads(&search_forum_threads_form)
+        .search_forum_threads(&search_forum_threads_form, &user.class)
         .await?;
 
     Ok(HttpResponse::Ok().json(json!({
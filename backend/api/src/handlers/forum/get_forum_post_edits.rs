@@ -0,0 +1,58 @@
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::forum_service::{check_forum_access, ForumAccess},
+    Arcadia,
+};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{forum::ForumPostEdit, user::UserClass},
+    redis::RedisPoolInterface,
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GetForumPostEditsQuery {
+    pub post_id: i64,
+}
+
+#[utoipa::path(
+    get,
+    operation_id = "Get forum post edits",
+    tag = "Forum",
+    path = "/api/forum/post/edits",
+    params(GetForumPostEditsQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "The previous versions of the forum post, latest first", body=Vec<ForumPostEdit>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<GetForumPostEditsQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let forum_post = arc.pool.find_forum_post(query.post_id).await?;
+    if forum_post.deleted_at.is_some() && user.class != UserClass::Staff {
+        return Err(Error::ForumPostNotFound(forum_post.id));
+    }
+    let forum_thread = arc
+        .pool
+        .find_forum_thread_raw(forum_post.forum_thread_id)
+        .await?;
+    let sub_category = arc
+        .pool
+        .find_forum_sub_category(forum_thread.forum_sub_category_id)
+        .await?;
+    check_forum_access(&sub_category, &user.class, ForumAccess::Read)?;
+
+    let edits = arc.pool.find_forum_post_edits(forum_post.id).await?;
+
+    Ok(HttpResponse::Ok().json(edits))
+}
@@ -1,4 +1,8 @@
-use crate::Arcadia;
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::forum_service::{check_forum_access, ForumAccess, DEFAULT_FORUM_POSTS_PAGE_SIZE},
+    Arcadia,
+};
 use actix_web::{
     web::{Data, Query},
     HttpResponse,
@@ -11,6 +15,8 @@ use utoipa::IntoParams;
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct GetForumSubCategoryThreadsQuery {
     id: i32,
+    // to compute the page of the first unread post of each thread
+    posts_page_size: Option<u32>,
 }
 
 #[utoipa::path(
@@ -26,9 +32,19 @@ pub struct GetForumSubCategoryThreadsQuery {
 pub async fn exec<R: RedisPoolInterface + 'static>(
     arc: Data<Arcadia<R>>,
     query: Query<GetForumSubCategoryThreadsQuery>,
+    user: Authdata,
 ) -> Result<HttpResponse> {
-    //TODO: restrict access to some sub_categories based on forbidden_classes
-    let threads = arc.pool.find_forum_sub_category_threads(query.id).await?;
+    let sub_category = arc.pool.find_forum_sub_category(query.id).await?;
+    check_forum_access(&sub_category, &user.class, ForumAccess::Read)?;
+
+    let posts_page_size = query
+        .posts_page_size
+        .unwrap_or(DEFAULT_FORUM_POSTS_PAGE_SIZE)
+        .max(1);
+    let threads = arc
+        .pool
+        .find_forum_sub_category_threads(query.id, user.sub, posts_page_size)
+        .await?;
 
     Ok(HttpResponse::Ok().json(threads))
 }
@@ -1,4 +1,8 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::forum_service::{check_forum_access, ForumAccess, DEFAULT_FORUM_POSTS_PAGE_SIZE},
+    Arcadia,
+};
 use actix_web::{
     web::{Data, Query},
     HttpResponse,
@@ -11,6 +15,8 @@ use utoipa::IntoParams;
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct GetForumThreadQueryId {
     pub id: i64,
+    // to compute the page of the first unread post
+    pub posts_page_size: Option<u32>,
 }
 
 #[utoipa::path(
@@ -28,9 +34,21 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
     query_id: Query<GetForumThreadQueryId>,
     user: Authdata,
 ) -> Result<HttpResponse> {
-    //TODO: restrict access to some sub_categories based on forbidden_classes
+    let forum_thread = arc.pool.find_forum_thread_raw(query_id.id).await?;
+    let sub_category = arc
+        .pool
+        .find_forum_sub_category(forum_thread.forum_sub_category_id)
+        .await?;
+    check_forum_access(&sub_category, &user.class, ForumAccess::Read)?;
 
-    let thread = arc.pool.find_forum_thread(query_id.0.id, user.sub).await?;
+    let posts_page_size = query_id
+        .posts_page_size
+        .unwrap_or(DEFAULT_FORUM_POSTS_PAGE_SIZE)
+        .max(1);
+    let thread = arc
+        .pool
+        .find_forum_thread(query_id.id, user.sub, posts_page_size)
+        .await?;
 
     Ok(HttpResponse::Ok().json(thread))
 }
@@ -1,4 +1,8 @@
-use crate::Arcadia;
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::forum_service::{check_forum_access, ForumAccess},
+    Arcadia,
+};
 use actix_web::{
     web::{Data, Query},
     HttpResponse,
@@ -8,6 +12,7 @@ use arcadia_storage::{
     models::{
         common::PaginatedResults,
         forum::{ForumPostHierarchy, GetForumThreadPostsQuery},
+        user::UserClass,
     },
     redis::RedisPoolInterface,
 };
@@ -25,10 +30,26 @@ use arcadia_storage::{
 pub async fn exec<R: RedisPoolInterface + 'static>(
     arc: Data<Arcadia<R>>,
     query: Query<GetForumThreadPostsQuery>,
+    user: Authdata,
 ) -> Result<HttpResponse> {
-    //TODO: restrict access to some sub_categories based on forbidden_classes
+    let forum_thread = arc.pool.find_forum_thread_raw(query.thread_id).await?;
+    let sub_category = arc
+        .pool
+        .find_forum_sub_category(forum_thread.forum_sub_category_id)
+        .await?;
+    check_forum_access(&sub_category, &user.class, ForumAccess::Read)?;
+
+    // staff can see the deleted posts to restore them
+    let thread = arc
+        .pool
+        .find_forum_thread_posts(query.into_inner(), user.class == UserClass::Staff)
+        .await?;
 
-    let thread = arc.pool.find_forum_thread_posts(query.into_inner()).await?;
+    if let Some(last_read_post_id) = thread.results.iter().map(|post| post.id).max() {
+        arc.pool
+            .mark_forum_thread_as_read(forum_thread.id, user.sub, last_read_post_id)
+            .await?;
+    }
 
     Ok(HttpResponse::Ok().json(thread))
 }
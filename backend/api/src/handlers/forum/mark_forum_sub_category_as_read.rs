@@ -0,0 +1,47 @@
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::forum_service::{check_forum_access, ForumAccess},
+    Arcadia,
+};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::redis::RedisPoolInterface;
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct MarkForumSubCategoryAsReadQuery {
+    pub id: i32,
+}
+
+#[utoipa::path(
+    put,
+    operation_id = "Mark forum sub-category as read",
+    tag = "Forum",
+    path = "/api/forum/sub-category/read",
+    params(MarkForumSubCategoryAsReadQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully marked all the threads of the sub-category as read"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<MarkForumSubCategoryAsReadQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let sub_category = arc.pool.find_forum_sub_category(query.id).await?;
+    check_forum_access(&sub_category, &user.class, ForumAccess::Read)?;
+
+    arc.pool
+        .mark_forum_sub_category_as_read(sub_category.id, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
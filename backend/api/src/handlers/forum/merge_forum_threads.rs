@@ -0,0 +1,39 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        forum::{ForumThread, ForumThreadsMerge},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Merge forum threads",
+    tag = "Forum",
+    path = "/api/forum/thread/merge",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully merged the source thread into the target one", body=ForumThread),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<ForumThreadsMerge>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let merged_thread = arc.pool.merge_forum_threads(&form, user.sub).await?;
+
+    Ok(HttpResponse::Ok().json(merged_thread))
+}
@@ -1,11 +1,26 @@
+pub mod create_forum_category;
+pub mod create_forum_poll_vote;
 pub mod create_forum_post;
+pub mod create_forum_sub_category;
 pub mod create_forum_thread;
+pub mod delete_forum_category;
+pub mod delete_forum_post;
+pub mod delete_forum_sub_category;
+pub mod edit_forum_category;
+pub mod edit_forum_post;
+pub mod edit_forum_sub_category;
+pub mod edit_forum_thread;
 pub mod get_forum;
+pub mod get_forum_post_edits;
 pub mod get_forum_sub_category_threads;
 pub mod get_forum_thread;
 pub mod get_forum_thread_posts;
+pub mod mark_forum_sub_category_as_read;
+pub mod merge_forum_threads;
+pub mod restore_forum_post;
+pub mod split_forum_thread;
 
-use actix_web::web::{get, post, resource, ServiceConfig};
+use actix_web::web::{delete, get, post, put, resource, ServiceConfig};
 use arcadia_storage::redis::RedisPoolInterface;
 
 pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
@@ -13,11 +28,36 @@ pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
     cfg.service(
         resource("/thread")
             .route(get().to(self::get_forum_thread::exec::<R>))
-            .route(post().to(self::create_forum_thread::exec::<R>)),
+            .route(post().to(self::create_forum_thread::exec::<R>))
+            .route(put().to(self::edit_forum_thread::exec::<R>)),
     );
     cfg.service(resource("/thread/posts").route(get().to(self::get_forum_thread_posts::exec::<R>)));
-    cfg.service(resource("/post").route(post().to(self::create_forum_post::exec::<R>)));
+    cfg.service(resource("/thread/split").route(post().to(self::split_forum_thread::exec::<R>)));
+    cfg.service(resource("/thread/merge").route(post().to(self::merge_forum_threads::exec::<R>)));
     cfg.service(
-        resource("/sub-category").route(get().to(self::get_forum_sub_category_threads::exec::<R>)),
+        resource("/post")
+            .route(post().to(self::create_forum_post::exec::<R>))
+            .route(put().to(self::edit_forum_post::exec::<R>))
+            .route(delete().to(self::delete_forum_post::exec::<R>)),
+    );
+    cfg.service(resource("/poll/vote").route(post().to(self::create_forum_poll_vote::exec::<R>)));
+    cfg.service(resource("/post/edits").route(get().to(self::get_forum_post_edits::exec::<R>)));
+    cfg.service(resource("/post/restore").route(put().to(self::restore_forum_post::exec::<R>)));
+    cfg.service(
+        resource("/category")
+            .route(post().to(self::create_forum_category::exec::<R>))
+            .route(put().to(self::edit_forum_category::exec::<R>))
+            .route(delete().to(self::delete_forum_category::exec::<R>)),
+    );
+    cfg.service(
+        resource("/sub-category")
+            .route(get().to(self::get_forum_sub_category_threads::exec::<R>))
+            .route(post().to(self::create_forum_sub_category::exec::<R>))
+            .route(put().to(self::edit_forum_sub_category::exec::<R>))
+            .route(delete().to(self::delete_forum_sub_category::exec::<R>)),
+    );
+    cfg.service(
+        resource("/sub-category/read")
+            .route(put().to(self::mark_forum_sub_category_as_read::exec::<R>)),
     );
 }
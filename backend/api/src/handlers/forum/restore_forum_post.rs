@@ -0,0 +1,44 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{forum::ForumPost, user::UserClass},
+    redis::RedisPoolInterface,
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RestoreForumPostQuery {
+    pub id: i64,
+}
+
+#[utoipa::path(
+    put,
+    operation_id = "Restore forum post",
+    tag = "Forum",
+    path = "/api/forum/post/restore",
+    params(RestoreForumPostQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully restored the deleted forum post", body=ForumPost),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<RestoreForumPostQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let restored_post = arc.pool.restore_forum_post(query.id, user.sub).await?;
+
+    Ok(HttpResponse::Ok().json(restored_post))
+}
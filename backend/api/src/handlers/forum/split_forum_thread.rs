@@ -0,0 +1,39 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        forum::{ForumThread, ForumThreadSplit},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Split forum thread",
+    tag = "Forum",
+    path = "/api/forum/thread/split",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 201, description = "Successfully moved the posts to a new thread", body=ForumThread),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<ForumThreadSplit>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let new_thread = arc.pool.split_forum_thread(&form, user.sub).await?;
+
+    Ok(HttpResponse::Created().json(new_thread))
+}
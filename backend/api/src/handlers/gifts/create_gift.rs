@@ -1,11 +1,16 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata, services::push_service::push_notifications, Arcadia,
+};
 use actix_web::{
     web::{Data, Json},
     HttpResponse,
 };
 use arcadia_common::error::{Error, Result};
 use arcadia_storage::{
-    models::gift::{Gift, UserCreatedGift},
+    models::{
+        gift::{Gift, UserCreatedGift},
+        notification::NotificationType,
+    },
     redis::RedisPoolInterface,
 };
 
@@ -35,6 +40,7 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
     }
 
     let gift = arc.pool.create_gift(&gift, user.sub).await?;
+    push_notifications(&arc, &[NotificationType::GiftReceived], gift.id).await;
 
     Ok(HttpResponse::Created().json(gift))
 }
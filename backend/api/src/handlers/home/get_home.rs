@@ -1,4 +1,4 @@
-use crate::Arcadia;
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
 use actix_web::{web::Data, HttpResponse};
 use arcadia_common::error::Result;
 use arcadia_storage::{
@@ -30,10 +30,13 @@ pub struct HomePage {
         (status = 200, description = "", body=HomePage),
     )
 )]
-pub async fn exec<R: RedisPoolInterface + 'static>(arc: Data<Arcadia<R>>) -> Result<HttpResponse> {
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
     let recent_announcements = arc
         .pool
-        .find_first_thread_posts_in_sub_category(1, 5)
+        .find_first_thread_posts_in_sub_category(1, 5, &user.class)
         .await?;
 
     let stats = arc.pool.find_home_stats().await?;
@@ -50,7 +53,7 @@ pub async fn exec<R: RedisPoolInterface + 'static>(arc: Data<Arcadia<R>>) -> Res
     };
     let latest_posts_in_threads = arc
         .pool
-        .search_forum_threads(&search_forum_threads_form)
+        .search_forum_threads(&search_forum_threads_form, &user.class)
         .await?;
 
     Ok(HttpResponse::Created().json(json!({
@@ -0,0 +1,44 @@
+use crate::{
+    middlewares::auth_middleware::Authdata, services::ip_ban_service::upsert_ip_ban_in_tracker,
+    Arcadia,
+};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        ip_ban::{IpBan, UserCreatedIpBan},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Create ip ban",
+    tag = "Ip Ban",
+    path = "/api/ip-bans",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 201, description = "Successfully banned the ip range", body=IpBan),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    ban: Json<UserCreatedIpBan>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let ban = arc.pool.create_ip_ban(&ban, user.sub).await?;
+
+    upsert_ip_ban_in_tracker(&arc.env.tracker, &ban).await;
+
+    Ok(HttpResponse::Created().json(ban))
+}
@@ -0,0 +1,32 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{web::Data, HttpResponse};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{ip_ban::IpBan, user::UserClass},
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get ip bans",
+    tag = "Ip Ban",
+    path = "/api/ip-bans",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Banned ip ranges", body=Vec<IpBan>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let bans = arc.pool.find_ip_bans().await?;
+
+    Ok(HttpResponse::Ok().json(bans))
+}
@@ -0,0 +1,15 @@
+pub mod create_ip_ban;
+pub mod get_ip_bans;
+pub mod remove_ip_ban;
+
+use actix_web::web::{delete, get, post, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(
+        resource("")
+            .route(post().to(self::create_ip_ban::exec::<R>))
+            .route(get().to(self::get_ip_bans::exec::<R>))
+            .route(delete().to(self::remove_ip_ban::exec::<R>)),
+    );
+}
@@ -0,0 +1,47 @@
+use crate::{
+    middlewares::auth_middleware::Authdata, services::ip_ban_service::remove_ip_ban_from_tracker,
+    Arcadia,
+};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{models::user::UserClass, redis::RedisPoolInterface};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RemoveIpBanQuery {
+    id: i32,
+}
+
+#[utoipa::path(
+    delete,
+    operation_id = "Remove ip ban",
+    tag = "Ip Ban",
+    path = "/api/ip-bans",
+    params(RemoveIpBanQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully lifted the ip ban"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<RemoveIpBanQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    arc.pool.delete_ip_ban(query.id).await?;
+
+    remove_ip_ban_from_tracker(&arc.env.tracker, query.id).await;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
@@ -5,21 +5,31 @@ pub mod collages;
 pub mod conversations;
 pub mod edition_groups;
 pub mod external_db;
+pub mod feeds;
 pub mod forum;
 pub mod gifts;
 pub mod home;
 pub mod invitations;
+pub mod ip_bans;
 pub mod master_groups;
+pub mod notification_filters;
 pub mod notifications;
+pub mod revisions;
+pub mod saved_torrent_searches;
 pub mod search;
 pub mod series;
+pub mod site_logs;
 pub mod staff_pms;
 pub mod subscriptions;
 pub mod title_group_bookmarks;
 pub mod title_groups;
+pub mod torrent_clients;
 pub mod torrent_requests;
 pub mod torrents;
+pub mod torznab;
 pub mod tracker;
+pub mod tracker_flags;
+pub mod upload_rules;
 pub mod user_applications;
 pub mod users;
 pub mod wiki;
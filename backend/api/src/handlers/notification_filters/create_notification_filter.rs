@@ -0,0 +1,38 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_storage::{
+    models::notification_filter::{NotificationFilter, UserCreatedNotificationFilter},
+    redis::RedisPoolInterface,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::Result;
+
+#[utoipa::path(
+    post,
+    operation_id = "Create notification filter",
+    tag = "Notification",
+    path = "/api/notification-filters",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 201, description = "Successfully created the notification filter", body=NotificationFilter),
+        (status = 403, description = "The user reached the amount of filters allowed for their class"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<UserCreatedNotificationFilter>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let max_filters = arc.notification_filters_per_class.get(&user.class);
+    let filter = arc
+        .pool
+        .create_notification_filter(&form, user.sub, max_filters)
+        .await?;
+
+    Ok(HttpResponse::Created().json(filter))
+}
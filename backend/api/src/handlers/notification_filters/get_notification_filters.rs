@@ -0,0 +1,26 @@
+use actix_web::{web::Data, HttpResponse};
+use arcadia_storage::{models::notification_filter::NotificationFilter, redis::RedisPoolInterface};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::Result;
+
+#[utoipa::path(
+    get,
+    operation_id = "Get notification filters",
+    tag = "Notification",
+    path = "/api/notification-filters",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "The notification filters of the user", body=Vec<NotificationFilter>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let filters = arc.pool.find_notification_filters(user.sub).await?;
+
+    Ok(HttpResponse::Ok().json(filters))
+}
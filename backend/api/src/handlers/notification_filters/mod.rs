@@ -0,0 +1,15 @@
+pub mod create_notification_filter;
+pub mod get_notification_filters;
+pub mod remove_notification_filter;
+
+use actix_web::web::{delete, get, post, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(
+        resource("")
+            .route(post().to(self::create_notification_filter::exec::<R>))
+            .route(get().to(self::get_notification_filters::exec::<R>))
+            .route(delete().to(self::remove_notification_filter::exec::<R>)),
+    );
+}
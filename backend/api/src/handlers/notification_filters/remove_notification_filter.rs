@@ -0,0 +1,40 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::redis::RedisPoolInterface;
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RemoveNotificationFilterQuery {
+    id: i64,
+}
+
+#[utoipa::path(
+    delete,
+    operation_id = "Remove notification filter",
+    tag = "Notification",
+    path = "/api/notification-filters",
+    params(RemoveNotificationFilterQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully removed the notification filter"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<RemoveNotificationFilterQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    arc.pool
+        .delete_notification_filter(query.id, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
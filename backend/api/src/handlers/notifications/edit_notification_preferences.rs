@@ -0,0 +1,32 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::{models::notification::NotificationPreference, redis::RedisPoolInterface};
+
+#[utoipa::path(
+    put,
+    operation_id = "Edit notification preferences",
+    tag = "Notification",
+    path = "/api/notifications/preferences",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully edited the notification preferences", body = Vec<NotificationPreference>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<Vec<NotificationPreference>>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let preferences = arc
+        .pool
+        .update_notification_preferences(&form, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(preferences))
+}
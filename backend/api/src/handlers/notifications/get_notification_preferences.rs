@@ -0,0 +1,25 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{web::Data, HttpResponse};
+use arcadia_common::error::Result;
+use arcadia_storage::{models::notification::NotificationPreference, redis::RedisPoolInterface};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get notification preferences",
+    tag = "Notification",
+    path = "/api/notifications/preferences",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully got the notification preferences", body = Vec<NotificationPreference>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let preferences = arc.pool.find_notification_preferences(user.sub).await?;
+
+    Ok(HttpResponse::Ok().json(preferences))
+}
@@ -0,0 +1,36 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::{
+    models::{
+        common::PaginatedResults,
+        notification::{Notification, NotificationQuery},
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get notifications",
+    tag = "Notification",
+    path = "/api/notifications",
+    params (NotificationQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully got the notifications", body = PaginatedResults<Notification>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<NotificationQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let notifications = arc.pool.find_notifications(user.sub, &query).await?;
+
+    Ok(HttpResponse::Ok().json(notifications))
+}
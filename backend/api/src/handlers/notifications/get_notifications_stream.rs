@@ -0,0 +1,176 @@
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::push_service::{PushEvent, PushMessage},
+    Arcadia,
+};
+use actix_web::{web::Data, Responder};
+use actix_web_lab::sse::{self, Sse};
+use arcadia_common::error::Result;
+use arcadia_storage::redis::RedisPoolInterface;
+use chrono::Utc;
+use futures_util::{future, stream};
+use serde_json::json;
+use std::{collections::VecDeque, time::Duration};
+use tokio::{
+    sync::broadcast::{error::RecvError, Receiver},
+    time::{interval_at, sleep_until, Instant, Interval},
+};
+
+const KEEP_ALIVE_PERIOD: Duration = Duration::from_secs(15);
+// how often a stream checks that its user was not banned nor its token invalidated in the meantime
+const USER_CHECK_PERIOD: Duration = Duration::from_secs(60);
+
+struct PushStream<R: RedisPoolInterface> {
+    arc: Data<Arcadia<R>>,
+    receiver: Receiver<PushMessage>,
+    user_id: i32,
+    // when the token the stream was opened with expires
+    expires_at: Option<Instant>,
+    // when the token the stream was opened with was issued, a logout or a password change
+    // invalidates it
+    issued_at: Option<i64>,
+    user_check: Interval,
+    last_notification_id: i64,
+    pending: VecDeque<sse::Event>,
+}
+
+impl<R: RedisPoolInterface> PushStream<R> {
+    async fn queue_events(&mut self, event: Option<PushEvent>) -> Result<()> {
+        match event {
+            Some(PushEvent::ConversationMessage(message)) => self.pending.push_back(
+                sse::Data::new_json(message)?
+                    .event("conversation_message")
+                    .into(),
+            ),
+            Some(PushEvent::StaffPmMessage(message)) => self.pending.push_back(
+                sse::Data::new_json(message)?
+                    .event("staff_pm_message")
+                    .into(),
+            ),
+            // a lagging stream may have missed its notifications, so it catches up as well
+            Some(PushEvent::Notifications) | None => {
+                let notifications = self
+                    .arc
+                    .pool
+                    .find_notifications_after(self.user_id, self.last_notification_id)
+                    .await?;
+                for notification in notifications {
+                    self.last_notification_id = notification.id;
+                    self.pending.push_back(
+                        sse::Data::new_json(notification)?
+                            .event("notification")
+                            .into(),
+                    );
+                }
+            }
+        }
+
+        let unread_conversations_amount = self
+            .arc
+            .pool
+            .find_unread_conversations_amount(self.user_id)
+            .await?;
+        let unread_notifications_amount = self
+            .arc
+            .pool
+            .find_unread_notifications_amount(self.user_id)
+            .await?;
+        self.pending.push_back(
+            sse::Data::new_json(json!({
+                "unread_conversations_amount": unread_conversations_amount,
+                "unread_notifications_amount": unread_notifications_amount,
+            }))?
+            .event("unread_amounts")
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    async fn next_event(&mut self) -> Option<Result<sse::Event>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(Ok(event));
+            }
+            let expires_at = self.expires_at;
+            let received = tokio::select! {
+                received = self.receiver.recv() => received,
+                _ = self.user_check.tick() => {
+                    match self.arc.pool.find_user_with_id(self.user_id).await {
+                        Ok(user) if user.banned => return None,
+                        Ok(_) => {}
+                        Err(error) => return Some(Err(error)),
+                    }
+                    if let Some(issued_at) = self.issued_at {
+                        match self.arc.auth.is_invalidated(self.user_id, issued_at).await {
+                            Ok(true) => return None,
+                            Ok(false) => {}
+                            Err(error) => return Some(Err(error)),
+                        }
+                    }
+                    continue;
+                }
+                _ = async {
+                    match expires_at {
+                        Some(expires_at) => sleep_until(expires_at).await,
+                        None => future::pending().await,
+                    }
+                } => return None,
+            };
+            let event = match received {
+                Ok(message) if message.user_ids.contains(&self.user_id) => Some(message.event),
+                Ok(_) => continue,
+                Err(RecvError::Lagged(_)) => None,
+                Err(RecvError::Closed) => return None,
+            };
+            if let Err(error) = self.queue_events(event).await {
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    operation_id = "Stream notifications",
+    tag = "Notification",
+    path = "/api/notifications/stream",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Server-sent events: notification, conversation_message, staff_pm_message and unread_amounts. \
+            The stream ends when the token it was opened with expires or gets invalidated, or when the user is banned. \
+            Browsers' EventSource cannot send the Authorization header, clients are expected to read the stream with fetch \
+            (e.g. @microsoft/fetch-event-source) and to reconnect with a refreshed token", content_type = "text/event-stream"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<impl Responder> {
+    let expires_at = user.exp.map(|exp| {
+        let remaining = (exp - Utc::now().timestamp()).max(0) as u64;
+        Instant::now() + Duration::from_secs(remaining)
+    });
+
+    let push_stream = PushStream {
+        receiver: arc.push_sender.subscribe(),
+        last_notification_id: arc.pool.find_last_notification_id(user.sub).await?,
+        arc,
+        user_id: user.sub,
+        expires_at,
+        issued_at: user.iat,
+        user_check: interval_at(Instant::now() + USER_CHECK_PERIOD, USER_CHECK_PERIOD),
+        pending: VecDeque::new(),
+    };
+
+    let events = stream::unfold(push_stream, |mut push_stream| async move {
+        push_stream
+            .next_event()
+            .await
+            .map(|event| (event, push_stream))
+    });
+
+    Ok(Sse::from_stream(events).with_keep_alive(KEEP_ALIVE_PERIOD))
+}
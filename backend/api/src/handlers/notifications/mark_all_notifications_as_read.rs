@@ -0,0 +1,26 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{web::Data, HttpResponse};
+use arcadia_common::error::Result;
+use arcadia_storage::redis::RedisPoolInterface;
+use serde_json::json;
+
+#[utoipa::path(
+    put,
+    operation_id = "Mark all notifications as read",
+    tag = "Notification",
+    path = "/api/notifications/read-all",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully marked all the notifications as read"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    arc.pool.mark_all_notifications_as_read(user.sub).await?;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
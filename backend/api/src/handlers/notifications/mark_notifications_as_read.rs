@@ -0,0 +1,32 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::{models::notification::NotificationsToMarkAsRead, redis::RedisPoolInterface};
+use serde_json::json;
+
+#[utoipa::path(
+    put,
+    operation_id = "Mark notifications as read",
+    tag = "Notification",
+    path = "/api/notifications/read",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully marked the notifications as read"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<NotificationsToMarkAsRead>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    arc.pool
+        .mark_notifications_as_read(&form.notification_ids, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
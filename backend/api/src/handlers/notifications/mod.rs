@@ -1,11 +1,28 @@
-pub mod get_notifications_forum_thread_posts;
+pub mod edit_notification_preferences;
+pub mod get_notification_preferences;
+pub mod get_notifications;
+pub mod get_notifications_stream;
+pub mod mark_all_notifications_as_read;
+pub mod mark_notifications_as_read;
+pub mod remove_notification;
 
-use actix_web::web::{get, resource, ServiceConfig};
+use actix_web::web::{delete, get, put, resource, ServiceConfig};
 use arcadia_storage::redis::RedisPoolInterface;
 
 pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
     cfg.service(
-        resource("/forum-thread-posts")
-            .route(get().to(self::get_notifications_forum_thread_posts::exec::<R>)),
+        resource("")
+            .route(get().to(self::get_notifications::exec::<R>))
+            .route(delete().to(self::remove_notification::exec::<R>)),
+    );
+    cfg.service(resource("/stream").route(get().to(self::get_notifications_stream::exec::<R>)));
+    cfg.service(resource("/read").route(put().to(self::mark_notifications_as_read::exec::<R>)));
+    cfg.service(
+        resource("/read-all").route(put().to(self::mark_all_notifications_as_read::exec::<R>)),
+    );
+    cfg.service(
+        resource("/preferences")
+            .route(get().to(self::get_notification_preferences::exec::<R>))
+            .route(put().to(self::edit_notification_preferences::exec::<R>)),
     );
 }
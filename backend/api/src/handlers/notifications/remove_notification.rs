@@ -0,0 +1,38 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::redis::RedisPoolInterface;
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RemoveNotificationQuery {
+    id: i64,
+}
+
+#[utoipa::path(
+    delete,
+    operation_id = "Remove notification",
+    tag = "Notification",
+    path = "/api/notifications",
+    params(RemoveNotificationQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully removed the notification"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<RemoveNotificationQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    arc.pool.delete_notification(query.id, user.sub).await?;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
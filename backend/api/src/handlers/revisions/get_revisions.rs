@@ -0,0 +1,36 @@
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+
+use crate::Arcadia;
+use arcadia_common::error::Result;
+use arcadia_storage::{
+    models::revision::{Revision, RevisionHistoryQuery},
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get revisions",
+    tag = "Revision",
+    path = "/api/revisions",
+    params (RevisionHistoryQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Edit history of the resource, newest first", body=Vec<Revision>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<RevisionHistoryQuery>,
+    arc: Data<Arcadia<R>>,
+) -> Result<HttpResponse> {
+    let revisions = arc
+        .pool
+        .find_revisions(query.resource_type, query.resource_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(revisions))
+}
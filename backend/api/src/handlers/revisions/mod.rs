@@ -0,0 +1,10 @@
+pub mod get_revisions;
+pub mod revert_revision;
+
+use actix_web::web::{get, post, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(resource("").route(get().to(self::get_revisions::exec::<R>)));
+    cfg.service(resource("/revert").route(post().to(self::revert_revision::exec::<R>)));
+}
@@ -0,0 +1,90 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    middlewares::auth_middleware::Authdata, services::upload_rule_service::validate_torrent_edit,
+    Arcadia,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        revision::{RevisionResourceType, RevisionToRevert},
+        torrent::EditedTorrent,
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+fn parse_snapshot<T: DeserializeOwned>(snapshot: Value) -> Result<T> {
+    serde_json::from_value(snapshot).map_err(|e| Error::InvalidRevisionSnapshot(e.to_string()))
+}
+
+#[utoipa::path(
+    post,
+    operation_id = "Revert to revision",
+    tag = "Revision",
+    path = "/api/revisions/revert",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Restored the resource as it was after this revision, the revert is itself saved as a new revision. Returns the updated resource"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<RevisionToRevert>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let revision = arc.pool.find_revision(form.revision_id).await?;
+    let resource_id = revision.resource_id;
+    let snapshot = revision.snapshot;
+
+    let response = match revision.resource_type {
+        RevisionResourceType::Torrent => {
+            let torrent = arc.pool.find_torrent(resource_id as i32).await?;
+            let mut reverted_torrent: EditedTorrent = parse_snapshot(snapshot)?;
+            // a revert doesn't undo a move or a split, the torrent stays in its edition group
+            reverted_torrent.edition_group_id = torrent.edition_group_id;
+
+            validate_torrent_edit(&arc, &torrent, &reverted_torrent, torrent.edition_group_id)
+                .await?;
+
+            HttpResponse::Ok().json(
+                arc.pool
+                    .update_torrent(&reverted_torrent, torrent.id, user.sub)
+                    .await?,
+            )
+        }
+        RevisionResourceType::EditionGroup => HttpResponse::Ok().json(
+            arc.pool
+                .update_edition_group(&parse_snapshot(snapshot)?, resource_id as i32, user.sub)
+                .await?,
+        ),
+        RevisionResourceType::TitleGroup => HttpResponse::Ok().json(
+            arc.pool
+                .update_title_group(&parse_snapshot(snapshot)?, resource_id as i32, user.sub)
+                .await?,
+        ),
+        RevisionResourceType::Artist => HttpResponse::Ok().json(
+            arc.pool
+                .update_artist(&parse_snapshot(snapshot)?, resource_id, user.sub)
+                .await?,
+        ),
+        RevisionResourceType::Series => HttpResponse::Ok().json(
+            arc.pool
+                .update_series(&parse_snapshot(snapshot)?, resource_id, user.sub)
+                .await?,
+        ),
+    };
+
+    Ok(response)
+}
@@ -0,0 +1,43 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_storage::{
+    models::saved_torrent_search::{SavedTorrentSearch, UserCreatedSavedTorrentSearch},
+    redis::RedisPoolInterface,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+
+#[utoipa::path(
+    post,
+    operation_id = "Create saved torrent search",
+    tag = "Saved Torrent Search",
+    path = "/api/saved-torrent-searches",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 201, description = "Successfully saved the torrent search", body=SavedTorrentSearch),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<UserCreatedSavedTorrentSearch>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    // snatches are not tracked yet, a feed couldn't list only the snatched torrents
+    if form.search.torrent_snatched_by_id.is_some() {
+        return Err(Error::BadRequest(
+            "a saved search can't filter on snatched torrents".into(),
+        ));
+    }
+
+    let saved_search = arc
+        .pool
+        .create_saved_torrent_search(&form, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Created().json(saved_search))
+}
@@ -0,0 +1,28 @@
+use actix_web::{web::Data, HttpResponse};
+use arcadia_storage::{
+    models::saved_torrent_search::SavedTorrentSearch, redis::RedisPoolInterface,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::Result;
+
+#[utoipa::path(
+    get,
+    operation_id = "Get saved torrent searches",
+    tag = "Saved Torrent Search",
+    path = "/api/saved-torrent-searches",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "The torrent searches saved by the user", body=Vec<SavedTorrentSearch>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let saved_searches = arc.pool.find_saved_torrent_searches(user.sub).await?;
+
+    Ok(HttpResponse::Ok().json(saved_searches))
+}
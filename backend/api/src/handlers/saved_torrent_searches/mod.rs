@@ -0,0 +1,15 @@
+pub mod create_saved_torrent_search;
+pub mod get_saved_torrent_searches;
+pub mod remove_saved_torrent_search;
+
+use actix_web::web::{delete, get, post, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(
+        resource("")
+            .route(post().to(self::create_saved_torrent_search::exec::<R>))
+            .route(get().to(self::get_saved_torrent_searches::exec::<R>))
+            .route(delete().to(self::remove_saved_torrent_search::exec::<R>)),
+    );
+}
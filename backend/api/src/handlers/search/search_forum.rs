@@ -1,4 +1,4 @@
-use crate::Arcadia;
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
 use actix_web::{
     web::{Data, Query},
     HttpResponse,
@@ -26,8 +26,9 @@ use arcadia_storage::{
 pub async fn exec<R: RedisPoolInterface + 'static>(
     query: Query<ForumSearchQuery>,
     arc: Data<Arcadia<R>>,
+    user: Authdata,
 ) -> Result<HttpResponse> {
-    let results = arc.pool.search_forum_threads(&query).await?;
+    let results = arc.pool.search_forum_threads(&query, &user.class).await?;
 
     Ok(HttpResponse::Ok().json(results))
 }
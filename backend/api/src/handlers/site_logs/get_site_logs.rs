@@ -0,0 +1,36 @@
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+
+use crate::Arcadia;
+use arcadia_common::error::Result;
+use arcadia_storage::{
+    models::{
+        common::PaginatedResults,
+        site_log::{SiteLog, SiteLogQuery},
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get site logs",
+    tag = "Site Log",
+    path = "/api/site-logs",
+    params (SiteLogQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Actions performed by staff on the site, newest first", body=PaginatedResults<SiteLog>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<SiteLogQuery>,
+    arc: Data<Arcadia<R>>,
+) -> Result<HttpResponse> {
+    let site_logs = arc.pool.find_site_logs(&query).await?;
+
+    Ok(HttpResponse::Ok().json(site_logs))
+}
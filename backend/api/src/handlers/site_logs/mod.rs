@@ -0,0 +1,8 @@
+pub mod get_site_logs;
+
+use actix_web::web::{get, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(resource("").route(get().to(self::get_site_logs::exec::<R>)));
+}
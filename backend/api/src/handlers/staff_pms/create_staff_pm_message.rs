@@ -1,4 +1,8 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::push_service::{self, PushEvent, PushMessage},
+    Arcadia,
+};
 use actix_web::{
     web::{Data, Json},
     HttpResponse,
@@ -28,10 +32,23 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
     let is_staff = user.class == UserClass::Staff;
     // Allow creator (non-staff) to reply only to their own thread, and staff to any. We'll rely on DB check in get step.
     // Quick check: ensure user has access to thread
-    let _ = arc
+    let staff_pm = arc
         .pool
         .get_staff_pm(message.staff_pm_id, user.sub, is_staff)
         .await?;
     let created = arc.pool.create_staff_pm_message(&message, user.sub).await?;
+
+    // the creator of the staff PM is told about the replies of the staff
+    if let Some(creator_id) = staff_pm["created_by"]["id"]
+        .as_i64()
+        .map(|id| id as i32)
+        .filter(|id| *id != user.sub)
+    {
+        let push_message = PushMessage {
+            user_ids: vec![creator_id],
+            event: PushEvent::StaffPmMessage(created.clone()),
+        };
+        push_service::publish(arc.redis_pool.as_ref(), &push_message).await;
+    }
     Ok(HttpResponse::Created().json(created))
 }
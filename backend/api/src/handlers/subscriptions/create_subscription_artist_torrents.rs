@@ -0,0 +1,39 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::redis::RedisPoolInterface;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AddSubscriptionArtistTorrentsQuery {
+    pub artist_id: i64,
+}
+
+#[utoipa::path(
+    post,
+    operation_id = "Create artist torrents subscription",
+    tag = "Subscription",
+    path = "/api/subscriptions/artist-torrents",
+    params (AddSubscriptionArtistTorrentsQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully subscribed to the item"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<AddSubscriptionArtistTorrentsQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    arc.pool
+        .create_subscription_artist_torrents(query.artist_id, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Created().json(serde_json::json!({"result": "success"})))
+}
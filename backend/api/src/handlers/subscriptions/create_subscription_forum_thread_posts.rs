@@ -1,4 +1,8 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::forum_service::{check_forum_access, ForumAccess},
+    Arcadia,
+};
 use actix_web::{
     web::{Data, Query},
     HttpResponse,
@@ -31,6 +35,13 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
     arc: Data<Arcadia<R>>,
     user: Authdata,
 ) -> Result<HttpResponse> {
+    let forum_thread = arc.pool.find_forum_thread_raw(query.thread_id).await?;
+    let sub_category = arc
+        .pool
+        .find_forum_sub_category(forum_thread.forum_sub_category_id)
+        .await?;
+    check_forum_access(&sub_category, &user.class, ForumAccess::Read)?;
+
     arc.pool
         .create_subscription_forum_thread_posts(query.thread_id, user.sub)
         .await?;
@@ -0,0 +1,39 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::redis::RedisPoolInterface;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AddSubscriptionSeriesTorrentsQuery {
+    pub series_id: i64,
+}
+
+#[utoipa::path(
+    post,
+    operation_id = "Create series torrents subscription",
+    tag = "Subscription",
+    path = "/api/subscriptions/series-torrents",
+    params (AddSubscriptionSeriesTorrentsQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully subscribed to the item"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<AddSubscriptionSeriesTorrentsQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    arc.pool
+        .create_subscription_series_torrents(query.series_id, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Created().json(serde_json::json!({"result": "success"})))
+}
@@ -1,6 +1,10 @@
+pub mod create_subscription_artist_torrents;
 pub mod create_subscription_forum_thread_posts;
+pub mod create_subscription_series_torrents;
 pub mod create_subscription_title_group_torrents;
+pub mod remove_subscription_artist_torrents;
 pub mod remove_subscription_forum_thread_posts;
+pub mod remove_subscription_series_torrents;
 pub mod remove_subscription_title_group_torrents;
 
 use actix_web::web::{delete, post, resource, ServiceConfig};
@@ -17,4 +21,14 @@ pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
             .route(post().to(self::create_subscription_title_group_torrents::exec::<R>))
             .route(delete().to(self::remove_subscription_title_group_torrents::exec::<R>)),
     );
+    cfg.service(
+        resource("/artist-torrents")
+            .route(post().to(self::create_subscription_artist_torrents::exec::<R>))
+            .route(delete().to(self::remove_subscription_artist_torrents::exec::<R>)),
+    );
+    cfg.service(
+        resource("/series-torrents")
+            .route(post().to(self::create_subscription_series_torrents::exec::<R>))
+            .route(delete().to(self::remove_subscription_series_torrents::exec::<R>)),
+    );
 }
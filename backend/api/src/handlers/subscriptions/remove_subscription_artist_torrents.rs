@@ -0,0 +1,37 @@
+use crate::{
+    handlers::subscriptions::create_subscription_artist_torrents::AddSubscriptionArtistTorrentsQuery,
+    middlewares::auth_middleware::Authdata, Arcadia,
+};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub type RemoveSubscriptionArtistTorrentsQuery = AddSubscriptionArtistTorrentsQuery;
+
+#[utoipa::path(
+    delete,
+    operation_id = "Remove artist torrents subscription",
+    tag = "Subscription",
+    path = "/api/subscriptions/artist-torrents",
+    params (RemoveSubscriptionArtistTorrentsQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully unsubscribed to the item"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<RemoveSubscriptionArtistTorrentsQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    arc.pool
+        .delete_subscription_artist_torrents(query.artist_id, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({"result": "success"})))
+}
@@ -0,0 +1,37 @@
+use crate::{
+    handlers::subscriptions::create_subscription_series_torrents::AddSubscriptionSeriesTorrentsQuery,
+    middlewares::auth_middleware::Authdata, Arcadia,
+};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::Result;
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub type RemoveSubscriptionSeriesTorrentsQuery = AddSubscriptionSeriesTorrentsQuery;
+
+#[utoipa::path(
+    delete,
+    operation_id = "Remove series torrents subscription",
+    tag = "Subscription",
+    path = "/api/subscriptions/series-torrents",
+    params (RemoveSubscriptionSeriesTorrentsQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully unsubscribed to the item"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<RemoveSubscriptionSeriesTorrentsQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    arc.pool
+        .delete_subscription_series_torrents(query.series_id, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({"result": "success"})))
+}
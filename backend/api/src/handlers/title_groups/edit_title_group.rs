@@ -36,6 +36,9 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         return Err(Error::InsufficientPrivileges);
     }
 
-    let updated_title_group = arc.pool.update_title_group(&form, title_group.id).await?;
+    let updated_title_group = arc
+        .pool
+        .update_title_group(&form, title_group.id, user.sub)
+        .await?;
     Ok(HttpResponse::Ok().json(updated_title_group))
 }
@@ -0,0 +1,59 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_storage::{
+    models::{
+        title_group::{TitleGroup, TitleGroupMerge},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+
+#[utoipa::path(
+    post,
+    operation_id = "Merge title groups",
+    tag = "Title Group",
+    path = "/api/title-groups/merge",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully merged the source title group into the target one", body=TitleGroup),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<TitleGroupMerge>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    if form.source_title_group_id == form.target_title_group_id {
+        return Err(Error::BadRequest(
+            "a title group cannot be merged into itself".into(),
+        ));
+    }
+
+    let source = arc
+        .pool
+        .find_title_group(form.source_title_group_id)
+        .await?;
+    let target = arc
+        .pool
+        .find_title_group(form.target_title_group_id)
+        .await?;
+
+    arc.pool
+        .merge_title_groups(&source, &target, user.sub)
+        .await?;
+
+    let merged_title_group = arc.pool.find_title_group(target.id).await?;
+
+    Ok(HttpResponse::Ok().json(merged_title_group))
+}
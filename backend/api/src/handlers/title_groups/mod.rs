@@ -3,6 +3,7 @@ pub mod create_title_group_comment;
 pub mod edit_title_group;
 pub mod get_title_group;
 pub mod get_title_group_info_lite;
+pub mod merge_title_groups;
 
 use actix_web::web::{get, post, put, resource, ServiceConfig};
 use arcadia_storage::redis::RedisPoolInterface;
@@ -14,6 +15,7 @@ pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
             .route(get().to(self::get_title_group::exec::<R>))
             .route(put().to(self::edit_title_group::exec::<R>)),
     );
+    cfg.service(resource("/merge").route(post().to(self::merge_title_groups::exec::<R>)));
     cfg.service(resource("/lite").route(post().to(self::get_title_group_info_lite::exec::<R>)));
     cfg.service(
         resource("/comments").route(post().to(self::create_title_group_comment::exec::<R>)),
@@ -0,0 +1,53 @@
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::torrent_client_service::{upsert_torrent_client_in_tracker, validate_torrent_client},
+    Arcadia,
+};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        torrent_client::{TorrentClient, UserCreatedTorrentClient},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Create torrent client",
+    tag = "Torrent Client",
+    path = "/api/torrent-clients",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 201, description = "Successfully created the torrent client rule", body=TorrentClient),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    client: Json<UserCreatedTorrentClient>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    validate_torrent_client(
+        &client.name,
+        &client.peer_id_prefix,
+        &client.min_version,
+        &client.max_version,
+        &client.user_agent_pattern,
+    )?;
+
+    let client = arc.pool.create_torrent_client(&client, user.sub).await?;
+
+    upsert_torrent_client_in_tracker(&arc.env.tracker, &client).await;
+
+    Ok(HttpResponse::Created().json(client))
+}
@@ -0,0 +1,53 @@
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::torrent_client_service::{upsert_torrent_client_in_tracker, validate_torrent_client},
+    Arcadia,
+};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        torrent_client::{EditedTorrentClient, TorrentClient},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "Edit torrent client",
+    tag = "Torrent Client",
+    path = "/api/torrent-clients",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully edited the torrent client rule", body=TorrentClient),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    client: Json<EditedTorrentClient>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    validate_torrent_client(
+        &client.name,
+        &client.peer_id_prefix,
+        &client.min_version,
+        &client.max_version,
+        &client.user_agent_pattern,
+    )?;
+
+    let client = arc.pool.update_torrent_client(&client).await?;
+
+    upsert_torrent_client_in_tracker(&arc.env.tracker, &client).await;
+
+    Ok(HttpResponse::Ok().json(client))
+}
@@ -0,0 +1,32 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{web::Data, HttpResponse};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{torrent_client::SeenTorrentClient, user::UserClass},
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get seen torrent clients",
+    tag = "Torrent Client",
+    path = "/api/torrent-clients/seen",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "User agents of the active peers, with their usage", body=Vec<SeenTorrentClient>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let clients = arc.pool.find_seen_torrent_clients().await?;
+
+    Ok(HttpResponse::Ok().json(clients))
+}
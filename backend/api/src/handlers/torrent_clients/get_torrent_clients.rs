@@ -0,0 +1,32 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{web::Data, HttpResponse};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{torrent_client::TorrentClient, user::UserClass},
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get torrent clients",
+    tag = "Torrent Client",
+    path = "/api/torrent-clients",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Rules deciding which torrent clients can announce", body=Vec<TorrentClient>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let clients = arc.pool.find_torrent_clients().await?;
+
+    Ok(HttpResponse::Ok().json(clients))
+}
@@ -0,0 +1,19 @@
+pub mod create_torrent_client;
+pub mod edit_torrent_client;
+pub mod get_seen_torrent_clients;
+pub mod get_torrent_clients;
+pub mod remove_torrent_client;
+
+use actix_web::web::{delete, get, post, put, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(
+        resource("")
+            .route(post().to(self::create_torrent_client::exec::<R>))
+            .route(get().to(self::get_torrent_clients::exec::<R>))
+            .route(put().to(self::edit_torrent_client::exec::<R>))
+            .route(delete().to(self::remove_torrent_client::exec::<R>)),
+    );
+    cfg.service(resource("/seen").route(get().to(self::get_seen_torrent_clients::exec::<R>)));
+}
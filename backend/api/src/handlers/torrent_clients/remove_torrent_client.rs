@@ -0,0 +1,47 @@
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::torrent_client_service::remove_torrent_client_from_tracker, Arcadia,
+};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{models::user::UserClass, redis::RedisPoolInterface};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RemoveTorrentClientQuery {
+    id: i32,
+}
+
+#[utoipa::path(
+    delete,
+    operation_id = "Remove torrent client",
+    tag = "Torrent Client",
+    path = "/api/torrent-clients",
+    params(RemoveTorrentClientQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully removed the torrent client rule"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<RemoveTorrentClientQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    arc.pool.delete_torrent_client(query.id).await?;
+
+    remove_torrent_client_from_tracker(&arc.env.tracker, query.id).await;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
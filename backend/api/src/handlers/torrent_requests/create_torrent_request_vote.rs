@@ -1,11 +1,16 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata, services::push_service::push_notifications, Arcadia,
+};
 use actix_web::{
     web::{Data, Json},
     HttpResponse,
 };
 use arcadia_common::error::Result;
 use arcadia_storage::{
-    models::torrent_request_vote::{TorrentRequestVote, UserCreatedTorrentRequestVote},
+    models::{
+        notification::NotificationType,
+        torrent_request_vote::{TorrentRequestVote, UserCreatedTorrentRequestVote},
+    },
     redis::RedisPoolInterface,
 };
 
@@ -30,6 +35,12 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         .pool
         .create_torrent_request_vote(&torrent_request_vote, user.sub)
         .await?;
+    push_notifications(
+        &arc,
+        &[NotificationType::TorrentRequestVoted],
+        vote.torrent_request_id,
+    )
+    .await;
 
     Ok(HttpResponse::Created().json(vote))
 }
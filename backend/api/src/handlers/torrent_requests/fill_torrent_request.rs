@@ -1,10 +1,15 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata, services::push_service::push_notifications, Arcadia,
+};
 use actix_web::{
     web::{Data, Json},
     HttpResponse,
 };
 use arcadia_common::error::Result;
-use arcadia_storage::{models::torrent_request::TorrentRequestFill, redis::RedisPoolInterface};
+use arcadia_storage::{
+    models::{notification::NotificationType, torrent_request::TorrentRequestFill},
+    redis::RedisPoolInterface,
+};
 use serde_json::json;
 
 #[utoipa::path(
@@ -31,6 +36,12 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
             user.sub,
         )
         .await?;
+    push_notifications(
+        &arc,
+        &[NotificationType::TorrentRequestFilled],
+        torrent_request_fill.torrent_request_id,
+    )
+    .await;
 
     Ok(HttpResponse::Ok().json(json!({"result": "succes"})))
 }
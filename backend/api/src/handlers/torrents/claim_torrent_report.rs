@@ -0,0 +1,44 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        torrent_report::{TorrentReport, TorrentReportToClaim},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Claim torrent report",
+    tag = "Torrent",
+    path = "/api/torrents/reports/claim",
+    request_body = TorrentReportToClaim,
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Torrent report claimed", body=TorrentReport),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<TorrentReportToClaim>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let report = arc
+        .pool
+        .claim_torrent_report(form.report_id, user.sub)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(report))
+}
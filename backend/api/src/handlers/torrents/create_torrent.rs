@@ -1,13 +1,26 @@
 use actix_multipart::form::MultipartForm;
 use actix_web::{web::Data, HttpResponse};
-use arcadia_shared::tracker::models::torrent::APIInsertTorrent;
-use log::debug;
-use reqwest::Client;
+use bip_metainfo::Metainfo;
 
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
-use arcadia_common::error::Result;
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::{
+        log_checker_service::check_log,
+        mediainfo_service::{apply_mediainfo_to_upload, parse_mediainfo},
+        push_service::push_notifications,
+        torrent_service::upsert_torrent_in_tracker,
+        trump_service::find_likely_trumped,
+        upload_rule_service::{validate_upload, RuledTorrent},
+    },
+    Arcadia,
+};
+use arcadia_common::error::{Error, Result};
 use arcadia_storage::{
-    models::torrent::{Torrent, UploadedTorrent},
+    models::{
+        notification::NotificationType,
+        torrent::{Torrent, UploadedTorrent},
+        torrent_trump::TorrentQuality,
+    },
     redis::RedisPoolInterface,
 };
 
@@ -31,38 +44,82 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
 ) -> Result<HttpResponse> {
     // TODO : check if user can upload
 
-    let torrent = arc.pool.create_torrent(&form, user.sub).await?;
+    let mut form = form.into_inner();
+    if let Some(mediainfo) = form.mediainfo.as_deref()
+        && !mediainfo.trim().is_empty()
+    {
+        let parsed = parse_mediainfo(mediainfo);
+        apply_mediainfo_to_upload(&mut form, &parsed)?;
+    }
 
-    let client = Client::new();
+    let rules = arc
+        .pool
+        .find_upload_rules_for_edition_group(form.edition_group_id.0)
+        .await?;
+    if !rules.is_empty() {
+        let metainfo =
+            Metainfo::from_bytes(&form.torrent_file.data).map_err(|_| Error::TorrentFileInvalid)?;
+        validate_upload(&rules, &RuledTorrent::from_upload(&form), metainfo.info())?;
+    }
 
-    let mut url = arc.env.tracker.url_internal.clone();
-    url.path_segments_mut()
-        .unwrap()
-        .push("api")
-        .push("torrents");
+    let logs = form
+        .log_files
+        .iter()
+        .map(|log_file| check_log(&log_file.data))
+        .collect::<Vec<_>>();
 
-    let payload = APIInsertTorrent {
-        id: torrent.id as u32,
-        info_hash: torrent.info_hash,
-        is_deleted: false,
-        seeders: 0,
-        leechers: 0,
-        times_completed: 0,
-        download_factor: torrent.upload_factor as u8,
-        upload_factor: torrent.download_factor as u8,
-    };
+    let torrent = arc
+        .pool
+        .create_torrent(
+            &form,
+            &logs,
+            arc.tracker.source(),
+            arc.hold_uploads_from_classes.contains(&user.class),
+            user.sub,
+        )
+        .await?;
 
-    let res = client
-        .put(url)
-        .header("x-api-key", arc.env.tracker.api_key.clone())
-        .json(&payload)
-        .send()
-        .await;
+    if let Some(rule) = arc
+        .pool
+        .find_trump_rule_for_edition_group(torrent.edition_group_id)
+        .await?
+    {
+        let others = arc
+            .pool
+            .find_edition_group_torrents_quality(torrent.edition_group_id)
+            .await?;
+        let uploaded = TorrentQuality {
+            id: torrent.id,
+            audio_bitrate_sampling: torrent.audio_bitrate_sampling,
+            audio_codec: torrent.audio_codec,
+            video_resolution: torrent.video_resolution,
+            video_codec: torrent.video_codec,
+            log_score: torrent.log_score,
+        };
+        let trumped = find_likely_trumped(&rule, &uploaded, &others);
+        if !trumped.is_empty() {
+            arc.pool
+                .flag_likely_trumped_torrents(&trumped, torrent.id)
+                .await?;
+        }
+    }
 
-    debug!(
-        "Tried to insert new torrent into tracker's db and got: {:?}",
-        res
-    );
+    // held uploads are sent to the tracker and users are notified once approved
+    if !torrent.held_from_tracker {
+        upsert_torrent_in_tracker(&arc.tracker, &torrent, false).await;
+
+        push_notifications(
+            &arc,
+            &[
+                NotificationType::TitleGroupTorrent,
+                NotificationType::ArtistTorrent,
+                NotificationType::SeriesTorrent,
+                NotificationType::FilteredTorrent,
+            ],
+            torrent.id as i64,
+        )
+        .await;
+    }
 
     Ok(HttpResponse::Created().json(torrent))
 }
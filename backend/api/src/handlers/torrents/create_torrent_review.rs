@@ -0,0 +1,78 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::{push_service::push_notifications, torrent_service::upsert_torrent_in_tracker},
+    Arcadia,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        notification::NotificationType,
+        torrent_review::{TorrentReview, TorrentReviewDecision, UserCreatedTorrentReview},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Create torrent review",
+    tag = "Torrent",
+    path = "/api/torrents/reviews",
+    request_body = UserCreatedTorrentReview,
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 201, description = "Torrent reviewed, the uploader is notified", body=TorrentReview),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<UserCreatedTorrentReview>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    if form.decision != TorrentReviewDecision::Approved
+        && form.reason.as_deref().is_none_or(|r| r.trim().is_empty())
+    {
+        return Err(Error::BadRequest(
+            "a reason must be given to the uploader".into(),
+        ));
+    }
+
+    let torrent = arc.pool.find_torrent(form.torrent_id).await?;
+    let review = arc.pool.review_torrent(&form, user.sub).await?;
+
+    match review.decision {
+        TorrentReviewDecision::Approved if torrent.held_from_tracker => {
+            upsert_torrent_in_tracker(&arc.tracker, &torrent, false).await
+        }
+        TorrentReviewDecision::Rejected => {
+            upsert_torrent_in_tracker(&arc.tracker, &torrent, true).await
+        }
+        _ => {}
+    }
+
+    push_notifications(
+        &arc,
+        &[
+            NotificationType::TorrentReviewed,
+            NotificationType::TitleGroupTorrent,
+            NotificationType::ArtistTorrent,
+            NotificationType::SeriesTorrent,
+            NotificationType::FilteredTorrent,
+        ],
+        torrent.id as i64,
+    )
+    .await;
+
+    Ok(HttpResponse::Created().json(review))
+}
@@ -0,0 +1,50 @@
+use actix_multipart::form::MultipartForm;
+use actix_web::{web::Data, HttpResponse};
+use arcadia_storage::{models::torrent::CrossSeedTorrent, redis::RedisPoolInterface};
+use bip_metainfo::Metainfo;
+
+use super::download_dottorrent_file::dottorrent_response;
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+
+#[utoipa::path(
+    post,
+    operation_id = "Cross-seed torrent",
+    tag = "Torrent",
+    path = "/api/torrents/cross-seed",
+    request_body(content = CrossSeedTorrent, content_type = "multipart/form-data"),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "The .torrent file of the torrent with the same files, ready to be seeded"),
+        (status = 404, description = "No torrent has the same files"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: MultipartForm<CrossSeedTorrent>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    let metainfo =
+        Metainfo::from_bytes(&form.torrent_file.data).map_err(|_| Error::TorrentFileInvalid)?;
+
+    let torrent_id = arc
+        .pool
+        .find_cross_seedable_torrent(metainfo.info())
+        .await?
+        .ok_or(Error::CrossSeedableTorrentNotFound)?;
+
+    let torrent = arc
+        .pool
+        .get_torrent(
+            user.sub,
+            torrent_id,
+            &arc.tracker.name,
+            arc.frontend_url.as_ref(),
+            arc.tracker.url.as_ref(),
+        )
+        .await?;
+
+    Ok(dottorrent_response(torrent))
+}
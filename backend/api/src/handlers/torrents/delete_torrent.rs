@@ -4,10 +4,16 @@ use actix_web::{
 };
 use serde_json::json;
 
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::{
+        push_service::push_notifications, torrent_service::deleted_torrent_displayed_reason,
+    },
+    Arcadia,
+};
 use arcadia_common::error::{Error, Result};
 use arcadia_storage::{
-    models::{torrent::TorrentToDelete, user::UserClass},
+    models::{notification::NotificationType, torrent::TorrentToDelete, user::UserClass},
     redis::RedisPoolInterface,
 };
 
@@ -33,24 +39,16 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
     }
 
     let current_user = arc.pool.find_user_with_id(user.sub).await?;
-    let user_url = &arc
-        .frontend_url
-        .join(&format!("/user/{}", user.sub))
-        .unwrap();
-    let displayed_reason = format!(
-        "A torrent you were a seeder on, has been deleted.
-  Please remove it from your torrent client.
-
-Reason: {}
-
-Handled by: [url={}]{}[/url]",
+    let displayed_reason = deleted_torrent_displayed_reason(
+        &arc.frontend_url,
         &form.reason,
-        &user_url.as_str(),
-        current_user.username
+        user.sub,
+        &current_user.username,
     );
 
     form.displayed_reason = Some(displayed_reason);
     arc.pool.remove_torrent(&form, user.sub).await?;
+    push_notifications(&arc, &[NotificationType::TorrentDeleted], form.id as i64).await;
 
     Ok(HttpResponse::Ok().json(json!({"result": "success"})))
 }
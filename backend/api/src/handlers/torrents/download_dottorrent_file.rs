@@ -5,7 +5,9 @@ use actix_web::{
     web::{Data, Query},
     HttpResponse,
 };
-use arcadia_storage::redis::RedisPoolInterface;
+use arcadia_storage::{
+    redis::RedisPoolInterface, repositories::torrent_repository::GetTorrentResult,
+};
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
 
@@ -46,6 +48,10 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         )
         .await?;
 
+    Ok(dottorrent_response(torrent))
+}
+
+pub(crate) fn dottorrent_response(torrent: GetTorrentResult) -> HttpResponse {
     let cd = ContentDisposition {
         disposition: DispositionType::Attachment,
         parameters: vec![
@@ -59,8 +65,8 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         ],
     };
 
-    Ok(HttpResponse::Ok()
+    HttpResponse::Ok()
         .insert_header(ContentType::octet_stream())
         .insert_header(cd)
-        .body(torrent.file_contents))
+        .body(torrent.file_contents)
 }
@@ -3,7 +3,10 @@ use actix_web::{
     HttpResponse,
 };
 
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata, services::upload_rule_service::validate_torrent_edit,
+    Arcadia,
+};
 use arcadia_common::error::{Error, Result};
 use arcadia_storage::{
     models::{
@@ -36,6 +39,8 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         return Err(Error::InsufficientPrivileges);
     }
 
-    let updated_torrent = arc.pool.update_torrent(&form, torrent.id).await?;
+    validate_torrent_edit(&arc, &torrent, &form, torrent.edition_group_id).await?;
+
+    let updated_torrent = arc.pool.update_torrent(&form, torrent.id, user.sub).await?;
     Ok(HttpResponse::Ok().json(updated_torrent))
 }
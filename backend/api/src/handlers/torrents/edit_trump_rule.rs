@@ -0,0 +1,38 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{torrent_trump::TrumpRule, user::UserClass},
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "Edit trump rule",
+    tag = "Torrent",
+    path = "/api/torrents/trump-rules",
+    request_body = TrumpRule,
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully edited the trump rule of the content type", body=TrumpRule),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    rule: Json<TrumpRule>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let rule = arc.pool.upsert_trump_rule(&rule).await?;
+
+    Ok(HttpResponse::Ok().json(rule))
+}
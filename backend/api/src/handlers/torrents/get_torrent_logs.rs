@@ -0,0 +1,38 @@
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_storage::{models::torrent_log::TorrentLog, redis::RedisPoolInterface};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::Result;
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct GetTorrentLogsQuery {
+    torrent_id: i32,
+}
+
+#[utoipa::path(
+    get,
+    operation_id = "Get torrent logs",
+    tag = "Torrent",
+    path = "/api/torrents/logs",
+    params (GetTorrentLogsQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Rip logs of the torrent with their score and deductions", body=Vec<TorrentLog>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<GetTorrentLogsQuery>,
+    arc: Data<Arcadia<R>>,
+    _: Authdata,
+) -> Result<HttpResponse> {
+    let logs = arc.pool.find_torrent_logs(query.torrent_id).await?;
+
+    Ok(HttpResponse::Ok().json(logs))
+}
@@ -0,0 +1,42 @@
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        common::PaginatedResults,
+        torrent_report::{TorrentReportInboxEntry, TorrentReportInboxQuery},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get torrent reports",
+    tag = "Torrent",
+    path = "/api/torrents/reports",
+    params (TorrentReportInboxQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Torrent reports matching the filters, oldest first", body=PaginatedResults<TorrentReportInboxEntry>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<TorrentReportInboxQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let reports = arc.pool.find_torrent_report_inbox(&query).await?;
+
+    Ok(HttpResponse::Ok().json(reports))
+}
@@ -0,0 +1,42 @@
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        common::PaginatedResults,
+        torrent_review::{TorrentReviewQueueQuery, TorrentToReview},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get torrent review queue",
+    tag = "Torrent",
+    path = "/api/torrents/review-queue",
+    params (TorrentReviewQueueQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Uploads not checked by staff yet, oldest first", body=PaginatedResults<TorrentToReview>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<TorrentReviewQueueQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let queue = arc.pool.find_torrent_review_queue(&query).await?;
+
+    Ok(HttpResponse::Ok().json(queue))
+}
@@ -0,0 +1,33 @@
+use actix_web::{web::Data, HttpResponse};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{torrent_review::TorrentReviewerStats, user::UserClass},
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get torrent reviewer stats",
+    tag = "Torrent",
+    path = "/api/torrents/reviews/stats",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Amount of reviews per decision, for each reviewer", body=Vec<TorrentReviewerStats>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let stats = arc.pool.find_torrent_reviewer_stats().await?;
+
+    Ok(HttpResponse::Ok().json(stats))
+}
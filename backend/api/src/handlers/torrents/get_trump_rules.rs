@@ -0,0 +1,26 @@
+use actix_web::{web::Data, HttpResponse};
+
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use arcadia_common::error::Result;
+use arcadia_storage::{models::torrent_trump::TrumpRule, redis::RedisPoolInterface};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get trump rules",
+    tag = "Torrent",
+    path = "/api/torrents/trump-rules",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Rankings used to flag likely trumps, per content type", body=Vec<TrumpRule>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    _: Authdata,
+) -> Result<HttpResponse> {
+    let rules = arc.pool.find_trump_rules().await?;
+
+    Ok(HttpResponse::Ok().json(rules))
+}
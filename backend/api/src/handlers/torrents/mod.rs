@@ -1,11 +1,24 @@
+pub mod claim_torrent_report;
 pub mod create_torrent;
 pub mod create_torrent_report;
+pub mod create_torrent_review;
+pub mod cross_seed_torrent;
 pub mod delete_torrent;
 pub mod download_dottorrent_file;
 pub mod edit_torrent;
+pub mod edit_trump_rule;
 pub mod get_registered_torrents;
 pub mod get_top_torrents;
+pub mod get_torrent_logs;
+pub mod get_torrent_reports;
+pub mod get_torrent_review_queue;
+pub mod get_torrent_reviewer_stats;
+pub mod get_trump_rules;
 pub mod get_upload_information;
+pub mod move_torrent;
+pub mod parse_mediainfo;
+pub mod resolve_torrent_report;
+pub mod trump_torrent;
 
 use actix_web::web::{delete, get, post, put, resource, ServiceConfig};
 use arcadia_storage::redis::RedisPoolInterface;
@@ -20,6 +33,31 @@ pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
     );
     cfg.service(resource("/registered").route(get().to(self::get_registered_torrents::exec::<R>)));
     cfg.service(resource("/upload-info").route(get().to(self::get_upload_information::exec::<R>)));
+    cfg.service(resource("/cross-seed").route(post().to(self::cross_seed_torrent::exec::<R>)));
+    cfg.service(resource("/move").route(post().to(self::move_torrent::exec::<R>)));
     cfg.service(resource("/top").route(get().to(self::get_top_torrents::exec::<R>)));
-    cfg.service(resource("/reports").route(post().to(self::create_torrent_report::exec::<R>)));
+    cfg.service(resource("/logs").route(get().to(self::get_torrent_logs::exec::<R>)));
+    cfg.service(
+        resource("/reports")
+            .route(post().to(self::create_torrent_report::exec::<R>))
+            .route(get().to(self::get_torrent_reports::exec::<R>)),
+    );
+    cfg.service(resource("/reports/claim").route(post().to(self::claim_torrent_report::exec::<R>)));
+    cfg.service(
+        resource("/reports/resolve").route(post().to(self::resolve_torrent_report::exec::<R>)),
+    );
+    cfg.service(
+        resource("/review-queue").route(get().to(self::get_torrent_review_queue::exec::<R>)),
+    );
+    cfg.service(resource("/reviews").route(post().to(self::create_torrent_review::exec::<R>)));
+    cfg.service(
+        resource("/reviews/stats").route(get().to(self::get_torrent_reviewer_stats::exec::<R>)),
+    );
+    cfg.service(resource("/parse-mediainfo").route(post().to(self::parse_mediainfo::exec::<R>)));
+    cfg.service(resource("/trump").route(post().to(self::trump_torrent::exec::<R>)));
+    cfg.service(
+        resource("/trump-rules")
+            .route(get().to(self::get_trump_rules::exec::<R>))
+            .route(put().to(self::edit_trump_rule::exec::<R>)),
+    );
 }
@@ -0,0 +1,63 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+
+use crate::{
+    middlewares::auth_middleware::Authdata, services::upload_rule_service::validate_torrent_edit,
+    Arcadia,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        torrent::{EditedTorrent, Torrent, TorrentToMove},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Move torrent",
+    tag = "Torrent",
+    path = "/api/torrents/move",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully moved the torrent to the edition group", body=Torrent),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<TorrentToMove>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let torrent = arc.pool.find_torrent(form.torrent_id).await?;
+    if torrent.edition_group_id == form.edition_group_id {
+        return Err(Error::BadRequest(
+            "the torrent already is in this edition group".into(),
+        ));
+    }
+    let edition_group = arc.pool.find_edition_group(form.edition_group_id).await?;
+    // the title group of the edition group can have another content type or category
+    validate_torrent_edit(
+        &arc,
+        &torrent,
+        &EditedTorrent::from(&torrent),
+        edition_group.id,
+    )
+    .await?;
+
+    arc.pool
+        .move_torrent(&torrent, &edition_group, user.sub)
+        .await?;
+
+    let moved_torrent = arc.pool.find_torrent(torrent.id).await?;
+
+    Ok(HttpResponse::Ok().json(moved_torrent))
+}
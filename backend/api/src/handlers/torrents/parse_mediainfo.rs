@@ -0,0 +1,30 @@
+use actix_web::{web::Json, HttpResponse};
+use arcadia_storage::{
+    models::mediainfo::{MediainfoToParse, ParsedMediainfo},
+    redis::RedisPoolInterface,
+};
+
+use crate::{middlewares::auth_middleware::Authdata, services::mediainfo_service::parse_mediainfo};
+use arcadia_common::error::Result;
+
+#[utoipa::path(
+    post,
+    operation_id = "Parse mediainfo",
+    tag = "Torrent",
+    path = "/api/torrents/parse-mediainfo",
+    request_body = MediainfoToParse,
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Technical fields read from the MediaInfo or BDInfo text", body=ParsedMediainfo),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    mediainfo: Json<MediainfoToParse>,
+    _: Authdata,
+) -> Result<HttpResponse> {
+    let parsed = parse_mediainfo(&mediainfo.mediainfo);
+
+    Ok(HttpResponse::Ok().json(parsed))
+}
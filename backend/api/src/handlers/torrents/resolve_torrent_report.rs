@@ -0,0 +1,95 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::{
+        push_service::push_notifications,
+        torrent_service::{deleted_torrent_displayed_reason, upsert_torrent_in_tracker},
+        upload_rule_service::validate_torrent_edit,
+    },
+    Arcadia,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        notification::NotificationType,
+        torrent_report::{TorrentReport, TorrentReportOutcome, TorrentReportResolution},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Resolve torrent report",
+    tag = "Torrent",
+    path = "/api/torrents/reports/resolve",
+    request_body = TorrentReportResolution,
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Outcome applied to the torrent, the reporter is notified", body=TorrentReport),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<TorrentReportResolution>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let report = arc.pool.find_torrent_report(form.report_id).await?;
+    if report.resolved_at.is_some() {
+        return Err(Error::TorrentReportAlreadyResolved);
+    }
+    if form.outcome == TorrentReportOutcome::Edited
+        && form
+            .edited_torrent
+            .as_ref()
+            .is_none_or(|edited| edited.id != report.reported_torrent_id)
+    {
+        return Err(Error::BadRequest(
+            "the edited reported torrent must be provided".into(),
+        ));
+    }
+
+    let reported_torrent = match form.outcome {
+        TorrentReportOutcome::Dismissed => None,
+        _ => Some(arc.pool.find_torrent(report.reported_torrent_id).await?),
+    };
+
+    // an edit made while resolving a report follows the upload rules, like any other edit
+    if form.outcome == TorrentReportOutcome::Edited
+        && let (Some(torrent), Some(edited_torrent)) = (&reported_torrent, &form.edited_torrent)
+    {
+        validate_torrent_edit(&arc, torrent, edited_torrent, torrent.edition_group_id).await?;
+    }
+
+    let current_user = arc.pool.find_user_with_id(user.sub).await?;
+    let displayed_reason = deleted_torrent_displayed_reason(
+        &arc.frontend_url,
+        &form.comment,
+        user.sub,
+        &current_user.username,
+    );
+
+    // the claim and the resolution are checked again while the report is locked
+    let report = arc
+        .pool
+        .resolve_torrent_report(&form, reported_torrent.as_ref(), displayed_reason, user.sub)
+        .await?;
+
+    if let (TorrentReportOutcome::Deleted, Some(torrent)) = (form.outcome, &reported_torrent) {
+        push_notifications(&arc, &[NotificationType::TorrentDeleted], torrent.id as i64).await;
+        upsert_torrent_in_tracker(&arc.tracker, torrent, true).await;
+    }
+    push_notifications(&arc, &[NotificationType::TorrentReportResolved], report.id).await;
+
+    Ok(HttpResponse::Ok().json(report))
+}
@@ -0,0 +1,51 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde_json::json;
+
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::{push_service::push_notifications, torrent_service::upsert_torrent_in_tracker},
+    Arcadia,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{notification::NotificationType, torrent_trump::TorrentToTrump, user::UserClass},
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Trump torrent",
+    tag = "Torrent",
+    path = "/api/torrents/trump",
+    request_body = TorrentToTrump,
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Torrent trumped and removed from the tracker, the users who downloaded it are notified"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    form: Json<TorrentToTrump>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let trumped_torrent = arc.pool.find_torrent(form.trumped_torrent_id).await?;
+    arc.pool.trump_torrent(&form, user.sub).await?;
+    upsert_torrent_in_tracker(&arc.tracker, &trumped_torrent, true).await;
+    push_notifications(
+        &arc,
+        &[NotificationType::TorrentTrumped],
+        form.trumped_torrent_id as i64,
+    )
+    .await;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
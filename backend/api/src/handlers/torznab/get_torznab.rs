@@ -0,0 +1,69 @@
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_storage::redis::RedisPoolInterface;
+
+use crate::{
+    services::{
+        feed_service::FeedLinks,
+        torznab_service::{
+            torznab_caps_response, torznab_error_response, torznab_results_response,
+            torznab_search_filter, TorznabFunction, TorznabQuery,
+        },
+    },
+    Arcadia,
+};
+use arcadia_common::error::{Error, Result};
+
+#[utoipa::path(
+    get,
+    operation_id = "Torznab",
+    tag = "Torznab",
+    path = "/api/torznab",
+    params (TorznabQuery),
+    responses(
+        (status = 200, description = "Torznab capabilities (t=caps) or search results, in xml"),
+        (status = 401, description = "Missing or invalid api key"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<TorznabQuery>,
+    arc: Data<Arcadia<R>>,
+) -> Result<HttpResponse> {
+    // the capabilities don't reveal anything about the content, indexer managers
+    // fetch them before the api key is set
+    if matches!(query.t, TorznabFunction::Caps) {
+        return Ok(torznab_caps_response(&arc.tracker.name));
+    }
+
+    let Some(api_key) = &query.apikey else {
+        return Ok(torznab_error_response(100, "Incorrect user credentials"));
+    };
+    let user = match arc.pool.find_user_id_with_api_key(api_key).await {
+        Ok(user) => user,
+        Err(Error::InvalidAPIKeyOrBanned) => {
+            return Ok(torznab_error_response(100, "Incorrect user credentials"))
+        }
+        Err(e) => return Ok(torznab_error_response(900, &e.to_string())),
+    };
+
+    let filter = match torznab_search_filter(&query) {
+        Ok(filter) => filter,
+        Err(e) => return Ok(torznab_error_response(201, &e.to_string())),
+    };
+    let torrents = match filter {
+        Some(filter) => arc.pool.find_feed_torrents(&filter).await?,
+        None => Vec::new(),
+    };
+
+    Ok(torznab_results_response(
+        &arc.tracker.name,
+        &torrents,
+        &FeedLinks {
+            frontend_url: &arc.frontend_url,
+            api_url: arc.api_url(),
+            passkey: &user.passkey,
+        },
+    ))
+}
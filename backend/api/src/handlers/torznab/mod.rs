@@ -0,0 +1,9 @@
+pub mod get_torznab;
+
+use actix_web::web::{get, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+// authenticated with the apikey in the query, as expected by torznab clients
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(resource("").route(get().to(self::get_torznab::exec::<R>)));
+}
@@ -0,0 +1,55 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{tracker_flag::TrackerFlag, user::UserClass},
+    redis::RedisPoolInterface,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, Serialize, IntoParams)]
+pub struct GetTrackerFlagsQuery {
+    pub user_id: Option<i32>,
+    pub include_resolved: Option<bool>,
+    pub limit: Option<i64>,
+    pub page: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    operation_id = "Get tracker flags",
+    tag = "Tracker Flag",
+    path = "/api/tracker-flags",
+    params(GetTrackerFlagsQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Suspicious announces detected by the tracker", body=Vec<TrackerFlag>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<GetTrackerFlagsQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let flags = arc
+        .pool
+        .find_tracker_flags(
+            query.user_id,
+            query.include_resolved.unwrap_or(false),
+            query.limit.unwrap_or(50),
+            query.page.unwrap_or(1),
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().json(flags))
+}
@@ -0,0 +1,10 @@
+pub mod get_tracker_flags;
+pub mod resolve_tracker_flag;
+
+use actix_web::web::{get, put, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(resource("").route(get().to(self::get_tracker_flags::exec::<R>)));
+    cfg.service(resource("/{id}/resolve").route(put().to(self::resolve_tracker_flag::exec::<R>)));
+}
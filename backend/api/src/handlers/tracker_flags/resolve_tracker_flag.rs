@@ -0,0 +1,40 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{tracker_flag::TrackerFlag, user::UserClass},
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "Resolve tracker flag",
+    tag = "Tracker Flag",
+    path = "/api/tracker-flags/{id}/resolve",
+    params(("id" = i64, Path, description = "Tracker flag id")),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully resolved the tracker flag", body=TrackerFlag),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    id: Path<i64>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    let flag = arc
+        .pool
+        .resolve_tracker_flag(id.into_inner(), user.sub)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(flag))
+}
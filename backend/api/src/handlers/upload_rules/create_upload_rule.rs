@@ -0,0 +1,46 @@
+use crate::{
+    middlewares::auth_middleware::Authdata,
+    services::upload_rule_service::{normalize_upload_rule, validate_upload_rule},
+    Arcadia,
+};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{
+    models::{
+        upload_rule::{UploadRule, UserCreatedUploadRule},
+        user::UserClass,
+    },
+    redis::RedisPoolInterface,
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "Create upload rule",
+    tag = "Upload Rule",
+    path = "/api/upload-rules",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 201, description = "Successfully saved the upload rule, replacing the one of the same content type and category", body=UploadRule),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    mut rule: Json<UserCreatedUploadRule>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    normalize_upload_rule(&mut rule);
+    validate_upload_rule(&rule)?;
+
+    let rule = arc.pool.upsert_upload_rule(&rule).await?;
+
+    Ok(HttpResponse::Created().json(rule))
+}
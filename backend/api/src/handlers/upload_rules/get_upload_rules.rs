@@ -0,0 +1,25 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{web::Data, HttpResponse};
+use arcadia_common::error::Result;
+use arcadia_storage::{models::upload_rule::UploadRule, redis::RedisPoolInterface};
+
+#[utoipa::path(
+    get,
+    operation_id = "Get upload rules",
+    tag = "Upload Rule",
+    path = "/api/upload-rules",
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Rules torrents are checked against on upload", body=Vec<UploadRule>),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    arc: Data<Arcadia<R>>,
+    _: Authdata,
+) -> Result<HttpResponse> {
+    let rules = arc.pool.find_upload_rules().await?;
+
+    Ok(HttpResponse::Ok().json(rules))
+}
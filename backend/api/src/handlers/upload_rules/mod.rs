@@ -0,0 +1,15 @@
+pub mod create_upload_rule;
+pub mod get_upload_rules;
+pub mod remove_upload_rule;
+
+use actix_web::web::{delete, get, post, resource, ServiceConfig};
+use arcadia_storage::redis::RedisPoolInterface;
+
+pub fn config<R: RedisPoolInterface + 'static>(cfg: &mut ServiceConfig) {
+    cfg.service(
+        resource("")
+            .route(post().to(self::create_upload_rule::exec::<R>))
+            .route(get().to(self::get_upload_rules::exec::<R>))
+            .route(delete().to(self::remove_upload_rule::exec::<R>)),
+    );
+}
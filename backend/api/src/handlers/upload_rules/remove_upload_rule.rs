@@ -0,0 +1,42 @@
+use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::{models::user::UserClass, redis::RedisPoolInterface};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RemoveUploadRuleQuery {
+    id: i32,
+}
+
+#[utoipa::path(
+    delete,
+    operation_id = "Remove upload rule",
+    tag = "Upload Rule",
+    path = "/api/upload-rules",
+    params(RemoveUploadRuleQuery),
+    security(
+      ("http" = ["Bearer"])
+    ),
+    responses(
+        (status = 200, description = "Successfully removed the upload rule"),
+    )
+)]
+pub async fn exec<R: RedisPoolInterface + 'static>(
+    query: Query<RemoveUploadRuleQuery>,
+    arc: Data<Arcadia<R>>,
+    user: Authdata,
+) -> Result<HttpResponse> {
+    if user.class != UserClass::Staff {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    arc.pool.delete_upload_rule(query.id).await?;
+
+    Ok(HttpResponse::Ok().json(json!({"result": "success"})))
+}
@@ -36,6 +36,7 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         title_group_include_empty_groups: false,
         torrent_reported: None,
         torrent_staff_checked: None,
+        torrent_log_score_min: None,
         torrent_created_by_id: Some(current_user.id),
         torrent_snatched_by_id: None,
         page: 1,
@@ -64,6 +65,10 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         .pool
         .find_unread_notifications_amount_forum_thread_posts(current_user.id)
         .await?;
+    let unread_notifications_amount = arc
+        .pool
+        .find_unread_notifications_amount(current_user.id)
+        .await?;
 
     Ok(HttpResponse::Ok().json(json!({
         "user": current_user,
@@ -71,6 +76,7 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         "user_warnings": user_warnings,
         "unread_conversations_amount": unread_conversations_amount,
         "unread_notifications_amount_forum_thread_posts":unread_notifications_amount_forum_thread_posts,
+        "unread_notifications_amount": unread_notifications_amount,
         "last_five_uploaded_torrents": uploaded_torrents.results,
         "last_five_snatched_torrents": snatched_torrents.results
     })))
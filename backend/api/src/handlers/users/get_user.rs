@@ -45,6 +45,7 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         title_group_include_empty_groups: false,
         torrent_reported: None,
         torrent_staff_checked: None,
+        torrent_log_score_min: None,
         torrent_created_by_id: Some(query.id),
         torrent_snatched_by_id: None,
         page: 1,
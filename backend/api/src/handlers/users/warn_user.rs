@@ -1,11 +1,16 @@
-use crate::{middlewares::auth_middleware::Authdata, Arcadia};
+use crate::{
+    middlewares::auth_middleware::Authdata, services::push_service::push_notifications, Arcadia,
+};
 use actix_web::{
     web::{Data, Json},
     HttpResponse,
 };
 use arcadia_common::error::{Error, Result};
 use arcadia_storage::{
-    models::user::{UserClass, UserCreatedUserWarning, UserWarning},
+    models::{
+        notification::NotificationType,
+        user::{UserClass, UserCreatedUserWarning, UserWarning},
+    },
     redis::RedisPoolInterface,
 };
 
@@ -30,6 +35,7 @@ pub async fn exec<R: RedisPoolInterface + 'static>(
         return Err(Error::InsufficientPrivileges);
     }
     let user_warning = arc.pool.create_user_warning(user.sub, &form).await?;
+    push_notifications(&arc, &[NotificationType::UserWarning], user_warning.id).await;
 
     if user_warning.ban {
         arc.auth.invalidate(user.sub).await?;
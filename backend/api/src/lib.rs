@@ -1,7 +1,14 @@
 use arcadia_storage::{connection_pool::ConnectionPool, redis::RedisPoolInterface};
 use std::{ops::Deref, str::FromStr, sync::Arc};
+use tokio::sync::broadcast;
 
-use crate::{env::Env, services::auth::Auth};
+use crate::{
+    env::Env,
+    services::{
+        auth::Auth,
+        push_service::{PushMessage, PUSH_BUFFER_SIZE},
+    },
+};
 
 pub mod api_doc;
 pub mod env;
@@ -34,6 +41,7 @@ pub struct Arcadia<R: RedisPoolInterface> {
     pub pool: Arc<ConnectionPool>,
     pub redis_pool: Arc<R>,
     pub auth: Auth<R>,
+    pub push_sender: broadcast::Sender<PushMessage>,
     env: Env,
 }
 
@@ -51,6 +59,7 @@ impl<R: RedisPoolInterface> Arcadia<R> {
             pool,
             redis_pool: Arc::clone(&redis_pool),
             auth: Auth::new(Arc::clone(&redis_pool)),
+            push_sender: broadcast::channel(PUSH_BUFFER_SIZE).0,
             env,
         }
     }
@@ -1,7 +1,9 @@
 use actix_cors::Cors;
 use actix_web::{middleware, web::Data, App, HttpServer};
 use arcadia_api::routes::init;
-use arcadia_api::{api_doc::ApiDoc, env::Env, Arcadia};
+use arcadia_api::{
+    api_doc::ApiDoc, env::Env, services::push_service::forward_push_messages, Arcadia,
+};
 use arcadia_storage::connection_pool::ConnectionPool;
 use arcadia_storage::redis::RedisPool;
 use envconfig::Envconfig;
@@ -54,6 +56,10 @@ async fn main() -> std::io::Result<()> {
         Arc::clone(&redis_pool),
         env,
     ));
+    tokio::spawn(forward_push_messages(
+        Arc::clone(&redis_pool),
+        arc.push_sender.clone(),
+    ));
     let server = HttpServer::new(move || {
         let cors = Cors::permissive();
         App::new()
@@ -17,6 +17,10 @@ use jsonwebtoken::{decode, errors::ErrorKind, DecodingKey, Validation};
 pub struct Authdata {
     pub sub: i32,
     pub class: UserClass,
+    // expiration timestamp of the jwt, api keys don't expire
+    pub exp: Option<i64>,
+    // issue timestamp of the jwt, to tell if it was invalidated since
+    pub iat: Option<i64>,
 }
 
 impl FromRequest for Authdata {
@@ -44,12 +48,22 @@ pub async fn authenticate_user<R: RedisPoolInterface + 'static>(
         return Ok(req);
     }
 
+    // feeds are fetched by feed readers and torrent clients, they authenticate with the passkey
+    if req.path().starts_with("/api/feeds/") {
+        return Ok(req);
+    }
+
+    // torznab clients send the user's api key in the query
+    if req.path() == "/api/torznab" {
+        return Ok(req);
+    }
+
     // it is a request from a user
     if let Some(bearer) = bearer {
         validate_bearer_auth::<R>(req, bearer).await
     } else if let Some(api_key) = req.headers().get("api_key") {
         let api_key = api_key.to_str().expect("api_key malformed").to_owned();
-        if req.path().starts_with("/api/tracker") {
+        if req.path().starts_with("/api/tracker/") {
             // it is a request from the tracker
             validate_tracker_api_key::<R>(req, &api_key)
         } else {
@@ -99,6 +113,8 @@ async fn validate_bearer_auth<R: RedisPoolInterface + 'static>(
             req.extensions_mut().insert(Authdata {
                 sub: user_id,
                 class: token_data.claims.class,
+                exp: Some(token_data.claims.exp),
+                iat: Some(token_data.claims.iat),
             });
         }
         Err(e) => return Err((ErrorUnauthorized(e.to_string()), req)),
@@ -121,6 +137,8 @@ async fn validate_user_api_key<R: RedisPoolInterface + 'static>(
     req.extensions_mut().insert(Authdata {
         sub: user.id,
         class: user.class,
+        exp: None,
+        iat: None,
     });
 
     Ok(req)
@@ -10,21 +10,31 @@ use crate::handlers::collages::config as CollagesConfig;
 use crate::handlers::conversations::config as ConversationsConfig;
 use crate::handlers::edition_groups::config as EditionGroupsConfig;
 use crate::handlers::external_db::config as ExternalDbConfig;
+use crate::handlers::feeds::config as FeedsConfig;
 use crate::handlers::forum::config as ForumConfig;
 use crate::handlers::gifts::config as GiftsConfig;
 use crate::handlers::home::config as HomeConfig;
 use crate::handlers::invitations::config as InvitationsConfig;
+use crate::handlers::ip_bans::config as IpBansConfig;
 use crate::handlers::master_groups::config as MasterGroupsConfig;
+use crate::handlers::notification_filters::config as NotificationFiltersConfig;
 use crate::handlers::notifications::config as NotificationsConfig;
+use crate::handlers::revisions::config as RevisionsConfig;
+use crate::handlers::saved_torrent_searches::config as SavedTorrentSearchesConfig;
 use crate::handlers::search::config as SearchConfig;
 use crate::handlers::series::config as SeriesConfig;
+use crate::handlers::site_logs::config as SiteLogsConfig;
 use crate::handlers::staff_pms::config as StaffPmsConfig;
 use crate::handlers::subscriptions::config as SubscriptionsConfig;
 use crate::handlers::title_group_bookmarks::config as BookmarksConfig;
 use crate::handlers::title_groups::config as TitleGroupsConfig;
+use crate::handlers::torrent_clients::config as TorrentClientsConfig;
 use crate::handlers::torrent_requests::config as TorrentRequestsConfig;
 use crate::handlers::torrents::config as TorrentsConfig;
+use crate::handlers::torznab::config as TorznabConfig;
 use crate::handlers::tracker::config as TrackerConfig;
+use crate::handlers::tracker_flags::config as TrackerFlagsConfig;
+use crate::handlers::upload_rules::config as UploadRulesConfig;
 use crate::handlers::user_applications::config as UserApplicationsConfig;
 use crate::handlers::users::config as UsersConfig;
 use crate::handlers::wiki::config as WikiConfig;
@@ -44,12 +54,14 @@ pub fn init<R: RedisPoolInterface + 'static>(cfg: &mut web::ServiceConfig) {
             .service(scope("/edition-groups").configure(EditionGroupsConfig::<R>))
             .service(scope("/search").configure(SearchConfig::<R>))
             .service(scope("/torrents").configure(TorrentsConfig::<R>))
+            .service(scope("/torrent-clients").configure(TorrentClientsConfig::<R>))
             .service(scope("/torrent-requests").configure(TorrentRequestsConfig::<R>))
             .service(scope("/artists").configure(ArtistsConfig::<R>))
             .service(scope("/affiliated-artists").configure(AffiliatedArtistsConfig::<R>))
             .service(scope("/conversations").configure(ConversationsConfig::<R>))
             .service(scope("/subscriptions").configure(SubscriptionsConfig::<R>))
             .service(scope("/notifications").configure(NotificationsConfig::<R>))
+            .service(scope("/notification-filters").configure(NotificationFiltersConfig::<R>))
             .service(scope("/series").configure(SeriesConfig::<R>))
             .service(scope("/external-sources").configure(ExternalDbConfig::<R>))
             .service(scope("/forum").configure(ForumConfig::<R>))
@@ -60,6 +72,14 @@ pub fn init<R: RedisPoolInterface + 'static>(cfg: &mut web::ServiceConfig) {
             .service(scope("/master-groups").configure(MasterGroupsConfig::<R>))
             .service(scope("/gifts").configure(GiftsConfig::<R>))
             .service(scope("/collages").configure(CollagesConfig::<R>))
+            .service(scope("/ip-bans").configure(IpBansConfig::<R>))
+            .service(scope("/tracker-flags").configure(TrackerFlagsConfig::<R>))
+            .service(scope("/upload-rules").configure(UploadRulesConfig::<R>))
+            .service(scope("/revisions").configure(RevisionsConfig::<R>))
+            .service(scope("/site-logs").configure(SiteLogsConfig::<R>))
+            .service(scope("/saved-torrent-searches").configure(SavedTorrentSearchesConfig::<R>))
+            .service(scope("/feeds").configure(FeedsConfig::<R>))
+            .service(scope("/torznab").configure(TorznabConfig::<R>))
             .service(scope("/tracker").configure(TrackerConfig::<R>)),
     );
 }
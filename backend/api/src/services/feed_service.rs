@@ -0,0 +1,167 @@
+use actix_web::HttpResponse;
+use arcadia_storage::models::feed::FeedTorrent;
+use atom_syndication::{ContentBuilder, EntryBuilder, FeedBuilder, LinkBuilder};
+use reqwest::Url;
+use rss::{ChannelBuilder, EnclosureBuilder, GuidBuilder, ItemBuilder};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+// amount of torrents listed in a feed
+pub const FEED_LENGTH: i64 = 50;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedFormat {
+    #[default]
+    Rss,
+    Atom,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct FeedQuery {
+    pub passkey: String,
+    #[serde(default)]
+    pub format: FeedFormat,
+}
+
+// links in the feed are authenticated with the passkey, as feed readers can't log in
+pub struct FeedLinks<'a> {
+    pub frontend_url: &'a Url,
+    pub api_url: &'a Url,
+    pub passkey: &'a str,
+}
+
+impl FeedLinks<'_> {
+    pub(crate) fn title_group_url(&self, torrent: &FeedTorrent) -> String {
+        format!(
+            "{}title-group/{}",
+            self.frontend_url, torrent.title_group_id
+        )
+    }
+
+    pub(crate) fn download_url(&self, torrent: &FeedTorrent) -> String {
+        let mut url = self.api_url.clone();
+        url.set_path("/api/feeds/download");
+        url.query_pairs_mut()
+            .append_pair("torrent_id", &torrent.id.to_string())
+            .append_pair("passkey", self.passkey);
+        url.to_string()
+    }
+}
+
+pub(crate) fn item_title(torrent: &FeedTorrent) -> String {
+    match torrent
+        .release_name
+        .as_ref()
+        .or(torrent.edition_group_name.as_ref())
+    {
+        Some(name) => format!("{} - {}", torrent.title_group_name, name),
+        None => torrent.title_group_name.clone(),
+    }
+}
+
+fn item_description(torrent: &FeedTorrent) -> String {
+    format!(
+        "Size: {} bytes, Seeders: {}, Leechers: {}, Snatches: {}, Tags: {}",
+        torrent.size,
+        torrent.seeders,
+        torrent.leechers,
+        torrent.times_completed,
+        torrent.tags.join(", ")
+    )
+}
+
+fn rss_feed(title: &str, torrents: &[FeedTorrent], links: &FeedLinks) -> String {
+    let items: Vec<_> = torrents
+        .iter()
+        .map(|torrent| {
+            ItemBuilder::default()
+                .title(item_title(torrent))
+                .link(links.title_group_url(torrent))
+                .description(item_description(torrent))
+                .guid(
+                    GuidBuilder::default()
+                        .value(format!("torrent-{}", torrent.id))
+                        .permalink(false)
+                        .build(),
+                )
+                .pub_date(torrent.created_at.to_rfc2822())
+                .enclosure(
+                    EnclosureBuilder::default()
+                        .url(links.download_url(torrent))
+                        .length(torrent.size.to_string())
+                        .mime_type("application/x-bittorrent")
+                        .build(),
+                )
+                .build()
+        })
+        .collect();
+
+    ChannelBuilder::default()
+        .title(title)
+        .link(links.frontend_url.to_string())
+        .description(title)
+        .items(items)
+        .build()
+        .to_string()
+}
+
+fn atom_feed(title: &str, torrents: &[FeedTorrent], links: &FeedLinks) -> String {
+    let entries: Vec<_> = torrents
+        .iter()
+        .map(|torrent| {
+            EntryBuilder::default()
+                .title(item_title(torrent))
+                .id(format!("torrent-{}", torrent.id))
+                .updated(torrent.created_at.fixed_offset())
+                .published(Some(torrent.created_at.fixed_offset()))
+                .links(vec![
+                    LinkBuilder::default()
+                        .href(links.title_group_url(torrent))
+                        .rel("alternate")
+                        .build(),
+                    LinkBuilder::default()
+                        .href(links.download_url(torrent))
+                        .rel("enclosure")
+                        .mime_type(Some("application/x-bittorrent".to_string()))
+                        .length(Some(torrent.size.to_string()))
+                        .build(),
+                ])
+                .content(
+                    ContentBuilder::default()
+                        .value(Some(item_description(torrent)))
+                        .build(),
+                )
+                .build()
+        })
+        .collect();
+
+    let updated = torrents
+        .first()
+        .map(|torrent| torrent.created_at.fixed_offset())
+        .unwrap_or_default();
+
+    FeedBuilder::default()
+        .title(title)
+        .id(links.frontend_url.to_string())
+        .updated(updated)
+        .entries(entries)
+        .build()
+        .to_string()
+}
+
+pub fn torrent_feed_response(
+    format: FeedFormat,
+    title: &str,
+    torrents: &[FeedTorrent],
+    links: &FeedLinks,
+) -> HttpResponse {
+    match format {
+        FeedFormat::Rss => HttpResponse::Ok()
+            .content_type("application/rss+xml; charset=utf-8")
+            .body(rss_feed(title, torrents, links)),
+        FeedFormat::Atom => HttpResponse::Ok()
+            .content_type("application/atom+xml; charset=utf-8")
+            .body(atom_feed(title, torrents, links)),
+    }
+}
@@ -0,0 +1,80 @@
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::models::{
+    forum::{ForumSubCategory, UserCreatedForumPoll},
+    user::UserClass,
+};
+use chrono::Utc;
+
+// used for the unread posts page computation when the client doesn't give its page size
+pub const DEFAULT_FORUM_POSTS_PAGE_SIZE: u32 = 10;
+
+const MAX_FORUM_POLL_OPTIONS: usize = 20;
+const MAX_FORUM_POLL_OPTION_LENGTH: usize = 200;
+
+pub enum ForumAccess {
+    Read,
+    Post,
+    CreateThread,
+}
+
+/// Checks that a user of the given class can perform the action in the
+/// sub-category. Posting or creating a thread also requires read access.
+pub fn check_forum_access(
+    sub_category: &ForumSubCategory,
+    user_class: &UserClass,
+    access: ForumAccess,
+) -> Result<()> {
+    let min_class = match access {
+        ForumAccess::Read => &sub_category.min_class_read,
+        ForumAccess::Post => &sub_category.min_class_post,
+        ForumAccess::CreateThread => &sub_category.min_class_create_thread,
+    };
+
+    if user_class.rank() < sub_category.min_class_read.rank()
+        || user_class.rank() < min_class.rank()
+    {
+        return Err(Error::InsufficientPrivileges);
+    }
+
+    Ok(())
+}
+
+pub fn validate_forum_poll(poll: &UserCreatedForumPoll) -> Result<()> {
+    if poll.question.trim().is_empty() {
+        return Err(Error::BadRequest("the poll needs a question".to_string()));
+    }
+    if poll.options.len() < 2 {
+        return Err(Error::BadRequest(
+            "the poll needs at least 2 options".to_string(),
+        ));
+    }
+    if poll.options.len() > MAX_FORUM_POLL_OPTIONS {
+        return Err(Error::BadRequest(format!(
+            "the poll cannot have more than {MAX_FORUM_POLL_OPTIONS} options"
+        )));
+    }
+    if poll.options.iter().any(|option| option.trim().is_empty()) {
+        return Err(Error::BadRequest(
+            "the poll options cannot be empty".to_string(),
+        ));
+    }
+    if poll
+        .options
+        .iter()
+        .any(|option| option.chars().count() > MAX_FORUM_POLL_OPTION_LENGTH)
+    {
+        return Err(Error::BadRequest(format!(
+            "the poll options cannot be longer than {MAX_FORUM_POLL_OPTION_LENGTH} characters"
+        )));
+    }
+    if poll
+        .closes_at
+        .is_some_and(|closes_at| closes_at <= Utc::now())
+    {
+        return Err(Error::BadRequest(
+            "the poll cannot close in the past".to_string(),
+        ));
+    }
+
+    Ok(())
+}
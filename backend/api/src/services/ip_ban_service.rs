@@ -0,0 +1,64 @@
+use actix_web::HttpRequest;
+use arcadia_common::error::{Error, Result};
+use arcadia_shared::tracker::models::ip_ban::APIInsertIpBan;
+use arcadia_storage::{models::ip_ban::IpBan, sqlx::types::ipnetwork::IpNetwork};
+use reqwest::Client;
+use std::net::IpAddr;
+
+use crate::env::TrackerConfig;
+
+// headers sent by the client can't be trusted, the ip is only read from the header
+// of the reverse proxy when one is configured (its last value is the one the proxy added)
+pub fn client_ip(req: &HttpRequest, reverse_proxy_header_name: Option<&str>) -> Result<IpNetwork> {
+    let ip = match reverse_proxy_header_name {
+        Some(header_name) => req
+            .headers()
+            .get(header_name)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|s| s.split(',').next_back())
+            .and_then(|s| s.trim().parse::<IpAddr>().ok()),
+        None => req.peer_addr().map(|addr| addr.ip()),
+    };
+
+    ip.map(IpNetwork::from)
+        .ok_or(Error::CouldNotDetermineClientIp)
+}
+
+// Fire and log; the tracker reloads every ban from the database on startup anyway
+pub async fn upsert_ip_ban_in_tracker(tracker: &TrackerConfig, ban: &IpBan) {
+    let mut url = tracker.url_internal.clone();
+    url.path_segments_mut().unwrap().push("api").push("ip-bans");
+
+    let payload = APIInsertIpBan {
+        id: ban.id as u32,
+        ip_range: ban.ip_range,
+    };
+
+    if let Err(e) = Client::new()
+        .put(url)
+        .header("x-api-key", tracker.api_key.clone())
+        .json(&payload)
+        .send()
+        .await
+    {
+        log::warn!("Failed to upsert ip ban in tracker: {}", e);
+    }
+}
+
+pub async fn remove_ip_ban_from_tracker(tracker: &TrackerConfig, ban_id: i32) {
+    let mut url = tracker.url_internal.clone();
+    url.path_segments_mut()
+        .unwrap()
+        .push("api")
+        .push("ip-bans")
+        .push(&ban_id.to_string());
+
+    if let Err(e) = Client::new()
+        .delete(url)
+        .header("x-api-key", tracker.api_key.clone())
+        .send()
+        .await
+    {
+        log::warn!("Failed to remove ip ban from tracker: {}", e);
+    }
+}
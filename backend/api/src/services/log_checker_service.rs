@@ -0,0 +1,488 @@
+use arcadia_storage::models::torrent_log::{CheckedLog, LogChecksum, LogDeduction, LogRipper};
+
+const MAX_SCORE: i16 = 100;
+
+/// Scores an EAC or XLD rip log out of 100, listing every deduction applied.
+/// Logs from other rippers are kept but scored 0.
+pub fn check_log(bytes: &[u8]) -> CheckedLog {
+    let log = decode_log(bytes);
+
+    let (ripper, deductions, checksum) = if log.contains("Exact Audio Copy") {
+        let (deductions, checksum) = check_eac_log(&log);
+        (LogRipper::Eac, deductions, checksum)
+    } else if log.contains("X Lossless Decoder") {
+        let (deductions, checksum) = check_xld_log(&log);
+        (LogRipper::Xld, deductions, checksum)
+    } else {
+        let deductions = vec![LogDeduction {
+            reason: "Unrecognized ripper, only EAC and XLD logs are supported".into(),
+            points: MAX_SCORE,
+        }];
+        (LogRipper::Unknown, deductions, LogChecksum::Missing)
+    };
+
+    let total: i32 = deductions.iter().map(|d| i32::from(d.points)).sum();
+
+    CheckedLog {
+        ripper,
+        log,
+        score: (i32::from(MAX_SCORE) - total).clamp(0, i32::from(MAX_SCORE)) as i16,
+        checksum,
+        deductions,
+    }
+}
+
+// EAC writes its logs in UTF-16LE, XLD in UTF-8
+fn decode_log(bytes: &[u8]) -> String {
+    if let Some(utf16) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        let units = utf16
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect::<Vec<_>>();
+        String::from_utf16_lossy(&units)
+    } else {
+        let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Track {
+    number: u32,
+    test_crc: Option<String>,
+    copy_crc: Option<String>,
+    suspicious: bool,
+}
+
+fn check_eac_log(log: &str) -> (Vec<LogDeduction>, LogChecksum) {
+    let mut deductions = Vec::new();
+
+    if setting(log, "Read mode") != Some("Secure") {
+        deduct(&mut deductions, "Read mode was not secure", 20);
+    }
+    if setting(log, "Defeat audio cache") != Some("Yes") {
+        deduct(&mut deductions, "Audio cache was not defeated", 10);
+    }
+    if setting(log, "Make use of C2 pointers") != Some("No") {
+        deduct(&mut deductions, "C2 pointers were used", 10);
+    }
+    if setting(log, "Read offset correction").is_none() {
+        deduct(&mut deductions, "Read offset correction is missing", 5);
+    }
+    if setting(log, "Null samples used in CRC calculations") != Some("Yes") {
+        deduct(
+            &mut deductions,
+            "Null samples were not used in CRC calculations",
+            5,
+        );
+    }
+    if log.contains("Range status and errors") {
+        deduct(&mut deductions, "Range rip", 30);
+    }
+
+    let tracks = parse_tracks(log, |line, track| {
+        if let Some(crc) = line.strip_prefix("Test CRC") {
+            track.test_crc = Some(crc.trim().to_uppercase());
+        } else if let Some(crc) = line.strip_prefix("Copy CRC") {
+            track.copy_crc = Some(crc.trim().to_uppercase());
+        } else if line.starts_with("Suspicious position") {
+            track.suspicious = true;
+        }
+    });
+    check_tracks(&tracks, &mut deductions);
+
+    let checksum = match log.split_once("==== Log checksum") {
+        Some((signed, rest)) => {
+            let expected = rest.split_whitespace().next().unwrap_or_default();
+            check_checksum(
+                Some(eac_checksum(signed).eq_ignore_ascii_case(expected)),
+                &mut deductions,
+            )
+        }
+        None => check_checksum(None, &mut deductions),
+    };
+
+    (deductions, checksum)
+}
+
+fn check_xld_log(log: &str) -> (Vec<LogDeduction>, LogChecksum) {
+    let mut deductions = Vec::new();
+
+    if !setting(log, "Ripper mode").is_some_and(|mode| mode.starts_with("XLD Secure Ripper")) {
+        deduct(&mut deductions, "Read mode was not secure", 20);
+    }
+    if setting(log, "Disable audio cache") != Some("OK") {
+        deduct(&mut deductions, "Audio cache was not disabled", 10);
+    }
+    if setting(log, "Use C2 Error Pointers") != Some("NO") {
+        deduct(&mut deductions, "C2 pointers were used", 10);
+    }
+    if setting(log, "Read offset correction").is_none() {
+        deduct(&mut deductions, "Read offset correction is missing", 5);
+    }
+
+    let tracks = parse_tracks(log, |line, track| {
+        let Some((key, value)) = line.split_once(':') else {
+            return;
+        };
+        match key.trim() {
+            "CRC32 hash (test run)" => track.test_crc = Some(value.trim().to_uppercase()),
+            "CRC32 hash" => track.copy_crc = Some(value.trim().to_uppercase()),
+            "Damaged sector count" if value.trim() != "0" => track.suspicious = true,
+            _ if key.trim().starts_with("Suspicious position") => track.suspicious = true,
+            _ => {}
+        }
+    });
+    check_tracks(&tracks, &mut deductions);
+
+    // the XLD signature is not verified yet
+    let checksum = if log.contains("-----BEGIN XLD SIGNATURE-----") {
+        deduct(&mut deductions, "Log checksum could not be verified", 5);
+        LogChecksum::Unverified
+    } else {
+        check_checksum(None, &mut deductions)
+    };
+
+    (deductions, checksum)
+}
+
+// valid is None when the log has no checksum
+fn check_checksum(valid: Option<bool>, deductions: &mut Vec<LogDeduction>) -> LogChecksum {
+    match valid {
+        Some(true) => LogChecksum::Valid,
+        Some(false) => {
+            deduct(
+                deductions,
+                "Log checksum does not match, the log was edited",
+                MAX_SCORE,
+            );
+            LogChecksum::Invalid
+        }
+        None => {
+            deduct(deductions, "Log checksum is missing", 15);
+            LogChecksum::Missing
+        }
+    }
+}
+
+fn check_tracks(tracks: &[Track], deductions: &mut Vec<LogDeduction>) {
+    if tracks.is_empty() {
+        deduct(deductions, "No ripped tracks found", MAX_SCORE);
+        return;
+    }
+    if tracks.iter().any(|track| track.test_crc.is_none()) {
+        deduct(deductions, "Test and copy was not used", 10);
+    }
+    for track in tracks {
+        if let (Some(test), Some(copy)) = (&track.test_crc, &track.copy_crc)
+            && test != copy
+        {
+            deduct(
+                deductions,
+                format!("Test and copy CRCs mismatch on track {}", track.number),
+                30,
+            );
+        }
+        if track.suspicious {
+            deduct(
+                deductions,
+                format!("Suspicious positions on track {}", track.number),
+                20,
+            );
+        }
+    }
+}
+
+// splits the log into its "Track N" sections and hands each of their lines
+// (trimmed) to read_line
+fn parse_tracks(log: &str, read_line: impl Fn(&str, &mut Track)) -> Vec<Track> {
+    let mut tracks: Vec<Track> = Vec::new();
+
+    for line in log.lines().map(str::trim) {
+        if let Some(number) = line
+            .strip_prefix("Track")
+            .and_then(|rest| rest.trim().parse::<u32>().ok())
+        {
+            tracks.push(Track {
+                number,
+                ..Default::default()
+            });
+        } else if let Some(track) = tracks.last_mut() {
+            read_line(line, track);
+        }
+    }
+
+    tracks
+}
+
+// value of the first "key : value" line of the log with this key
+fn setting<'a>(log: &'a str, key: &str) -> Option<&'a str> {
+    log.lines().find_map(|line| {
+        let (line_key, value) = line.split_once(':')?;
+        (line_key.trim() == key).then(|| value.trim())
+    })
+}
+
+fn deduct(deductions: &mut Vec<LogDeduction>, reason: impl Into<String>, points: i16) {
+    deductions.push(LogDeduction {
+        reason: reason.into(),
+        points,
+    });
+}
+
+// EAC signs the text of the log before its checksum line, without the line breaks,
+// encoded in UTF-16LE: it is encrypted with Rijndael in CBC mode, with 256 bit blocks,
+// a fixed key and a zero IV, and the last block of ciphertext is the checksum
+const EAC_CHECKSUM_KEY: [u8; 32] = [
+    0x93, 0x78, 0x71, 0x6c, 0xf1, 0x3e, 0x42, 0x65, 0xae, 0x55, 0x33, 0x8e, 0x94, 0x0b, 0x37, 0x61,
+    0x84, 0xda, 0x38, 0x9e, 0x50, 0x64, 0x77, 0x26, 0xb3, 0x5f, 0x6f, 0x34, 0x1e, 0xe3, 0xef, 0xd9,
+];
+
+fn eac_checksum(signed: &str) -> String {
+    let round_keys = rijndael_round_keys(&EAC_CHECKSUM_KEY);
+    let plaintext = signed
+        .chars()
+        .filter(|c| !matches!(c, '\r' | '\n' | '\u{feff}' | '\u{fffe}'))
+        .collect::<String>()
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect::<Vec<_>>();
+
+    let mut block = [0u8; RIJNDAEL_BLOCK];
+    // the last block is padded with zeroes
+    for chunk in plaintext.chunks(RIJNDAEL_BLOCK) {
+        for (state, byte) in block.iter_mut().zip(chunk) {
+            *state ^= byte;
+        }
+        rijndael_encrypt(&round_keys, &mut block);
+    }
+
+    block.iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
+// Rijndael with 256 bit blocks and keys, which AES doesn't cover (its blocks are 128 bit)
+const RIJNDAEL_BLOCK: usize = 32;
+const RIJNDAEL_ROUNDS: usize = 14;
+// left rotation of each row of the state, for 8 columns
+const RIJNDAEL_ROW_SHIFTS: [usize; 4] = [0, 1, 3, 4];
+
+#[rustfmt::skip]
+const RIJNDAEL_SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+fn xtime(byte: u8) -> u8 {
+    (byte << 1) ^ if byte & 0x80 != 0 { 0x1b } else { 0 }
+}
+
+fn rijndael_round_keys(key: &[u8; 32]) -> Vec<[u8; RIJNDAEL_BLOCK]> {
+    const KEY_WORDS: usize = 8;
+    let mut words = key
+        .chunks_exact(4)
+        .map(|word| [word[0], word[1], word[2], word[3]])
+        .collect::<Vec<_>>();
+    let mut rcon = 1u8;
+
+    for i in KEY_WORDS..(RIJNDAEL_ROUNDS + 1) * RIJNDAEL_BLOCK / 4 {
+        let mut word = words[i - 1];
+        if i % KEY_WORDS == 0 {
+            word.rotate_left(1);
+            word = word.map(|byte| RIJNDAEL_SBOX[byte as usize]);
+            word[0] ^= rcon;
+            rcon = xtime(rcon);
+        } else if i % KEY_WORDS == 4 {
+            word = word.map(|byte| RIJNDAEL_SBOX[byte as usize]);
+        }
+        for (byte, previous) in word.iter_mut().zip(words[i - KEY_WORDS]) {
+            *byte ^= previous;
+        }
+        words.push(word);
+    }
+
+    words
+        .chunks_exact(RIJNDAEL_BLOCK / 4)
+        .map(|round| {
+            let mut round_key = [0u8; RIJNDAEL_BLOCK];
+            round_key.copy_from_slice(round.as_flattened());
+            round_key
+        })
+        .collect()
+}
+
+// the state is stored column by column, as in the block
+fn rijndael_encrypt(round_keys: &[[u8; RIJNDAEL_BLOCK]], state: &mut [u8; RIJNDAEL_BLOCK]) {
+    let add_round_key = |state: &mut [u8; RIJNDAEL_BLOCK], round: usize| {
+        for (byte, key) in state.iter_mut().zip(round_keys[round]) {
+            *byte ^= key;
+        }
+    };
+    let columns = RIJNDAEL_BLOCK / 4;
+
+    add_round_key(state, 0);
+    for round in 1..=RIJNDAEL_ROUNDS {
+        let previous = state.map(|byte| RIJNDAEL_SBOX[byte as usize]);
+        for (row, shift) in RIJNDAEL_ROW_SHIFTS.iter().enumerate() {
+            for column in 0..columns {
+                state[4 * column + row] = previous[4 * ((column + shift) % columns) + row];
+            }
+        }
+        if round != RIJNDAEL_ROUNDS {
+            for column in state.chunks_exact_mut(4) {
+                let [a, b, c, d] = [column[0], column[1], column[2], column[3]];
+                column[0] = xtime(a) ^ xtime(b) ^ b ^ c ^ d;
+                column[1] = a ^ xtime(b) ^ xtime(c) ^ c ^ d;
+                column[2] = a ^ b ^ xtime(c) ^ xtime(d) ^ d;
+                column[3] = xtime(a) ^ a ^ b ^ c ^ xtime(d);
+            }
+        }
+        add_round_key(state, round);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_utf16(text: &str) -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+        bytes
+    }
+
+    #[test]
+    fn test_check_perfect_eac_log() {
+        let checked = check_log(&encode_utf16(include_str!("testdata/eac.log")));
+
+        assert_eq!(checked.ripper, LogRipper::Eac);
+        assert!(checked.deductions.is_empty());
+        assert_eq!(checked.score, 100);
+        assert_eq!(checked.checksum, LogChecksum::Valid);
+        assert!(checked.log.starts_with("Exact Audio Copy"));
+    }
+
+    #[test]
+    fn test_check_edited_eac_log() {
+        let log =
+            include_str!("testdata/eac.log").replace("Artist / Album", "Artist / Other Album");
+
+        let checked = check_log(&encode_utf16(&log));
+
+        assert_eq!(
+            checked.deductions,
+            vec![LogDeduction {
+                reason: "Log checksum does not match, the log was edited".into(),
+                points: 100,
+            }]
+        );
+        assert_eq!(checked.score, 0);
+        assert_eq!(checked.checksum, LogChecksum::Invalid);
+    }
+
+    #[test]
+    fn test_eac_checksum_ignores_line_breaks() {
+        let log = include_str!("testdata/eac.log");
+        let (signed, _) = log.split_once("==== Log checksum").unwrap();
+
+        assert_eq!(
+            eac_checksum(signed),
+            "8132758A06D2BAAEC8B505BB82B0F67ABA119D9125BED0AD73116F29C3D9DA65"
+        );
+        assert_eq!(
+            eac_checksum(&signed.replace('\n', "\r\n")),
+            eac_checksum(signed)
+        );
+    }
+
+    #[test]
+    fn test_check_eac_log_with_deductions() {
+        let log = include_str!("testdata/eac.log")
+            .replace(
+                "Read mode               : Secure",
+                "Read mode               : Burst",
+            )
+            .replace("Copy CRC 9A0B1C2D", "Copy CRC 9A0B1C2E");
+        let log = &log[..log.find("==== Log checksum").unwrap()];
+
+        let checked = check_log(log.as_bytes());
+
+        assert_eq!(
+            checked
+                .deductions
+                .iter()
+                .map(|d| (d.reason.as_str(), d.points))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Read mode was not secure", 20),
+                ("Test and copy CRCs mismatch on track 2", 30),
+                ("Log checksum is missing", 15),
+            ]
+        );
+        assert_eq!(checked.score, 35);
+        assert_eq!(checked.checksum, LogChecksum::Missing);
+    }
+
+    #[test]
+    fn test_check_perfect_xld_log() {
+        let checked = check_log(include_bytes!("testdata/xld.log"));
+
+        assert_eq!(checked.ripper, LogRipper::Xld);
+        assert_eq!(checked.deductions.len(), 1);
+        assert_eq!(checked.score, 95);
+        assert_eq!(checked.checksum, LogChecksum::Unverified);
+    }
+
+    #[test]
+    fn test_check_xld_log_with_damaged_sectors() {
+        let log = include_str!("testdata/xld.log").replacen(
+            "Damaged sector count                     : 0",
+            "Damaged sector count                     : 3",
+            1,
+        );
+
+        let checked = check_log(log.as_bytes());
+
+        assert_eq!(checked.score, 75);
+        assert_eq!(
+            checked.deductions[0].reason,
+            "Suspicious positions on track 1"
+        );
+    }
+
+    #[test]
+    fn test_check_log_score_does_not_go_below_zero() {
+        let log = include_str!("testdata/eac.log")
+            .replace(
+                "Read mode               : Secure",
+                "Read mode               : Burst",
+            )
+            .replace("Track", "Trak");
+
+        let checked = check_log(log.as_bytes());
+
+        assert!(checked.deductions.iter().map(|d| d.points).sum::<i16>() > 100);
+        assert_eq!(checked.score, 0);
+    }
+
+    #[test]
+    fn test_check_unknown_log() {
+        let checked = check_log(b"dBpoweramp Release 16.6 Digital Audio Extraction Log");
+
+        assert_eq!(checked.ripper, LogRipper::Unknown);
+        assert_eq!(checked.score, 0);
+    }
+}
@@ -0,0 +1,688 @@
+use actix_multipart::form::text::Text;
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::models::{
+    mediainfo::{MediainfoKind, ParsedMediainfo},
+    torrent::{
+        AudioChannels, AudioCodec, Features, Language, UploadedTorrent, VideoCodec, VideoResolution,
+    },
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+// MediaInfo rounds durations to the minute for anything longer than an hour
+const DURATION_TOLERANCE_SECONDS: i32 = 60;
+
+/// Reads the technical fields of a torrent from the text output of MediaInfo
+/// or BDInfo (full report or quick summary). Unknown values are left empty.
+pub fn parse_mediainfo(text: &str) -> ParsedMediainfo {
+    if is_bdinfo(text) {
+        parse_bdinfo(text)
+    } else {
+        parse_mediainfo_report(text)
+    }
+}
+
+/// Fills the technical fields left empty in the upload form from the parsed
+/// mediainfo, and rejects the upload when a submitted field contradicts it
+pub fn apply_mediainfo_to_upload(
+    form: &mut UploadedTorrent,
+    parsed: &ParsedMediainfo,
+) -> Result<()> {
+    if let Some(container) = &parsed.container
+        && !form.container.eq_ignore_ascii_case(container)
+    {
+        return Err(Error::MediainfoMismatch("container".into()));
+    }
+
+    // full disc uploads use the disc type as their codec
+    if !matches!(
+        form.video_codec.as_deref(),
+        Some(VideoCodec::BD50 | VideoCodec::UHD100)
+    ) {
+        check_or_fill(&mut form.video_codec, parsed.video_codec, "video codec")?;
+    }
+    check_or_fill(
+        &mut form.video_resolution,
+        parsed.video_resolution,
+        "video resolution",
+    )?;
+    if form.video_resolution.as_deref() == Some(&VideoResolution::Other) {
+        fill(
+            &mut form.video_resolution_other_x,
+            parsed.video_resolution_other_x,
+        );
+        fill(
+            &mut form.video_resolution_other_y,
+            parsed.video_resolution_other_y,
+        );
+    }
+    check_or_fill(&mut form.audio_codec, parsed.audio_codec, "audio codec")?;
+    check_or_fill(
+        &mut form.audio_channels,
+        parsed.audio_channels,
+        "audio channels",
+    )?;
+    fill(&mut form.audio_bitrate, parsed.audio_bitrate);
+
+    match (form.duration.as_deref(), parsed.duration) {
+        (Some(submitted), Some(parsed))
+            if (i64::from(*submitted) - i64::from(parsed)).abs()
+                > i64::from(DURATION_TOLERANCE_SECONDS) =>
+        {
+            return Err(Error::MediainfoMismatch("duration".into()));
+        }
+        (None, Some(parsed)) => form.duration = Some(Text(parsed)),
+        _ => {}
+    }
+
+    if form.languages.trim().is_empty() {
+        form.languages = Text(join_names(&parsed.languages));
+    }
+    if form.subtitle_languages.trim().is_empty() {
+        form.subtitle_languages = Text(join_names(&parsed.subtitle_languages));
+    }
+
+    // features are only ever added, some of them (remux, cue...) can't be read from the mediainfo
+    let mut features = form
+        .features
+        .split(',')
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .map(String::from)
+        .collect::<Vec<_>>();
+    for feature in parsed.features.iter().map(to_name) {
+        if !features.contains(&feature) {
+            features.push(feature);
+        }
+    }
+    form.features = Text(features.join(","));
+
+    Ok(())
+}
+
+fn check_or_fill<T: DeserializeOwned + Copy + PartialEq>(
+    field: &mut Option<Text<T>>,
+    parsed: Option<T>,
+    field_name: &str,
+) -> Result<()> {
+    match (field.as_deref(), parsed) {
+        (Some(submitted), Some(parsed)) if *submitted != parsed => {
+            Err(Error::MediainfoMismatch(field_name.into()))
+        }
+        (None, Some(parsed)) => {
+            *field = Some(Text(parsed));
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn fill<T: DeserializeOwned>(field: &mut Option<Text<T>>, parsed: Option<T>) {
+    if field.is_none() {
+        *field = parsed.map(Text);
+    }
+}
+
+fn is_bdinfo(text: &str) -> bool {
+    text.lines().map(str::trim).any(|line| {
+        line == "DISC INFO:"
+            || line == "PLAYLIST REPORT:"
+            || line.starts_with("Disc Title:")
+            || line.starts_with("Disc Label:")
+    })
+}
+
+struct Section<'a> {
+    kind: &'a str,
+    fields: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Section<'a> {
+    fn get(&self, key: &str) -> Option<&'a str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| *v)
+    }
+}
+
+/// Splits a MediaInfo report into its sections ("General", "Video", "Audio #1"...)
+fn split_sections(text: &str) -> Vec<Section<'_>> {
+    let mut sections: Vec<Section> = Vec::new();
+
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        match line.split_once(':') {
+            Some((key, value)) => {
+                if let Some(section) = sections.last_mut() {
+                    section.fields.push((key.trim(), value.trim()));
+                }
+            }
+            None => sections.push(Section {
+                kind: line.split_whitespace().next().unwrap_or(line),
+                fields: Vec::new(),
+            }),
+        }
+    }
+
+    sections
+}
+
+fn parse_mediainfo_report(text: &str) -> ParsedMediainfo {
+    let sections = split_sections(text);
+    let mut parsed = ParsedMediainfo {
+        kind: MediainfoKind::Mediainfo,
+        ..Default::default()
+    };
+
+    let general = sections.iter().find(|s| s.kind == "General");
+    let video = sections.iter().find(|s| s.kind == "Video");
+    let audio_tracks = sections.iter().filter(|s| s.kind == "Audio");
+    let text_tracks = sections.iter().filter(|s| s.kind == "Text");
+
+    if let Some(general) = general {
+        parsed.container = general.get("Format").and_then(container_from_format);
+        parsed.duration = general.get("Duration").and_then(parse_duration);
+    }
+
+    if let Some(video) = video {
+        parsed.video_codec = video_codec_from_mediainfo(video);
+        parsed.duration = parsed
+            .duration
+            .or_else(|| video.get("Duration").and_then(parse_duration));
+
+        if let (Some(width), Some(height)) = (
+            video.get("Width").and_then(parse_number).and_then(to_i32),
+            video.get("Height").and_then(parse_number).and_then(to_i32),
+        ) {
+            let interlaced = video
+                .get("Scan type")
+                .is_some_and(|s| s.contains("Interlaced") || s.contains("MBAFF"));
+            let resolution = resolution_from_dimensions(width, height, interlaced);
+            parsed.video_resolution = Some(resolution);
+            if resolution == VideoResolution::Other {
+                parsed.video_resolution_other_x = Some(width);
+                parsed.video_resolution_other_y = Some(height);
+            }
+        }
+
+        let hdr = [
+            video.get("HDR format"),
+            video.get("Transfer characteristics"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" / ");
+        parsed.features.extend(hdr_features(&hdr));
+
+        if video
+            .get("MultiView_Count")
+            .and_then(parse_number)
+            .is_some_and(|views| views > 1.0)
+        {
+            parsed.features.push(Features::ThreeD);
+        }
+    }
+
+    for (index, audio) in audio_tracks.enumerate() {
+        if index == 0 {
+            parsed.audio_codec = audio.get("Format").and_then(|format| {
+                audio_codec_from_mediainfo(format, audio.get("Format profile").unwrap_or(""))
+            });
+            parsed.audio_channels =
+                audio
+                    .get("Channel(s)")
+                    .and_then(parse_number)
+                    .and_then(|count| {
+                        let lfe = audio
+                            .get("Channel layout")
+                            .or_else(|| audio.get("Channel positions"))
+                            .map(|layout| layout.contains("LFE"));
+                        audio_channels_from_count(count as u32, lfe)
+                    });
+            parsed.audio_bitrate = audio.get("Bit rate").and_then(parse_bitrate);
+        }
+
+        if audio
+            .get("Title")
+            .is_some_and(|title| title.to_lowercase().contains("commentary"))
+            && !parsed.features.contains(&Features::Commentary)
+        {
+            parsed.features.push(Features::Commentary);
+        }
+
+        push_language(&mut parsed.languages, audio.get("Language"));
+    }
+
+    for subtitle in text_tracks {
+        push_language(&mut parsed.subtitle_languages, subtitle.get("Language"));
+    }
+
+    parsed
+}
+
+#[derive(PartialEq)]
+enum BdinfoTable {
+    None,
+    Video,
+    Audio,
+    Subtitles,
+}
+
+fn parse_bdinfo(text: &str) -> ParsedMediainfo {
+    let mut parsed = ParsedMediainfo {
+        kind: MediainfoKind::Bdinfo,
+        ..Default::default()
+    };
+    let mut table = BdinfoTable::None;
+    let mut found_video = false;
+    let mut found_audio = false;
+
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        // hidden tracks (such as the dolby vision enhancement layer) are prefixed with a star
+        let line = line.trim_start_matches("* ");
+
+        match line {
+            "VIDEO:" => table = BdinfoTable::Video,
+            "AUDIO:" => table = BdinfoTable::Audio,
+            "SUBTITLES:" => table = BdinfoTable::Subtitles,
+            _ if line.ends_with(':') && line == line.to_uppercase() => table = BdinfoTable::None,
+            _ if line.starts_with("Codec") || line.starts_with("-----") => {}
+            _ => {
+                if let Some(value) = line.strip_prefix("Length:") {
+                    if parsed.duration.is_none() {
+                        parsed.duration = value.split_whitespace().next().and_then(parse_duration);
+                    }
+                    continue;
+                }
+
+                // quick summaries put the whole stream on a single line
+                let (row, columns) = if let Some(value) = line.strip_prefix("Video:") {
+                    (
+                        BdinfoTable::Video,
+                        value.split(" / ").map(str::trim).collect(),
+                    )
+                } else if let Some(value) = line.strip_prefix("Audio:") {
+                    // language / codec / channels / ... to match the report columns
+                    let mut columns = value.split(" / ").map(str::trim).collect::<Vec<_>>();
+                    if columns.len() > 1 {
+                        columns.swap(0, 1);
+                    }
+                    (BdinfoTable::Audio, columns)
+                } else if let Some(value) = line.strip_prefix("Subtitle:") {
+                    let columns = std::iter::once("")
+                        .chain(value.split(" / ").map(str::trim))
+                        .collect();
+                    (BdinfoTable::Subtitles, columns)
+                } else if table != BdinfoTable::None {
+                    let columns = line
+                        .split("  ")
+                        .map(str::trim)
+                        .filter(|c| !c.is_empty())
+                        .collect::<Vec<_>>();
+                    let row = match table {
+                        BdinfoTable::Video => BdinfoTable::Video,
+                        BdinfoTable::Audio => BdinfoTable::Audio,
+                        _ => BdinfoTable::Subtitles,
+                    };
+                    (row, columns)
+                } else {
+                    continue;
+                };
+
+                match row {
+                    BdinfoTable::Video => {
+                        parsed.features.extend(
+                            hdr_features(line)
+                                .into_iter()
+                                .filter(|f| !parsed.features.contains(f))
+                                .collect::<Vec<_>>(),
+                        );
+                        if !found_video && let Some(codec) = columns.first() {
+                            found_video = true;
+                            parsed.video_codec = video_codec_from_bdinfo(codec);
+                            parsed.video_resolution = columns
+                                .iter()
+                                .flat_map(|c| c.split(" / "))
+                                .find_map(|c| from_name::<VideoResolution>(c.trim()));
+                        }
+                        if line.contains("MVC") && !parsed.features.contains(&Features::ThreeD) {
+                            parsed.features.push(Features::ThreeD);
+                        }
+                    }
+                    BdinfoTable::Audio => {
+                        if !found_audio && let Some(codec) = columns.first() {
+                            found_audio = true;
+                            parsed.audio_codec = audio_codec_from_bdinfo(codec);
+                            parsed.audio_channels = columns
+                                .iter()
+                                .skip(2)
+                                .flat_map(|c| c.split(" / "))
+                                .find_map(|c| from_name::<AudioChannels>(c.trim()));
+                            parsed.audio_bitrate = columns
+                                .iter()
+                                .skip(2)
+                                .flat_map(|c| c.split(" / "))
+                                .find(|c| c.trim().ends_with("kbps"))
+                                .and_then(parse_bitrate);
+                        }
+                        push_language(&mut parsed.languages, columns.get(1).copied());
+                    }
+                    _ => push_language(&mut parsed.subtitle_languages, columns.get(1).copied()),
+                }
+            }
+        }
+    }
+
+    parsed
+}
+
+fn container_from_format(format: &str) -> Option<String> {
+    let container = match format {
+        "Matroska" => "mkv",
+        "MPEG-4" => "mp4",
+        "AVI" => "avi",
+        "BDAV" => "m2ts",
+        "MPEG-TS" => "ts",
+        _ => return None,
+    };
+
+    Some(container.into())
+}
+
+fn video_codec_from_mediainfo(video: &Section) -> Option<VideoCodec> {
+    match video.get("Format")? {
+        "AVC" => Some(VideoCodec::H264),
+        "HEVC" => Some(VideoCodec::H265),
+        "VC-1" => Some(VideoCodec::Vc1),
+        "VP9" => Some(VideoCodec::Vp9),
+        "MPEG Video" => match video.get("Format version") {
+            Some(version) if version.contains('1') => Some(VideoCodec::Mpeg1),
+            _ => Some(VideoCodec::Mpeg2),
+        },
+        "MPEG-4 Visual" => {
+            let is_xvid = [video.get("Codec ID"), video.get("Writing library")]
+                .into_iter()
+                .flatten()
+                .any(|v| v.to_lowercase().contains("xvid"));
+            Some(if is_xvid {
+                VideoCodec::Xvid
+            } else {
+                VideoCodec::DivX
+            })
+        }
+        _ => None,
+    }
+}
+
+fn video_codec_from_bdinfo(codec: &str) -> Option<VideoCodec> {
+    match codec {
+        "MPEG-4 AVC Video" | "MPEG-4 MVC Video" => Some(VideoCodec::H264),
+        "MPEG-H HEVC Video" => Some(VideoCodec::H265),
+        "MPEG-2 Video" => Some(VideoCodec::Mpeg2),
+        "MPEG-1 Video" => Some(VideoCodec::Mpeg1),
+        "VC-1 Video" => Some(VideoCodec::Vc1),
+        _ => None,
+    }
+}
+
+fn audio_codec_from_mediainfo(format: &str, profile: &str) -> Option<AudioCodec> {
+    match format {
+        f if f.starts_with("AAC") => Some(AudioCodec::Aac),
+        f if f.starts_with("AC-3") || f.starts_with("E-AC-3") => Some(AudioCodec::Ac3),
+        f if f.starts_with("DTS") => Some(AudioCodec::Dts),
+        f if f.starts_with("MLP FBA") || f.contains("TrueHD") => Some(AudioCodec::TrueHd),
+        f if f.starts_with("DSD") => Some(AudioCodec::Dsd),
+        "FLAC" => Some(AudioCodec::Flac),
+        "PCM" => Some(AudioCodec::Pcm),
+        "Opus" => Some(AudioCodec::Opus),
+        "MPEG Audio" if profile.contains("Layer 3") => Some(AudioCodec::Mp3),
+        "MPEG Audio" if profile.contains("Layer 2") => Some(AudioCodec::Mp2),
+        _ => None,
+    }
+}
+
+fn audio_codec_from_bdinfo(codec: &str) -> Option<AudioCodec> {
+    let codec = codec.to_lowercase();
+
+    if codec.contains("truehd") {
+        Some(AudioCodec::TrueHd)
+    } else if codec.starts_with("dts") {
+        Some(AudioCodec::Dts)
+    } else if codec.starts_with("dolby digital") {
+        Some(AudioCodec::Ac3)
+    } else if codec.starts_with("lpcm") {
+        Some(AudioCodec::Pcm)
+    } else if codec.starts_with("flac") {
+        Some(AudioCodec::Flac)
+    } else if codec.starts_with("aac") {
+        Some(AudioCodec::Aac)
+    } else if codec.starts_with("mpeg") {
+        Some(AudioCodec::Mp2)
+    } else {
+        None
+    }
+}
+
+fn audio_channels_from_count(count: u32, lfe: Option<bool>) -> Option<AudioChannels> {
+    match (count, lfe) {
+        (1, _) => Some(AudioChannels::OneDotZero),
+        (2, None | Some(false)) => Some(AudioChannels::TwoDotZero),
+        (3, Some(true)) => Some(AudioChannels::TwoDotOne),
+        (5, None | Some(false)) => Some(AudioChannels::FiveDotZero),
+        (6, None | Some(true)) => Some(AudioChannels::FiveDotOne),
+        (8, None | Some(true)) => Some(AudioChannels::SevenDotOne),
+        _ => None,
+    }
+}
+
+/// Cropped encodes keep the width of their source, so it decides before the height
+fn resolution_from_dimensions(width: i32, height: i32, interlaced: bool) -> VideoResolution {
+    if width >= 7600 || height >= 4320 {
+        VideoResolution::P4320
+    } else if width >= 3800 || height >= 2160 {
+        VideoResolution::P2160
+    } else if width >= 2500 || height >= 1440 {
+        VideoResolution::P1440
+    } else if width >= 1900 || height >= 1080 {
+        if interlaced {
+            VideoResolution::I1080
+        } else {
+            VideoResolution::P1080
+        }
+    } else if width >= 1260 || height >= 720 {
+        VideoResolution::P720
+    } else if height > 480 && height <= 576 {
+        if interlaced {
+            VideoResolution::I576
+        } else {
+            VideoResolution::P576
+        }
+    } else if width >= 640 && height <= 480 {
+        if interlaced {
+            VideoResolution::I480
+        } else {
+            VideoResolution::P480
+        }
+    } else {
+        VideoResolution::Other
+    }
+}
+
+fn hdr_features(description: &str) -> Vec<Features> {
+    let mut features = Vec::new();
+
+    if description.contains("Dolby Vision") {
+        features.push(Features::Dv);
+    }
+    if description.contains("HDR10+") || description.contains("SMPTE ST 2094 App 4") {
+        features.push(Features::HdrTenPlus);
+    } else if description.contains("HDR10") || description.contains("SMPTE ST 2086") {
+        features.push(Features::HdrTen);
+    } else if description.contains("PQ") || description.contains("HLG") {
+        features.push(Features::Hdr);
+    }
+
+    features
+}
+
+fn push_language(languages: &mut Vec<Language>, name: Option<&str>) {
+    let Some(name) = name.map(str::trim).filter(|n| !n.is_empty()) else {
+        return;
+    };
+    // "English (US)", "Chinese (Simplified)"...
+    let name = name.split(" (").next().unwrap_or(name);
+    let language = from_name::<Language>(name).unwrap_or(Language::Other);
+
+    if !languages.contains(&language) {
+        languages.push(language);
+    }
+}
+
+/// "1 h 52 min", "52 min 10 s", "01:52:10.000"
+fn parse_duration(value: &str) -> Option<i32> {
+    if value.contains(':') {
+        let mut seconds = 0.0;
+        for part in value.split(':') {
+            seconds = seconds * 60.0 + part.trim().parse::<f64>().ok()?;
+        }
+        return to_i32(seconds);
+    }
+
+    let tokens = value.split_whitespace().collect::<Vec<_>>();
+    let mut seconds = None;
+    for pair in tokens.windows(2) {
+        let Ok(amount) = pair[0].parse::<u32>() else {
+            continue;
+        };
+        let unit = match pair[1] {
+            "h" => 3600,
+            "min" => 60,
+            "s" => 1,
+            _ => continue,
+        };
+        let total: i32 = seconds.unwrap_or(0);
+        seconds = Some(total.checked_add(i32::try_from(amount.checked_mul(unit)?).ok()?)?);
+    }
+
+    seconds
+}
+
+/// "1 920 pixels", "6 channels", "23.976 (24000/1001) FPS"
+fn parse_number(value: &str) -> Option<f64> {
+    value
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ' ' || *c == '.')
+        .filter(|c| *c != ' ')
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+/// "1 509 kb/s", "4 608 kbps", "1.5 Mb/s"
+fn parse_bitrate(value: &str) -> Option<i32> {
+    let bitrate = parse_number(value.trim())?;
+
+    if value.contains("Mb") || value.contains("Mbps") {
+        to_i32(bitrate * 1000.0)
+    } else {
+        to_i32(bitrate)
+    }
+}
+
+// values too large for the columns are rejected rather than saturated
+fn to_i32(value: f64) -> Option<i32> {
+    (value.is_finite() && (0.0..=f64::from(i32::MAX)).contains(&value)).then_some(value as i32)
+}
+
+fn from_name<T: DeserializeOwned>(name: &str) -> Option<T> {
+    serde_json::from_value(Value::String(name.to_string())).ok()
+}
+
+fn to_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+fn join_names<T: Serialize>(values: &[T]) -> String {
+    values.iter().map(to_name).collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mediainfo_report() {
+        let parsed = parse_mediainfo(include_str!("testdata/mediainfo.txt"));
+
+        assert_eq!(parsed.kind, MediainfoKind::Mediainfo);
+        assert_eq!(parsed.container.as_deref(), Some("mkv"));
+        assert_eq!(parsed.duration, Some(6720));
+        assert_eq!(parsed.video_codec, Some(VideoCodec::H265));
+        assert_eq!(parsed.video_resolution, Some(VideoResolution::P2160));
+        assert_eq!(parsed.audio_codec, Some(AudioCodec::TrueHd));
+        assert_eq!(parsed.audio_channels, Some(AudioChannels::SevenDotOne));
+        assert_eq!(parsed.audio_bitrate, Some(4608));
+        assert_eq!(parsed.languages, vec![Language::English, Language::French]);
+        assert_eq!(
+            parsed.subtitle_languages,
+            vec![Language::English, Language::Spanish]
+        );
+        assert_eq!(
+            parsed.features,
+            vec![Features::Dv, Features::HdrTen, Features::Commentary]
+        );
+    }
+
+    #[test]
+    fn test_parse_bdinfo_report() {
+        let parsed = parse_mediainfo(include_str!("testdata/bdinfo.txt"));
+
+        assert_eq!(parsed.kind, MediainfoKind::Bdinfo);
+        assert_eq!(parsed.duration, Some(8508));
+        assert_eq!(parsed.video_codec, Some(VideoCodec::H264));
+        assert_eq!(parsed.video_resolution, Some(VideoResolution::P1080));
+        assert_eq!(parsed.audio_codec, Some(AudioCodec::Dts));
+        assert_eq!(parsed.audio_channels, Some(AudioChannels::FiveDotOne));
+        assert_eq!(parsed.audio_bitrate, Some(3747));
+        assert_eq!(parsed.languages, vec![Language::English, Language::German]);
+        assert_eq!(parsed.subtitle_languages, vec![Language::English]);
+        assert!(parsed.features.is_empty());
+    }
+
+    #[test]
+    fn test_parse_bdinfo_quick_summary() {
+        let parsed = parse_mediainfo(
+            "Disc Title: Test\n\
+             Length: 1:30:00.000\n\
+             Video: MPEG-H HEVC Video / 60000 kbps / 2160p / 23.976 fps / 16:9 / Main 10 @ Level 5.1 @ High / 10 bits / HDR10 / BT.2020\n\
+             Audio: English / Dolby TrueHD/Atmos Audio / 7.1 / 48 kHz / 4000 kbps / 24-bit\n\
+             Subtitle: French / 20.000 kbps\n",
+        );
+
+        assert_eq!(parsed.kind, MediainfoKind::Bdinfo);
+        assert_eq!(parsed.duration, Some(5400));
+        assert_eq!(parsed.video_codec, Some(VideoCodec::H265));
+        assert_eq!(parsed.video_resolution, Some(VideoResolution::P2160));
+        assert_eq!(parsed.audio_codec, Some(AudioCodec::TrueHd));
+        assert_eq!(parsed.audio_channels, Some(AudioChannels::SevenDotOne));
+        assert_eq!(parsed.audio_bitrate, Some(4000));
+        assert_eq!(parsed.languages, vec![Language::English]);
+        assert_eq!(parsed.subtitle_languages, vec![Language::French]);
+        assert_eq!(parsed.features, vec![Features::HdrTen]);
+    }
+
+    #[test]
+    fn test_parse_out_of_range_values() {
+        assert_eq!(parse_duration("1 h 52 min"), Some(6720));
+        assert_eq!(parse_duration("4000000 h"), None);
+        assert_eq!(parse_duration("596523 h 14 min 8 s"), None);
+        assert_eq!(parse_duration("99999999:00:00.000"), None);
+        assert_eq!(parse_bitrate("1.5 Mb/s"), Some(1500));
+        assert_eq!(parse_bitrate("3 000 000 Mb/s"), None);
+        assert_eq!(parse_bitrate("99999999999 kb/s"), None);
+    }
+}
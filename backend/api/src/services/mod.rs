@@ -3,3 +3,14 @@ pub mod auth;
 pub mod common_service;
 pub mod email_service;
 pub mod external_db_service;
+pub mod feed_service;
+pub mod forum_service;
+pub mod ip_ban_service;
+pub mod log_checker_service;
+pub mod mediainfo_service;
+pub mod push_service;
+pub mod torrent_client_service;
+pub mod torrent_service;
+pub mod torznab_service;
+pub mod trump_service;
+pub mod upload_rule_service;
@@ -0,0 +1,95 @@
+use crate::Arcadia;
+use arcadia_storage::{
+    models::{
+        conversation::ConversationMessage, notification::NotificationType, staff_pm::StaffPmMessage,
+    },
+    redis::{RedisInterface, RedisPoolInterface},
+};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::broadcast::Sender;
+
+pub const PUSH_CHANNEL: &str = "arcadia:push";
+// messages a slow stream can lag behind before it has to catch up from the database
+pub const PUSH_BUFFER_SIZE: usize = 1024;
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushMessage {
+    pub user_ids: Vec<i32>,
+    pub event: PushEvent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum PushEvent {
+    // the notifications themselves are read from the database by each stream
+    Notifications,
+    ConversationMessage(ConversationMessage),
+    StaffPmMessage(StaffPmMessage),
+}
+
+// Fire and log; clients still get everything from the regular endpoints
+pub async fn publish<R: RedisPoolInterface>(redis_pool: &R, message: &PushMessage) {
+    let result = async {
+        let payload = serde_json::to_string(message).unwrap();
+        redis_pool
+            .connection()
+            .await?
+            .publish(PUSH_CHANNEL, payload)
+            .await
+    }
+    .await;
+
+    if let Err(e) = result {
+        log::warn!("Failed to publish push message: {}", e);
+    }
+}
+
+pub async fn push_notifications<R: RedisPoolInterface>(
+    arc: &Arcadia<R>,
+    notification_types: &[NotificationType],
+    target_id: i64,
+) {
+    match arc
+        .pool
+        .find_unread_notification_recipients(notification_types, target_id)
+        .await
+    {
+        Ok(user_ids) if user_ids.is_empty() => {}
+        Ok(user_ids) => {
+            let message = PushMessage {
+                user_ids,
+                event: PushEvent::Notifications,
+            };
+            publish(arc.redis_pool.as_ref(), &message).await;
+        }
+        Err(e) => log::warn!("Failed to find the notifications to push: {}", e),
+    }
+}
+
+/// Forwards what any backend instance published to the streams connected to this one
+pub async fn forward_push_messages<R: RedisPoolInterface>(
+    redis_pool: Arc<R>,
+    sender: Sender<PushMessage>,
+) {
+    loop {
+        match redis_pool.subscribe(PUSH_CHANNEL).await {
+            Ok(mut payloads) => {
+                while let Some(payload) = payloads.next().await {
+                    match serde_json::from_str::<PushMessage>(&payload) {
+                        // no receiver just means that nobody is connected
+                        Ok(message) => {
+                            let _ = sender.send(message);
+                        }
+                        Err(e) => log::warn!("Failed to parse push message: {}", e),
+                    }
+                }
+                log::warn!("Push channel subscription ended, resubscribing");
+            }
+            Err(e) => log::warn!("Failed to subscribe to the push channel: {}", e),
+        }
+        tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+    }
+}
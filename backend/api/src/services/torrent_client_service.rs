@@ -0,0 +1,127 @@
+use arcadia_common::error::{Error, Result};
+use arcadia_shared::tracker::models::torrent_client::APIInsertTorrentClient;
+use arcadia_storage::models::torrent_client::TorrentClient;
+use regex::Regex;
+use reqwest::Client;
+
+use crate::env::TrackerConfig;
+
+pub fn validate_torrent_client(
+    name: &str,
+    peer_id_prefix: &str,
+    min_version: &Option<String>,
+    max_version: &Option<String>,
+    user_agent_pattern: &Option<String>,
+) -> Result<()> {
+    if name.trim().is_empty() || name.chars().count() > 50 {
+        return Err(Error::BadRequest(
+            "name must be between 1 and 50 characters long".into(),
+        ));
+    }
+
+    if peer_id_prefix.is_empty() || peer_id_prefix.len() > 19 {
+        return Err(Error::BadRequest(
+            "peer_id prefix must be between 1 and 19 characters long".into(),
+        ));
+    }
+
+    for version in [min_version, max_version].into_iter().flatten() {
+        if version.len() != 4 {
+            return Err(Error::BadRequest(
+                "versions must be exactly 4 characters long".into(),
+            ));
+        }
+    }
+    if let (Some(min_version), Some(max_version)) = (min_version, max_version)
+        && min_version > max_version
+    {
+        return Err(Error::BadRequest(
+            "min version cannot be greater than max version".into(),
+        ));
+    }
+
+    if let Some(pattern) = user_agent_pattern {
+        Regex::new(pattern)
+            .map_err(|e| Error::BadRequest(format!("invalid user agent pattern: {e}")))?;
+    }
+
+    Ok(())
+}
+
+// Fire and log; the tracker reloads every rule from the database on startup anyway
+pub async fn upsert_torrent_client_in_tracker(tracker: &TrackerConfig, client: &TorrentClient) {
+    let mut url = tracker.url_internal.clone();
+    url.path_segments_mut()
+        .unwrap()
+        .push("api")
+        .push("torrent-clients");
+
+    let payload = APIInsertTorrentClient {
+        id: client.id as u32,
+        rule: client.rule,
+        peer_id_prefix: client.peer_id_prefix.clone(),
+        min_version: client.min_version.clone(),
+        max_version: client.max_version.clone(),
+        user_agent_pattern: client.user_agent_pattern.clone(),
+    };
+
+    if let Err(e) = Client::new()
+        .put(url)
+        .header("x-api-key", tracker.api_key.clone())
+        .json(&payload)
+        .send()
+        .await
+    {
+        log::warn!("Failed to upsert torrent client in tracker: {}", e);
+    }
+}
+
+pub async fn remove_torrent_client_from_tracker(tracker: &TrackerConfig, client_id: i32) {
+    let mut url = tracker.url_internal.clone();
+    url.path_segments_mut()
+        .unwrap()
+        .push("api")
+        .push("torrent-clients")
+        .push(&client_id.to_string());
+
+    if let Err(e) = Client::new()
+        .delete(url)
+        .header("x-api-key", tracker.api_key.clone())
+        .send()
+        .await
+    {
+        log::warn!("Failed to remove torrent client from tracker: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(name: &str, min_version: Option<&str>, max_version: Option<&str>) -> Result<()> {
+        validate_torrent_client(
+            name,
+            "qB",
+            &min_version.map(String::from),
+            &max_version.map(String::from),
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_validate_name_length() {
+        assert!(validate("qBittorrent", None, None).is_ok());
+        assert!(validate(" ", None, None).is_err());
+        assert!(validate(&"q".repeat(50), None, None).is_ok());
+        assert!(validate(&"q".repeat(51), None, None).is_err());
+    }
+
+    #[test]
+    fn test_validate_version_range() {
+        assert!(validate("qBittorrent", Some("4500"), Some("4620")).is_ok());
+        assert!(validate("qBittorrent", Some("4620"), Some("4620")).is_ok());
+        assert!(validate("qBittorrent", Some("4620"), None).is_ok());
+        assert!(validate("qBittorrent", Some("4621"), Some("4620")).is_err());
+        assert!(validate("qBittorrent", Some("462"), None).is_err());
+    }
+}
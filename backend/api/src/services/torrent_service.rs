@@ -0,0 +1,62 @@
+use arcadia_shared::tracker::models::torrent::APIInsertTorrent;
+use arcadia_storage::models::torrent::Torrent;
+use reqwest::{Client, Url};
+
+use crate::env::TrackerConfig;
+
+// Fire and log; the tracker reloads every torrent from the database on startup anyway
+pub async fn upsert_torrent_in_tracker(
+    tracker: &TrackerConfig,
+    torrent: &Torrent,
+    is_deleted: bool,
+) {
+    let mut url = tracker.url_internal.clone();
+    url.path_segments_mut()
+        .unwrap()
+        .push("api")
+        .push("torrents");
+
+    let payload = APIInsertTorrent {
+        id: torrent.id as u32,
+        info_hash: torrent.info_hash,
+        info_hash_v2: torrent.info_hash_v2,
+        is_deleted,
+        seeders: torrent.seeders as u32,
+        leechers: torrent.leechers as u32,
+        times_completed: torrent.times_completed as u32,
+        download_factor: torrent.download_factor as u8,
+        upload_factor: torrent.upload_factor as u8,
+    };
+
+    if let Err(e) = Client::new()
+        .put(url)
+        .header("x-api-key", tracker.api_key.clone())
+        .json(&payload)
+        .send()
+        .await
+    {
+        log::warn!("Failed to upsert torrent in tracker: {}", e);
+    }
+}
+
+// Sent to the users who were seeding a torrent deleted by staff
+pub fn deleted_torrent_displayed_reason(
+    frontend_url: &Url,
+    reason: &str,
+    staff_id: i32,
+    staff_username: &str,
+) -> String {
+    let user_url = frontend_url.join(&format!("/user/{}", staff_id)).unwrap();
+
+    format!(
+        "A torrent you were a seeder on, has been deleted.
+  Please remove it from your torrent client.
+
+Reason: {}
+
+Handled by: [url={}]{}[/url]",
+        reason,
+        user_url.as_str(),
+        staff_username
+    )
+}
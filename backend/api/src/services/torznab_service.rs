@@ -0,0 +1,424 @@
+use std::{collections::BTreeMap, io::Cursor, sync::LazyLock};
+
+use actix_web::{http::StatusCode, HttpResponse};
+use arcadia_common::error::{Error, Result};
+use arcadia_storage::models::{
+    feed::{FeedTorrent, TorrentFeedFilter},
+    title_group::{ContentType, TitleGroupCategory},
+};
+use quick_xml::{events::BytesDecl, events::Event, Writer};
+use regex::Regex;
+use rss::{
+    extension::{Extension, ExtensionBuilder},
+    CategoryBuilder, ChannelBuilder, EnclosureBuilder, GuidBuilder, ItemBuilder,
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+use super::feed_service::{item_title, FeedLinks, FEED_LENGTH};
+
+pub const TORZNAB_MAX_LIMIT: i64 = 100;
+
+const TORZNAB_NAMESPACE: &str = "http://torznab.com/schemas/2015/feed";
+
+// newznab categories and the content they are mapped to, the most specific entry
+// matching a title group is used as its category.
+// a category of None matches all the title group categories of the content type
+const TORZNAB_CATEGORIES: &[(i32, &str, ContentType, Option<TitleGroupCategory>)] = &[
+    (2000, "Movies", ContentType::Movie, None),
+    (2000, "Movies", ContentType::Video, None),
+    (3000, "Audio", ContentType::Music, None),
+    (3050, "Audio/Other", ContentType::Podcast, None),
+    (4000, "PC", ContentType::Software, None),
+    (
+        4050,
+        "PC/Games",
+        ContentType::Software,
+        Some(TitleGroupCategory::Game),
+    ),
+    (5000, "TV", ContentType::TVShow, None),
+    (7000, "Books", ContentType::Book, None),
+    (
+        7010,
+        "Books/Mags",
+        ContentType::Book,
+        Some(TitleGroupCategory::Periodical),
+    ),
+    (
+        7020,
+        "Books/EBook",
+        ContentType::Book,
+        Some(TitleGroupCategory::Book),
+    ),
+    (
+        7030,
+        "Books/Comics",
+        ContentType::Book,
+        Some(TitleGroupCategory::Illustrated),
+    ),
+    (
+        7040,
+        "Books/Technical",
+        ContentType::Book,
+        Some(TitleGroupCategory::Manual),
+    ),
+    (8000, "Other", ContentType::Collection, None),
+];
+
+// search functions and the parameters they take into account
+const TORZNAB_SEARCHES: &[(&str, &str)] = &[
+    ("search", "q"),
+    ("tv-search", "q,imdbid,tvdbid,tmdbid"),
+    ("movie-search", "q,imdbid,tmdbid"),
+    ("music-search", "q,artist,album"),
+    ("audio-search", "q,artist,album"),
+    ("book-search", "q,author,title"),
+];
+
+static IMDB_ID: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)imdb\.com/title/(tt\d+)").unwrap());
+static TMDB_ID: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)themoviedb\.org/(?:movie|tv)/(\d+)").unwrap());
+
+#[derive(Debug, Clone, Copy, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TorznabFunction {
+    Caps,
+    Search,
+    TvSearch,
+    Movie,
+    Music,
+    Book,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TorznabQuery {
+    pub t: TorznabFunction,
+    // api key of the user, created with /api/users/api-keys
+    pub apikey: Option<String>,
+    pub q: Option<String>,
+    // comma separated list of newznab categories
+    pub cat: Option<String>,
+    pub offset: Option<i64>,
+    pub limit: Option<i64>,
+    pub imdbid: Option<String>,
+    pub tmdbid: Option<String>,
+    pub tvdbid: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub author: Option<String>,
+    pub title: Option<String>,
+}
+
+fn non_empty(value: &Option<String>) -> Option<String> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn numeric_id(id: &str, prefix: &str) -> Result<String> {
+    let id = id.trim();
+    let digits = id
+        .strip_prefix(prefix)
+        .unwrap_or(id)
+        .trim_start_matches('0');
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::BadRequest(format!("invalid id: {id}")));
+    }
+    Ok(digits.to_string())
+}
+
+fn external_link_regex(query: &TorznabQuery) -> Result<Option<String>> {
+    let mut patterns = Vec::new();
+    if let Some(imdb_id) = non_empty(&query.imdbid) {
+        patterns.push(format!(
+            r"imdb\.com/title/tt0*{}(\D|$)",
+            numeric_id(&imdb_id, "tt")?
+        ));
+    }
+    if let Some(tmdb_id) = non_empty(&query.tmdbid) {
+        patterns.push(format!(
+            r"themoviedb\.org/(movie|tv)/{}(\D|$)",
+            numeric_id(&tmdb_id, "")?
+        ));
+    }
+    if let Some(tvdb_id) = non_empty(&query.tvdbid) {
+        patterns.push(format!(
+            r"thetvdb\.com/.*(id=|/){}(\D|$)",
+            numeric_id(&tvdb_id, "")?
+        ));
+    }
+
+    Ok((!patterns.is_empty()).then(|| format!("({})", patterns.join(")|("))))
+}
+
+// a parent category (e.g. 7000) includes all of its subcategories (e.g. 7020)
+fn content_types_of_categories(
+    category_ids: &[i32],
+) -> Vec<(ContentType, Option<TitleGroupCategory>)> {
+    TORZNAB_CATEGORIES
+        .iter()
+        .filter(|(id, ..)| {
+            category_ids.iter().any(|requested| {
+                requested == id || (requested % 1000 == 0 && requested / 1000 == id / 1000)
+            })
+        })
+        .map(|(_, _, content_type, category)| (*content_type, *category))
+        .collect()
+}
+
+fn torznab_category(torrent: &FeedTorrent) -> i32 {
+    TORZNAB_CATEGORIES
+        .iter()
+        .filter(|(_, _, content_type, category)| {
+            *content_type == torrent.content_type
+                && category.is_none_or(|category| Some(category) == torrent.category)
+        })
+        .max_by_key(|(_, _, _, category)| category.is_some())
+        .map(|(id, ..)| *id)
+        .unwrap_or(8000)
+}
+
+// None when the requested categories can't match any torrent
+pub fn torznab_search_filter(query: &TorznabQuery) -> Result<Option<TorrentFeedFilter>> {
+    let category_ids: Vec<i32> = match non_empty(&query.cat) {
+        Some(cat) => cat
+            .split(',')
+            .map(|id| {
+                id.trim()
+                    .parse()
+                    .map_err(|_| Error::BadRequest(format!("invalid category: {id}")))
+            })
+            .collect::<Result<_>>()?,
+        None => match query.t {
+            TorznabFunction::TvSearch => vec![5000],
+            TorznabFunction::Movie => vec![2000],
+            TorznabFunction::Music => vec![3000],
+            TorznabFunction::Book => vec![7000],
+            TorznabFunction::Caps | TorznabFunction::Search => vec![],
+        },
+    };
+    let content_types = content_types_of_categories(&category_ids);
+    if !category_ids.is_empty() && content_types.is_empty() {
+        return Ok(None);
+    }
+
+    let (title_group_name, artist_name) = match query.t {
+        TorznabFunction::Music => (non_empty(&query.album), non_empty(&query.artist)),
+        TorznabFunction::Book => (non_empty(&query.title), non_empty(&query.author)),
+        _ => (None, None),
+    };
+
+    Ok(Some(TorrentFeedFilter {
+        title_group_name: title_group_name.or_else(|| non_empty(&query.q)),
+        artist_name,
+        external_link_regex: external_link_regex(query)?,
+        content_types,
+        offset: query.offset.unwrap_or(0).max(0),
+        limit: query
+            .limit
+            .unwrap_or(FEED_LENGTH)
+            .clamp(1, TORZNAB_MAX_LIMIT),
+        ..Default::default()
+    }))
+}
+
+fn torznab_attr(name: &str, value: impl ToString) -> Extension {
+    ExtensionBuilder::default()
+        .name("torznab:attr")
+        .attrs(BTreeMap::from([
+            ("name".to_string(), name.to_string()),
+            ("value".to_string(), value.to_string()),
+        ]))
+        .build()
+}
+
+fn torznab_attrs(torrent: &FeedTorrent, category: i32) -> Vec<Extension> {
+    let mut attrs = vec![
+        torznab_attr("category", category),
+        torznab_attr("size", torrent.size),
+        torznab_attr("seeders", torrent.seeders),
+        torznab_attr("peers", torrent.seeders + torrent.leechers),
+        torznab_attr("grabs", torrent.times_completed),
+        // factors are stored as percentages
+        torznab_attr(
+            "downloadvolumefactor",
+            f64::from(torrent.download_factor) / 100.0,
+        ),
+        torznab_attr(
+            "uploadvolumefactor",
+            f64::from(torrent.upload_factor) / 100.0,
+        ),
+    ];
+
+    for link in &torrent.external_links {
+        if let Some(id) = IMDB_ID.captures(link) {
+            attrs.push(torznab_attr("imdbid", &id[1]));
+        } else if let Some(id) = TMDB_ID.captures(link) {
+            attrs.push(torznab_attr("tmdbid", &id[1]));
+        }
+    }
+    if !torrent.tags.is_empty() {
+        attrs.push(torznab_attr("tag", torrent.tags.join(",")));
+    }
+
+    attrs
+}
+
+pub fn torznab_results_response(
+    title: &str,
+    torrents: &[FeedTorrent],
+    links: &FeedLinks,
+) -> HttpResponse {
+    let items: Vec<_> = torrents
+        .iter()
+        .map(|torrent| {
+            let category = torznab_category(torrent);
+            ItemBuilder::default()
+                .title(item_title(torrent))
+                .guid(
+                    GuidBuilder::default()
+                        .value(format!("torrent-{}", torrent.id))
+                        .permalink(false)
+                        .build(),
+                )
+                .link(links.download_url(torrent))
+                .comments(links.title_group_url(torrent))
+                .pub_date(torrent.created_at.to_rfc2822())
+                .categories(vec![CategoryBuilder::default()
+                    .name(category.to_string())
+                    .build()])
+                .enclosure(
+                    EnclosureBuilder::default()
+                        .url(links.download_url(torrent))
+                        .length(torrent.size.to_string())
+                        .mime_type("application/x-bittorrent")
+                        .build(),
+                )
+                .extensions(BTreeMap::from([(
+                    "torznab".to_string(),
+                    BTreeMap::from([("attr".to_string(), torznab_attrs(torrent, category))]),
+                )]))
+                .build()
+        })
+        .collect();
+
+    let channel = ChannelBuilder::default()
+        .namespaces(BTreeMap::from([(
+            "torznab".to_string(),
+            TORZNAB_NAMESPACE.to_string(),
+        )]))
+        .title(title)
+        .link(links.frontend_url.to_string())
+        .description(title)
+        .items(items)
+        .build();
+
+    HttpResponse::Ok()
+        .content_type("application/rss+xml; charset=utf-8")
+        .body(channel.to_string())
+}
+
+fn caps_xml(server_title: &str) -> std::io::Result<Vec<u8>> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer
+        .create_element("caps")
+        .write_inner_content(|writer| {
+            writer
+                .create_element("server")
+                .with_attribute(("title", server_title))
+                .write_empty()?;
+            writer
+                .create_element("limits")
+                .with_attributes([
+                    ("max", TORZNAB_MAX_LIMIT.to_string().as_str()),
+                    ("default", FEED_LENGTH.to_string().as_str()),
+                ])
+                .write_empty()?;
+            writer
+                .create_element("searching")
+                .write_inner_content(|writer| {
+                    for (search, params) in TORZNAB_SEARCHES {
+                        writer
+                            .create_element(*search)
+                            .with_attributes([("available", "yes"), ("supportedParams", params)])
+                            .write_empty()?;
+                    }
+                    Ok(())
+                })?;
+            writer
+                .create_element("categories")
+                .write_inner_content(|writer| {
+                    let mut parents: Vec<(i32, &str)> = TORZNAB_CATEGORIES
+                        .iter()
+                        .filter(|(id, ..)| id % 1000 == 0)
+                        .map(|(id, name, ..)| (*id, *name))
+                        .collect();
+                    parents.dedup();
+                    for (parent_id, parent_name) in parents {
+                        let mut subcategories: Vec<(i32, &str)> = TORZNAB_CATEGORIES
+                            .iter()
+                            .filter(|(id, ..)| id % 1000 != 0 && id / 1000 == parent_id / 1000)
+                            .map(|(id, name, ..)| (*id, *name))
+                            .collect();
+                        subcategories.dedup();
+                        writer
+                            .create_element("category")
+                            .with_attributes([
+                                ("id", parent_id.to_string().as_str()),
+                                ("name", parent_name),
+                            ])
+                            .write_inner_content(|writer| {
+                                for (id, name) in subcategories {
+                                    writer
+                                        .create_element("subcat")
+                                        .with_attributes([
+                                            ("id", id.to_string().as_str()),
+                                            ("name", name),
+                                        ])
+                                        .write_empty()?;
+                                }
+                                Ok(())
+                            })?;
+                    }
+                    Ok(())
+                })?;
+            Ok(())
+        })?;
+
+    Ok(writer.into_inner().into_inner())
+}
+
+pub fn torznab_caps_response(server_title: &str) -> HttpResponse {
+    match caps_xml(server_title) {
+        Ok(caps) => HttpResponse::Ok()
+            .content_type("application/xml; charset=utf-8")
+            .body(caps),
+        Err(e) => torznab_error_response(900, &e.to_string()),
+    }
+}
+
+// errors are sent in the format expected by torznab clients instead of json
+pub fn torznab_error_response(code: u16, description: &str) -> HttpResponse {
+    let status = match code {
+        100..=199 => StatusCode::UNAUTHORIZED,
+        200..=299 => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    let mut writer = Writer::new(Vec::new());
+    let _ = writer
+        .create_element("error")
+        .with_attributes([
+            ("code", code.to_string().as_str()),
+            ("description", description),
+        ])
+        .write_empty();
+    let error = writer.into_inner();
+
+    HttpResponse::build(status)
+        .content_type("application/xml; charset=utf-8")
+        .body(error)
+}
@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+
+use arcadia_storage::models::torrent_trump::{TorrentQuality, TrumpRule};
+
+/// Ids of the torrents of the same edition that the uploaded torrent is
+/// likely a trump for, according to the rule of its content type.
+///
+/// Fields are compared in order (audio bitrate/sampling, audio codec, video
+/// resolution, video codec, then log score) and the first one ranking
+/// differently decides. Fields that are missing or unranked are skipped.
+pub fn find_likely_trumped(
+    rule: &TrumpRule,
+    uploaded: &TorrentQuality,
+    others: &[TorrentQuality],
+) -> Vec<i32> {
+    others
+        .iter()
+        .filter(|other| other.id != uploaded.id)
+        .filter(|other| compare(rule, uploaded, other) == Ordering::Greater)
+        .map(|other| other.id)
+        .collect()
+}
+
+fn compare(rule: &TrumpRule, a: &TorrentQuality, b: &TorrentQuality) -> Ordering {
+    compare_ranks(
+        &rule.audio_bitrate_sampling_ranking,
+        a.audio_bitrate_sampling,
+        b.audio_bitrate_sampling,
+    )
+    .then_with(|| compare_ranks(&rule.audio_codec_ranking, a.audio_codec, b.audio_codec))
+    .then_with(|| {
+        compare_ranks(
+            &rule.video_resolution_ranking,
+            a.video_resolution,
+            b.video_resolution,
+        )
+    })
+    .then_with(|| compare_ranks(&rule.video_codec_ranking, a.video_codec, b.video_codec))
+    .then_with(|| match (a.log_score, b.log_score) {
+        (Some(a), Some(b)) if rule.compare_log_score => a.cmp(&b),
+        _ => Ordering::Equal,
+    })
+}
+
+fn compare_ranks<T: PartialEq>(ranking: &[T], a: Option<T>, b: Option<T>) -> Ordering {
+    let rank = |value: Option<T>| value.and_then(|v| ranking.iter().position(|r| *r == v));
+
+    match (rank(a), rank(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        _ => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcadia_storage::models::{
+        title_group::ContentType,
+        torrent::{AudioBitrateSampling, VideoCodec, VideoResolution},
+    };
+
+    fn music_rule() -> TrumpRule {
+        TrumpRule {
+            content_type: ContentType::Music,
+            audio_bitrate_sampling_ranking: vec![
+                AudioBitrateSampling::Bitrate320,
+                AudioBitrateSampling::V0Vbr,
+                AudioBitrateSampling::Lossless,
+            ],
+            audio_codec_ranking: Vec::new(),
+            video_resolution_ranking: Vec::new(),
+            video_codec_ranking: Vec::new(),
+            compare_log_score: true,
+        }
+    }
+
+    #[test]
+    fn test_lossless_trumps_lossy() {
+        let uploaded = TorrentQuality {
+            id: 3,
+            audio_bitrate_sampling: Some(AudioBitrateSampling::Lossless),
+            ..Default::default()
+        };
+        let others = [
+            TorrentQuality {
+                id: 1,
+                audio_bitrate_sampling: Some(AudioBitrateSampling::V0Vbr),
+                ..Default::default()
+            },
+            TorrentQuality {
+                id: 2,
+                audio_bitrate_sampling: Some(AudioBitrateSampling::Lossless),
+                ..Default::default()
+            },
+        ];
+
+        assert_eq!(find_likely_trumped(&music_rule(), &uploaded, &others), [1]);
+    }
+
+    #[test]
+    fn test_better_log_score_trumps_same_format() {
+        let uploaded = TorrentQuality {
+            id: 2,
+            audio_bitrate_sampling: Some(AudioBitrateSampling::Lossless),
+            log_score: Some(100),
+            ..Default::default()
+        };
+        let others = [TorrentQuality {
+            id: 1,
+            audio_bitrate_sampling: Some(AudioBitrateSampling::Lossless),
+            log_score: Some(70),
+            ..Default::default()
+        }];
+
+        assert_eq!(find_likely_trumped(&music_rule(), &uploaded, &others), [1]);
+    }
+
+    #[test]
+    fn test_unranked_values_are_not_compared() {
+        let rule = TrumpRule {
+            content_type: ContentType::Movie,
+            audio_bitrate_sampling_ranking: Vec::new(),
+            audio_codec_ranking: Vec::new(),
+            video_resolution_ranking: vec![VideoResolution::P720, VideoResolution::P1080],
+            video_codec_ranking: vec![VideoCodec::Xvid, VideoCodec::H264],
+            compare_log_score: false,
+        };
+        let uploaded = TorrentQuality {
+            id: 2,
+            video_resolution: Some(VideoResolution::P1080),
+            video_codec: Some(VideoCodec::H265),
+            ..Default::default()
+        };
+        let others = [TorrentQuality {
+            id: 1,
+            video_resolution: Some(VideoResolution::P1080),
+            video_codec: Some(VideoCodec::H264),
+            ..Default::default()
+        }];
+
+        assert!(find_likely_trumped(&rule, &uploaded, &others).is_empty());
+    }
+}
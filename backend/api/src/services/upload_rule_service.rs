@@ -0,0 +1,312 @@
+use std::collections::HashSet;
+
+use crate::Arcadia;
+use arcadia_common::error::{Error, FieldError, Result};
+use arcadia_storage::{
+    models::{
+        torrent::{EditedTorrent, Torrent, UploadedTorrent},
+        upload_rule::{UploadRule, UserCreatedUploadRule},
+    },
+    redis::RedisPoolInterface,
+};
+use bip_metainfo::Info;
+use regex::Regex;
+
+// fields of the upload form a rule can require
+const REQUIRABLE_FIELDS: [&str; 14] = [
+    "release_group",
+    "description",
+    "mediainfo",
+    "languages",
+    "duration",
+    "audio_codec",
+    "audio_bitrate",
+    "audio_bitrate_sampling",
+    "audio_channels",
+    "video_codec",
+    "features",
+    "subtitle_languages",
+    "video_resolution",
+    "log_files",
+];
+
+// uploads are compared in lowercase, without the dot of the extensions
+pub fn normalize_upload_rule(rule: &mut UserCreatedUploadRule) {
+    for extension in &mut rule.allowed_file_extensions {
+        *extension = extension.trim().trim_start_matches('.').to_lowercase();
+    }
+    for container in &mut rule.allowed_containers {
+        *container = container.trim().to_lowercase();
+    }
+}
+
+pub fn validate_upload_rule(rule: &UserCreatedUploadRule) -> Result<()> {
+    if let Some(field) = rule
+        .required_fields
+        .iter()
+        .find(|field| !REQUIRABLE_FIELDS.contains(&field.as_str()))
+    {
+        return Err(Error::BadRequest(format!(
+            "'{field}' cannot be required, valid fields are: {}",
+            REQUIRABLE_FIELDS.join(", ")
+        )));
+    }
+
+    if let (Some(min), Some(max)) = (rule.min_piece_length, rule.max_piece_length)
+        && min > max
+    {
+        return Err(Error::BadRequest(
+            "min piece length must not be greater than max piece length".into(),
+        ));
+    }
+
+    if let Some(pattern) = &rule.release_name_pattern {
+        Regex::new(pattern)
+            .map_err(|e| Error::BadRequest(format!("invalid release name pattern: {e}")))?;
+    }
+
+    Ok(())
+}
+
+/// The fields of an upload or of an edited torrent that the rules check
+pub struct RuledTorrent<'a> {
+    release_name: &'a str,
+    container: &'a str,
+    missing_fields: Vec<&'static str>,
+}
+
+impl<'a> RuledTorrent<'a> {
+    pub fn from_upload(form: &'a UploadedTorrent) -> Self {
+        Self::new(&form.release_name, &form.container, |field| match field {
+            "release_group" => is_text_filled(form.release_group.as_deref()),
+            "description" => is_text_filled(form.description.as_deref()),
+            "mediainfo" => is_text_filled(form.mediainfo.as_deref()),
+            "languages" => is_text_filled(Some(&form.languages)),
+            "duration" => form.duration.is_some(),
+            "audio_codec" => form.audio_codec.is_some(),
+            "audio_bitrate" => form.audio_bitrate.is_some(),
+            "audio_bitrate_sampling" => form.audio_bitrate_sampling.is_some(),
+            "audio_channels" => form.audio_channels.is_some(),
+            "video_codec" => form.video_codec.is_some(),
+            "features" => is_text_filled(Some(&form.features)),
+            "subtitle_languages" => is_text_filled(Some(&form.subtitle_languages)),
+            "video_resolution" => form.video_resolution.is_some(),
+            "log_files" => !form.log_files.is_empty(),
+            _ => true,
+        })
+    }
+
+    // the logs can't be edited, has_logs tells if the torrent was uploaded with some
+    pub fn from_edit(form: &'a EditedTorrent, has_logs: bool) -> Self {
+        let release_name = form.release_name.as_deref().unwrap_or_default();
+        Self::new(release_name, &form.container, |field| match field {
+            "release_group" => is_text_filled(form.release_group.as_ref()),
+            "description" => is_text_filled(form.description.as_ref()),
+            "mediainfo" => is_text_filled(form.mediainfo.as_ref()),
+            "languages" => !form.languages.is_empty(),
+            "duration" => form.duration.is_some(),
+            "audio_codec" => form.audio_codec.is_some(),
+            "audio_bitrate" => form.audio_bitrate.is_some(),
+            "audio_bitrate_sampling" => form.audio_bitrate_sampling.is_some(),
+            "audio_channels" => form.audio_channels.is_some(),
+            "video_codec" => form.video_codec.is_some(),
+            "features" => !form.features.is_empty(),
+            "subtitle_languages" => !form.subtitle_languages.is_empty(),
+            "video_resolution" => form.video_resolution.is_some(),
+            "log_files" => has_logs,
+            _ => true,
+        })
+    }
+
+    fn new(release_name: &'a str, container: &'a str, is_filled: impl Fn(&str) -> bool) -> Self {
+        Self {
+            release_name,
+            container,
+            missing_fields: REQUIRABLE_FIELDS
+                .into_iter()
+                .filter(|field| !is_filled(field))
+                .collect(),
+        }
+    }
+}
+
+/// Checks the upload against every rule of its content type and category,
+/// reporting all the fields that break one of them at once
+pub fn validate_upload(rules: &[UploadRule], torrent: &RuledTorrent, info: &Info) -> Result<()> {
+    let mut errors = Vec::new();
+
+    for rule in rules {
+        check_rule(rule, torrent, info, &mut errors);
+    }
+
+    // a field can be checked by both the content type and the category rule
+    let mut seen = HashSet::new();
+    errors.retain(|error: &FieldError| seen.insert(error.clone()));
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::UploadRulesViolated(errors))
+    }
+}
+
+/// Checks an edit of the torrent against the upload rules of the edition group
+/// it ends up in, its own one unless it is moved
+pub async fn validate_torrent_edit<R: RedisPoolInterface>(
+    arc: &Arcadia<R>,
+    torrent: &Torrent,
+    edited: &EditedTorrent,
+    edition_group_id: i32,
+) -> Result<()> {
+    let rules = arc
+        .pool
+        .find_upload_rules_for_edition_group(edition_group_id)
+        .await?;
+    if rules.is_empty() {
+        return Ok(());
+    }
+
+    let info = arc.pool.find_torrent_info(torrent.id).await?;
+    let edited = RuledTorrent::from_edit(edited, torrent.log_score.is_some());
+    validate_upload(&rules, &edited, &info)
+}
+
+fn check_rule(
+    rule: &UploadRule,
+    torrent: &RuledTorrent,
+    info: &Info,
+    errors: &mut Vec<FieldError>,
+) {
+    let mut error = |field: &str, message: String| {
+        errors.push(FieldError {
+            field: field.into(),
+            message,
+        })
+    };
+
+    if !rule.allowed_file_extensions.is_empty() {
+        let mut reported = HashSet::new();
+        for file in info.files().filter(|file| !file.is_padding()) {
+            let extension = file
+                .path()
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            if !rule.allowed_file_extensions.contains(&extension)
+                && reported.insert(extension.clone())
+            {
+                error(
+                    "file_list",
+                    format!(
+                        "'{extension}' files are not allowed ({})",
+                        file.path().display()
+                    ),
+                );
+            }
+        }
+    }
+
+    if !rule.allowed_containers.is_empty()
+        && !rule
+            .allowed_containers
+            .contains(&torrent.container.to_lowercase())
+    {
+        error(
+            "container",
+            format!("must be one of: {}", rule.allowed_containers.join(", ")),
+        );
+    }
+
+    for field in &rule.required_fields {
+        if torrent.missing_fields.contains(&field.as_str()) {
+            error(field, "is required".into());
+        }
+    }
+
+    let piece_length = info.piece_length() as i64;
+    if let Some(min) = rule.min_piece_length
+        && piece_length < min
+    {
+        error(
+            "torrent_file",
+            format!("piece length must be at least {min} bytes"),
+        );
+    }
+    if let Some(max) = rule.max_piece_length
+        && piece_length > max
+    {
+        error(
+            "torrent_file",
+            format!("piece length must be at most {max} bytes"),
+        );
+    }
+
+    // patterns are validated when the rule is saved
+    if let Some(pattern) = &rule.release_name_pattern
+        && let Ok(regex) = Regex::new(pattern)
+        && !regex.is_match(torrent.release_name)
+    {
+        error("release_name", format!("must match the pattern {pattern}"));
+    }
+}
+
+fn is_text_filled(text: Option<&String>) -> bool {
+    text.is_some_and(|t| !t.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arcadia_storage::models::title_group::ContentType;
+
+    fn rule() -> UserCreatedUploadRule {
+        UserCreatedUploadRule {
+            content_type: ContentType::Movie,
+            category: None,
+            allowed_file_extensions: vec!["mkv".into()],
+            allowed_containers: vec!["mkv".into()],
+            required_fields: vec!["video_codec".into(), "video_resolution".into()],
+            min_piece_length: Some(262144),
+            max_piece_length: Some(16777216),
+            release_name_pattern: Some(r"^.+\.\d{4}\..+$".into()),
+        }
+    }
+
+    #[test]
+    fn test_normalize_upload_rule() {
+        let mut rule = UserCreatedUploadRule {
+            allowed_file_extensions: vec![" MKV ".into(), ".Srt".into()],
+            allowed_containers: vec![" MKV".into()],
+            ..rule()
+        };
+
+        normalize_upload_rule(&mut rule);
+
+        assert_eq!(rule.allowed_file_extensions, vec!["mkv", "srt"]);
+        assert_eq!(rule.allowed_containers, vec!["mkv"]);
+    }
+
+    #[test]
+    fn test_validate_upload_rule() {
+        assert!(validate_upload_rule(&rule()).is_ok());
+
+        let unknown_field = UserCreatedUploadRule {
+            required_fields: vec!["info_hash".into()],
+            ..rule()
+        };
+        assert!(validate_upload_rule(&unknown_field).is_err());
+
+        let inverted_piece_lengths = UserCreatedUploadRule {
+            min_piece_length: Some(16777216),
+            max_piece_length: Some(262144),
+            ..rule()
+        };
+        assert!(validate_upload_rule(&inverted_piece_lengths).is_err());
+
+        let invalid_pattern = UserCreatedUploadRule {
+            release_name_pattern: Some("(".into()),
+            ..rule()
+        };
+        assert!(validate_upload_rule(&invalid_pattern).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use actix_http::Request;
+use actix_multipart_rfc7578::client::multipart;
 use actix_web::{
     body::MessageBody,
     dev::{Service, ServiceResponse},
@@ -8,7 +9,7 @@ use actix_web::{
     },
     test, web, App, Error,
 };
-use arcadia_api::{env::Env, Arcadia, OpenSignups};
+use arcadia_api::{env::Env, services::push_service::forward_push_messages, Arcadia, OpenSignups};
 use arcadia_storage::{
     connection_pool::ConnectionPool,
     models::user::{LoginResponse, User},
@@ -16,26 +17,60 @@ use arcadia_storage::{
 };
 use envconfig::Envconfig;
 use serde::{de::DeserializeOwned, Deserialize};
-use std::sync::Arc;
+use sqlx::PgPool;
+use std::{borrow::Borrow, sync::Arc};
+
+// Address the test users log in from, it matches their registration ip.
+pub const TEST_USER_IP: &str = "10.10.4.88";
 
 #[derive(Deserialize)]
 pub struct Profile {
     pub user: User,
 }
 
-pub async fn create_test_app<R: RedisPoolInterface + 'static>(
+#[derive(Debug, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorResponse {
+    pub error: String,
+    #[serde(default)]
+    pub field_errors: Vec<FieldError>,
+}
+
+// The test requests come through a reverse proxy forwarding the client ip in X-Forwarded-For.
+pub fn test_env() -> Env {
+    let mut env = Env::init_from_env().unwrap();
+    env.reverse_proxy_client_ip_header_name = Some("X-Forwarded-For".into());
+    env
+}
+
+pub async fn create_test_app<R: RedisPoolInterface + Send + Sync + 'static>(
     pool: Arc<ConnectionPool>,
     redis_pool: R,
     open_signups: OpenSignups,
     global_upload_factor: i16,
     global_download_factor: i16,
 ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
-    let mut env = Env::init_from_env().unwrap();
+    let mut env = test_env();
     env.open_signups = open_signups;
     env.global_upload_factor = global_upload_factor;
     env.global_download_factor = global_download_factor;
 
-    let arc = Arcadia::<R>::new(pool, Arc::new(redis_pool), env);
+    create_test_app_with_env(pool, redis_pool, env).await
+}
+
+pub async fn create_test_app_with_env<R: RedisPoolInterface + Send + Sync + 'static>(
+    pool: Arc<ConnectionPool>,
+    redis_pool: R,
+    env: Env,
+) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+    let redis_pool = Arc::new(redis_pool);
+    let arc = Arcadia::<R>::new(pool, Arc::clone(&redis_pool), env);
+    tokio::spawn(forward_push_messages(redis_pool, arc.push_sender.clone()));
 
     // TODO: CORS?
     test::init_service(
@@ -47,7 +82,7 @@ pub async fn create_test_app<R: RedisPoolInterface + 'static>(
 }
 
 // Requires "with_test_user" fixture.
-pub async fn create_test_app_and_login<R: RedisPoolInterface + 'static>(
+pub async fn create_test_app_and_login<R: RedisPoolInterface + Send + Sync + 'static>(
     pool: Arc<ConnectionPool>,
     redis_pool: R,
     global_upload_factor: i16,
@@ -66,28 +101,122 @@ pub async fn create_test_app_and_login<R: RedisPoolInterface + 'static>(
     .await;
 
     // Login first
+    let user = login(&service, "test_user").await;
+
+    (service, user)
+}
+
+// The test users of the fixtures all share the same password.
+pub async fn login<S>(service: &S, username: &str) -> LoginResponse
+where
+    S: Service<Request, Response = ServiceResponse, Error = Error>,
+{
     let req = test::TestRequest::post()
-        .insert_header(("X-Forwarded-For", "10.10.4.88"))
+        .insert_header(("X-Forwarded-For", TEST_USER_IP))
         .uri("/api/auth/login")
         .set_json(serde_json::json!({
-            "username": "test_user",
+            "username": username,
             "password": "test_password",
             "remember_me": true,
         }))
         .to_request();
 
-    let user = call_and_read_body_json::<LoginResponse, _>(&service, req).await;
+    let user = call_and_read_body_json::<LoginResponse, _>(service, req).await;
 
     assert!(!user.token.is_empty());
     assert!(!user.refresh_token.is_empty());
 
-    (service, user)
+    user
+}
+
+// Requires "with_test_user" fixture.
+// The class is part of the token, so test_user is promoted before logging in.
+pub async fn create_test_app_and_login_as_staff<R: RedisPoolInterface + Send + Sync + 'static>(
+    pool: Arc<ConnectionPool>,
+    redis_pool: R,
+    global_upload_factor: i16,
+    global_download_factor: i16,
+) -> (
+    impl Service<Request, Response = ServiceResponse, Error = Error>,
+    LoginResponse,
+) {
+    sqlx::query("UPDATE users SET class = 'staff' WHERE username = 'test_user'")
+        .execute(<ConnectionPool as Borrow<PgPool>>::borrow(&pool))
+        .await
+        .unwrap();
+
+    create_test_app_and_login(
+        pool,
+        redis_pool,
+        global_upload_factor,
+        global_download_factor,
+    )
+    .await
 }
 
 pub fn auth_header(token: &str) -> impl TryIntoHeaderPair {
     (AUTHORIZATION, format!("Bearer {}", token))
 }
 
+// Adds the headers every request of a logged in test user carries.
+pub fn authenticated(request: test::TestRequest, token: &str) -> test::TestRequest {
+    request
+        .insert_header(("X-Forwarded-For", TEST_USER_IP))
+        .insert_header(auth_header(token))
+}
+
+// Upload form for the debian torrent, on the edition group of the
+// "with_test_edition_group" fixture.
+pub fn torrent_upload_form(logs: &[&'static [u8]]) -> multipart::Form<'static> {
+    let mut form = multipart::Form::default();
+
+    form.add_text("release_name", "test release name");
+    form.add_text("uploaded_as_anonymous", "false");
+    form.add_text("languages", "English");
+    form.add_text("container", "FLAC");
+    form.add_text("edition_group_id", "1");
+    form.add_text("audio_codec", "flac");
+    form.add_text("features", "");
+    form.add_text("subtitle_languages", "");
+    form.add_text("extras", "");
+
+    let torrent_data = bytes::Bytes::from_static(include_bytes!(
+        "data/debian-12.10.0-i386-netinst.iso.torrent"
+    ));
+
+    form.add_reader_file(
+        "torrent_file",
+        std::io::Cursor::new(torrent_data),
+        "torrent_file.torrent",
+    );
+
+    for log in logs {
+        form.add_reader_file(
+            "log_files",
+            std::io::Cursor::new(bytes::Bytes::from_static(log)),
+            "rip.log",
+        );
+    }
+
+    form
+}
+
+pub async fn multipart_request(
+    request: test::TestRequest,
+    token: &str,
+    form: multipart::Form<'static>,
+) -> Request {
+    let content_type = form.content_type();
+    let payload = actix_web::body::to_bytes(multipart::Body::from(form))
+        .await
+        .unwrap();
+
+    authenticated(request, token)
+        .insert_header((CONTENT_TYPE, content_type))
+        .set_payload(payload)
+        .to_request()
+}
+
 pub async fn read_body_bencode<T: DeserializeOwned, B: MessageBody>(
     resp: ServiceResponse<B>,
 ) -> Result<T, serde_bencode::Error> {
@@ -2,3 +2,5 @@ INSERT INTO edition_groups (id, title_group_id, name, release_date, created_at,
 The publishing year was printed on the label, as “RECORDING FIRST PUBLISHED 1962″.
 This release has labels without “MADE IN GT. BRITAIN” printed below the Parlophone £ logo.
 Matrix numbers: Side A: 7XCE 17144-1N, Side B: 7XCE 17145-1N.', 'Parlophone', '{""}', '{https://www.discogs.com/release/1789539-The-Beatles-Love-Me-Do}', 'Vinyl', '{}');
+
+SELECT setval('edition_groups_id_seq', (SELECT MAX(id) FROM edition_groups));
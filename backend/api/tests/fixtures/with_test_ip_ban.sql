@@ -0,0 +1,4 @@
+INSERT INTO
+    ip_bans (created_by_id, ip_range, reason)
+VALUES
+    (1, '10.10.0.0/16', 'test ban');
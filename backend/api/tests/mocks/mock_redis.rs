@@ -1,7 +1,9 @@
 use arcadia_storage::redis::{error::Result, RedisInterface, RedisPoolInterface};
+use futures::{stream, Stream};
 use redis::ToRedisArgs;
 #[cfg(test)]
 use std::collections::HashMap;
+use tokio::sync::broadcast::{self, error::RecvError};
 
 #[derive(Default)]
 pub struct MockRedisPool {
@@ -18,11 +20,41 @@ impl RedisPoolInterface for MockRedisPool {
     async fn connection(&self) -> Result<impl RedisInterface> {
         Ok(self.conn.clone())
     }
+
+    // every channel is the same one, the tests only publish on the push channel
+    async fn subscribe(
+        &self,
+        _: &str,
+    ) -> Result<impl Stream<Item = String> + Send + Unpin + 'static> {
+        let receiver = self.conn.published.subscribe();
+        Ok(Box::pin(stream::unfold(
+            receiver,
+            |mut receiver| async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(message) => return Some((message, receiver)),
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            },
+        )))
+    }
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct MockRedis {
     inner: HashMap<Vec<u8>, Vec<u8>>,
+    published: broadcast::Sender<String>,
+}
+
+impl Default for MockRedis {
+    fn default() -> Self {
+        Self {
+            inner: HashMap::new(),
+            published: broadcast::channel(64).0,
+        }
+    }
 }
 
 impl RedisInterface for MockRedis {
@@ -59,4 +91,14 @@ impl RedisInterface for MockRedis {
         self.inner.remove(&key);
         Ok(())
     }
+
+    async fn publish<C, M>(&mut self, _: C, message: M) -> Result<()>
+    where
+        C: ToRedisArgs + Send,
+        M: ToRedisArgs + Send,
+    {
+        let message = message.to_redis_args()[0].clone();
+        let _ = self.published.send(String::from_utf8(message).unwrap());
+        Ok(())
+    }
 }
@@ -0,0 +1,43 @@
+use actix_web::{web, App, HttpResponse, HttpServer};
+use arcadia_shared::tracker::models::torrent::APIInsertTorrent;
+use reqwest::Url;
+use std::sync::{Arc, Mutex};
+
+type Upserts = Arc<Mutex<Vec<APIInsertTorrent>>>;
+
+// Stands in for the tracker's api, records the torrents the backend upserts in it
+pub struct MockTracker {
+    pub url: Url,
+    upserts: Upserts,
+}
+
+impl MockTracker {
+    pub async fn start() -> Self {
+        let upserts = Upserts::default();
+        let recorded = Arc::clone(&upserts);
+        let server = HttpServer::new(move || {
+            App::new()
+                .app_data(web::Data::from(Arc::clone(&recorded)))
+                .route("/api/torrents", web::put().to(upsert_torrent))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let url = Url::parse(&format!("http://{}", server.addrs()[0])).unwrap();
+        tokio::spawn(server.run());
+
+        Self { url, upserts }
+    }
+
+    pub fn upserted_torrents(&self) -> Vec<APIInsertTorrent> {
+        self.upserts.lock().unwrap().clone()
+    }
+}
+
+async fn upsert_torrent(
+    upserts: web::Data<Mutex<Vec<APIInsertTorrent>>>,
+    torrent: web::Json<APIInsertTorrent>,
+) -> HttpResponse {
+    upserts.lock().unwrap().push(torrent.into_inner());
+    HttpResponse::Ok().finish()
+}
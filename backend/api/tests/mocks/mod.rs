@@ -1 +1,2 @@
 pub mod mock_redis;
+pub mod mock_tracker;
@@ -17,7 +17,8 @@ use std::{sync::Arc, time::Duration};
 
 use crate::{
     common::{
-        auth_header, call_and_read_body_json, create_test_app, create_test_app_and_login, Profile,
+        auth_header, call_and_read_body_json, create_test_app, create_test_app_and_login,
+        create_test_app_with_env, Profile,
     },
     mocks::mock_redis::MockRedis,
 };
@@ -131,6 +132,122 @@ async fn test_duplicate_username_registration(pool: PgPool) {
     assert_eq!(error["error"], "username already exists");
 }
 
+#[sqlx::test(fixtures("with_test_ip_ban"), migrations = "../storage/migrations")]
+async fn test_registration_from_banned_ip(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let service = create_test_app(
+        pool,
+        MockRedisPool::default(),
+        OpenSignups::Enabled,
+        100,
+        100,
+    )
+    .await;
+
+    let req = TestRequest::post()
+        .insert_header(("X-Forwarded-For", "10.10.4.88"))
+        .uri("/api/auth/register")
+        .set_json(RegisterRequest {
+            username: "test_user",
+            password: "test_password",
+            password_verify: "test_password",
+            email: "test_email@testdomain.com",
+        })
+        .to_request();
+
+    let resp = call_service(&service, req).await;
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let body = read_body(resp).await;
+    let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error["error"], "ip address banned");
+}
+
+#[sqlx::test(fixtures("with_test_ip_ban"), migrations = "../storage/migrations")]
+async fn test_login_from_ipv4_mapped_banned_ip(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let service = create_test_app(
+        pool,
+        MockRedisPool::default(),
+        OpenSignups::Disabled,
+        100,
+        100,
+    )
+    .await;
+
+    let req = TestRequest::post()
+        .insert_header(("X-Forwarded-For", "::ffff:10.10.4.88"))
+        .uri("/api/auth/login")
+        .set_json(serde_json::json!({
+            "username": "test_user",
+            "password": "test_password",
+            "remember_me": true,
+        }))
+        .to_request();
+
+    let resp = call_service(&service, req).await;
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_ip_ban"),
+    migrations = "../storage/migrations"
+)]
+async fn test_login_ignores_client_ip_headers_without_reverse_proxy(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let mut env = common::test_env();
+    env.reverse_proxy_client_ip_header_name = None;
+    let service = create_test_app_with_env(pool, MockRedisPool::default(), env).await;
+
+    let login = |peer_addr: Option<&str>| {
+        let req = TestRequest::post()
+            .insert_header(("X-Forwarded-For", "10.10.4.88"))
+            .uri("/api/auth/login")
+            .set_json(serde_json::json!({
+                "username": "test_user",
+                "password": "test_password",
+                "remember_me": true,
+            }));
+        match peer_addr {
+            Some(peer_addr) => req.peer_addr(peer_addr.parse().unwrap()),
+            None => req,
+        }
+        .to_request()
+    };
+
+    // the banned ip in the header is not trusted
+    let resp = call_service(&service, login(Some("127.0.0.1:4000"))).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let resp = call_service(&service, login(Some("10.10.4.88:4000"))).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let resp = call_service(&service, login(None)).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_create_already_banned_ip_range(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login_as_staff(pool, MockRedisPool::default(), 100, 100).await;
+
+    // the host bits are zeroed, both are the same range
+    for (ip_range, status) in [
+        ("192.0.2.0/24", StatusCode::CREATED),
+        ("192.0.2.1/24", StatusCode::CONFLICT),
+    ] {
+        let req = common::authenticated(TestRequest::post(), &user.token)
+            .uri("/api/ip-bans")
+            .set_json(serde_json::json!({ "ip_range": ip_range, "reason": "proxy" }))
+            .to_request();
+        let resp = call_service(&service, req).await;
+        assert_eq!(resp.status(), status, "{ip_range}");
+    }
+}
+
 #[sqlx::test(
     fixtures("with_test_user", "with_test_user_invite"),
     migrations = "../storage/migrations"
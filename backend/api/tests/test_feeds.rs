@@ -0,0 +1,236 @@
+pub mod common;
+pub mod mocks;
+
+use std::sync::Arc;
+
+use actix_web::{http::StatusCode, test};
+use arcadia_storage::connection_pool::ConnectionPool;
+use mocks::mock_redis::MockRedisPool;
+use serde::Deserialize;
+use sqlx::PgPool;
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../storage/migrations"
+)]
+async fn test_torrent_feeds_with_passkey(pool: PgPool) {
+    let passkey: String = sqlx::query_scalar("SELECT passkey FROM users WHERE id = 2")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+
+    // feeds are fetched without a jwt, feed readers only know the passkey
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/feeds/torrents?passkey={passkey}"))
+        .insert_header(("X-Forwarded-For", common::TEST_USER_IP))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let rss = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert!(rss.contains("<rss"));
+    assert!(rss.contains(&format!(
+        "/api/feeds/download?torrent_id=1&amp;passkey={passkey}"
+    )));
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/feeds/torrents?passkey={passkey}&format=atom"
+        ))
+        .insert_header(("X-Forwarded-For", common::TEST_USER_IP))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let atom = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert!(atom.contains("<feed"));
+    assert!(atom.contains("torrent-1"));
+
+    let req = test::TestRequest::get()
+        .uri("/api/feeds/torrents?passkey=invalid")
+        .insert_header(("X-Forwarded-For", common::TEST_USER_IP))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    #[derive(Debug, Deserialize)]
+    struct SavedTorrentSearch {
+        id: i64,
+    }
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/saved-torrent-searches")
+        .set_json(serde_json::json!({
+            "name": "no match",
+            "search": {
+                "title_group_name": "title that does not exist",
+                "title_group_include_empty_groups": false,
+                "page": 1,
+                "page_size": 10,
+                "order_by_column": "torrent_created_at",
+                "order_by_direction": "desc"
+            }
+        }))
+        .to_request();
+    let saved_search = common::call_and_read_body_json_with_status::<SavedTorrentSearch, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/feeds/saved-searches?passkey={passkey}&saved_search_id={}",
+            saved_search.id
+        ))
+        .insert_header(("X-Forwarded-For", common::TEST_USER_IP))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let rss = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    assert!(rss.contains("no match"));
+    assert!(!rss.contains("torrent-1"));
+
+    let saved_search_request = |name: &str, filter: &str, value: serde_json::Value| {
+        let mut search = serde_json::json!({
+            "title_group_include_empty_groups": false,
+            "page": 1,
+            "page_size": 10,
+            "order_by_column": "torrent_created_at",
+            "order_by_direction": "desc"
+        });
+        search[filter] = value;
+        common::authenticated(test::TestRequest::post(), &user.token)
+            .uri("/api/saved-torrent-searches")
+            .set_json(serde_json::json!({ "name": name, "search": search }))
+            .to_request()
+    };
+
+    // the fixture torrent isn't reported
+    for (reported, listed) in [(true, false), (false, true)] {
+        let saved_search = common::call_and_read_body_json_with_status::<SavedTorrentSearch, _>(
+            &service,
+            saved_search_request("reported", "torrent_reported", reported.into()),
+            StatusCode::CREATED,
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/api/feeds/saved-searches?passkey={passkey}&saved_search_id={}",
+                saved_search.id
+            ))
+            .insert_header(("X-Forwarded-For", common::TEST_USER_IP))
+            .to_request();
+        let resp = test::call_service(&service, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let rss = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        assert_eq!(rss.contains("torrent-1"), listed);
+    }
+
+    let resp = test::call_service(
+        &service,
+        saved_search_request("snatched", "torrent_snatched_by_id", 2.into()),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/api/feeds/download?torrent_id=1&passkey={passkey}"
+        ))
+        .insert_header(("X-Forwarded-For", common::TEST_USER_IP))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../storage/migrations"
+)]
+async fn test_torznab_caps_and_search(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(pool, MockRedisPool::default(), 100, 100).await;
+
+    let read_xml = |resp: actix_web::dev::ServiceResponse| async move {
+        String::from_utf8(test::read_body(resp).await.to_vec()).unwrap()
+    };
+
+    let req = test::TestRequest::get()
+        .uri("/api/torznab?t=caps")
+        .insert_header(("X-Forwarded-For", common::TEST_USER_IP))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let caps = read_xml(resp).await;
+    assert!(caps.contains("<caps>"));
+    assert!(caps.contains(r#"<subcat id="4050" name="PC/Games"/>"#));
+
+    let req = test::TestRequest::get()
+        .uri("/api/torznab?t=search&apikey=invalid")
+        .insert_header(("X-Forwarded-For", common::TEST_USER_IP))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    assert!(read_xml(resp).await.contains(r#"code="100""#));
+
+    #[derive(Debug, Deserialize)]
+    struct APIKey {
+        value: String,
+    }
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/users/api-keys")
+        .set_json(serde_json::json!({ "name": "prowlarr" }))
+        .to_request();
+    let api_key = common::call_and_read_body_json_with_status::<APIKey, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    let search = |params: &str| {
+        test::TestRequest::get()
+            .uri(&format!("/api/torznab?apikey={}&{params}", api_key.value))
+            .insert_header(("X-Forwarded-For", common::TEST_USER_IP))
+            .to_request()
+    };
+
+    let resp = test::call_service(&service, search("t=music&album=love%20me%20do")).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let results = read_xml(resp).await;
+    assert!(results.contains("torrent-1"));
+    assert!(results.contains("<category>3000</category>"));
+    assert!(results.contains(r#"name="seeders""#));
+    assert!(results.contains(r#"name="downloadvolumefactor" value="1""#));
+    assert!(results.contains("/api/feeds/download?torrent_id=1"));
+
+    // the torrent is music, it is not listed in the movie or book categories
+    let resp = test::call_service(&service, search("t=movie&q=love")).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(!read_xml(resp).await.contains("torrent-1"));
+
+    let resp = test::call_service(&service, search("t=search&cat=7000,7020")).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(!read_xml(resp).await.contains("torrent-1"));
+
+    let resp = test::call_service(&service, search("t=search&cat=3000")).await;
+    assert!(read_xml(resp).await.contains("torrent-1"));
+}
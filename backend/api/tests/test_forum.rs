@@ -0,0 +1,838 @@
+pub mod common;
+pub mod mocks;
+
+use std::sync::Arc;
+
+use actix_web::{http::StatusCode, test};
+use arcadia_storage::connection_pool::ConnectionPool;
+use mocks::mock_redis::MockRedisPool;
+use sqlx::PgPool;
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_forum_moderation_keeps_counters_consistent(pool: PgPool) {
+    use serde_json::{json, Value};
+
+    let pg_pool = pool.clone();
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/thread")
+        .set_json(json!({
+            "forum_sub_category_id": 1,
+            "name": "Rips of the week",
+            "first_post": { "content": "first", "forum_thread_id": 0 }
+        }))
+        .to_request();
+    let thread =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::CREATED)
+            .await;
+    let thread_id = thread["id"].as_i64().unwrap();
+
+    let mut post_ids = vec![];
+    for content in ["second", "third"] {
+        let req = common::authenticated(test::TestRequest::post(), &user.token)
+            .uri("/api/forum/post")
+            .set_json(json!({ "content": content, "forum_thread_id": thread_id }))
+            .to_request();
+        let post = common::call_and_read_body_json_with_status::<Value, _>(
+            &service,
+            req,
+            StatusCode::CREATED,
+        )
+        .await;
+        post_ids.push(post["id"].as_i64().unwrap());
+    }
+
+    // users edit their own posts, keeping the previous versions
+    let req = common::authenticated(test::TestRequest::put(), &user.token)
+        .uri("/api/forum/post")
+        .set_json(json!({ "id": post_ids[0], "content": "second, edited", "sticky": false }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri(&format!("/api/forum/post/edits?post_id={}", post_ids[0]))
+        .to_request();
+    let edits =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK)
+            .await;
+    assert_eq!(edits.as_array().unwrap().len(), 1);
+    assert_eq!(edits[0]["previous_content"], "second");
+
+    // only staff moderates
+    let req = common::authenticated(test::TestRequest::delete(), &user.token)
+        .uri(&format!("/api/forum/post?id={}", post_ids[0]))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    sqlx::query("UPDATE forum_threads SET locked = TRUE WHERE id = $1")
+        .bind(thread_id)
+        .execute(&pg_pool)
+        .await
+        .unwrap();
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/post")
+        .set_json(json!({ "content": "too late", "forum_thread_id": thread_id }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let (service, staff) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    let counters = async |thread_id: i64| {
+        sqlx::query_as::<_, (i64, i64, i64, i32, i32)>(
+            r#"
+            SELECT
+                ft.posts_amount,
+                fsc.posts_amount,
+                fsc.threads_amount,
+                u.forum_posts,
+                u.forum_threads
+            FROM forum_threads ft
+            JOIN forum_sub_categories fsc ON fsc.id = ft.forum_sub_category_id
+            JOIN users u ON u.id = 2
+            WHERE ft.id = $1
+            "#,
+        )
+        .bind(thread_id)
+        .fetch_one(&pg_pool)
+        .await
+        .unwrap()
+    };
+    // the sub-category starts with the welcome thread and its post
+    assert_eq!(counters(thread_id).await, (3, 4, 2, 3, 1));
+
+    let req = common::authenticated(test::TestRequest::delete(), &staff.token)
+        .uri(&format!("/api/forum/post?id={}", post_ids[0]))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(counters(thread_id).await, (2, 3, 2, 2, 1));
+
+    let req = common::authenticated(test::TestRequest::put(), &staff.token)
+        .uri(&format!("/api/forum/post/restore?id={}", post_ids[0]))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(counters(thread_id).await, (3, 4, 2, 3, 1));
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/thread/split")
+        .set_json(json!({
+            "forum_thread_id": thread_id,
+            "post_ids": post_ids,
+            "name": "Off topic"
+        }))
+        .to_request();
+    let split_thread =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::CREATED)
+            .await;
+    let split_thread_id = split_thread["id"].as_i64().unwrap();
+    assert_eq!(split_thread["posts_amount"], 2);
+    assert_eq!(counters(thread_id).await, (1, 4, 3, 3, 2));
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/thread/merge")
+        .set_json(json!({
+            "source_thread_id": split_thread_id,
+            "target_thread_id": thread_id
+        }))
+        .to_request();
+    let merged_thread =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK)
+            .await;
+    assert_eq!(merged_thread["posts_amount"], 3);
+    assert_eq!(counters(thread_id).await, (3, 4, 2, 3, 1));
+
+    sqlx::raw_sql(
+        "INSERT INTO forum_sub_categories (forum_category_id, name, created_by_id) VALUES (1, 'Music', 1)",
+    )
+    .execute(&pg_pool)
+    .await
+    .unwrap();
+    let req = common::authenticated(test::TestRequest::put(), &staff.token)
+        .uri("/api/forum/thread")
+        .set_json(json!({
+            "id": thread_id,
+            "name": "Rips of the week",
+            "forum_sub_category_id": 2,
+            "sticky": true,
+            "locked": false
+        }))
+        .to_request();
+    let moved_thread =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK)
+            .await;
+    assert_eq!(moved_thread["sticky"], true);
+    assert_eq!(counters(thread_id).await, (3, 3, 1, 3, 1));
+
+    let site_logs = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM site_logs WHERE action::TEXT LIKE 'forum_%'",
+    )
+    .fetch_one(&pg_pool)
+    .await
+    .unwrap();
+    assert_eq!(site_logs, 5);
+}
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_forum_merge_keeps_poll_and_read_markers(pool: PgPool) {
+    use serde_json::{json, Value};
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+
+    let create_thread = async |name: &str, poll: Value| {
+        let req = common::authenticated(test::TestRequest::post(), &user.token)
+            .uri("/api/forum/thread")
+            .set_json(json!({
+                "forum_sub_category_id": 1,
+                "name": name,
+                "first_post": { "content": "first", "forum_thread_id": 0 },
+                "poll": poll
+            }))
+            .to_request();
+        let thread = common::call_and_read_body_json_with_status::<Value, _>(
+            &service,
+            req,
+            StatusCode::CREATED,
+        )
+        .await;
+        thread["id"].as_i64().unwrap()
+    };
+    let create_post = async |thread_id: i64| {
+        let req = common::authenticated(test::TestRequest::post(), &user.token)
+            .uri("/api/forum/post")
+            .set_json(json!({ "content": "reply", "forum_thread_id": thread_id }))
+            .to_request();
+        let post = common::call_and_read_body_json_with_status::<Value, _>(
+            &service,
+            req,
+            StatusCode::CREATED,
+        )
+        .await;
+        post["id"].as_i64().unwrap()
+    };
+    let get_json = async |uri: String| {
+        let req = common::authenticated(test::TestRequest::get(), &user.token)
+            .uri(&uri)
+            .to_request();
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK).await
+    };
+    let poll = json!({
+        "question": "Which one?",
+        "options": ["FLAC", "MP3 V0"],
+        "multiple_choice": false,
+        "anonymous": true,
+        "closes_at": null
+    });
+
+    let target_thread_id = create_thread("Formats", Value::Null).await;
+    get_json(format!(
+        "/api/forum/thread/posts?thread_id={target_thread_id}&page=1&page_size=10"
+    ))
+    .await;
+    let source_thread_id = create_thread("Formats, again", poll.clone()).await;
+    create_post(source_thread_id).await;
+    get_json(format!(
+        "/api/forum/thread/posts?thread_id={source_thread_id}&page=1&page_size=10"
+    ))
+    .await;
+    let unread_post_id = create_post(target_thread_id).await;
+    let other_poll_thread_id = create_thread("Bitrates", poll).await;
+
+    let (service, staff) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    let req = common::authenticated(test::TestRequest::put(), &staff.token)
+        .uri("/api/forum/thread")
+        .set_json(json!({
+            "id": target_thread_id,
+            "name": "Formats",
+            "forum_sub_category_id": 999,
+            "sticky": false,
+            "locked": false
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/thread/merge")
+        .set_json(json!({
+            "source_thread_id": source_thread_id,
+            "target_thread_id": target_thread_id
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // the posts read in the source thread stay read in the target one
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri(&format!("/api/forum/thread?id={target_thread_id}"))
+        .to_request();
+    let thread =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK)
+            .await;
+    assert_eq!(thread["first_unread_post_id"], unread_post_id);
+    assert_eq!(thread["poll"]["question"], "Which one?");
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/thread/merge")
+        .set_json(json!({
+            "source_thread_id": other_poll_thread_id,
+            "target_thread_id": target_thread_id
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
+
+// the tracker class is declared after staff in user_class_enum, it must not outrank it
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_tracker_class_cannot_read_staff_only_sub_category(pool: PgPool) {
+    use serde_json::{json, Value};
+
+    let pg_pool = pool.clone();
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, staff) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/sub-category")
+        .set_json(json!({
+            "forum_category_id": 1,
+            "name": "Staff room",
+            "sort_order": 0,
+            "min_class_read": "staff",
+            "min_class_post": "staff",
+            "min_class_create_thread": "staff"
+        }))
+        .to_request();
+    let sub_category =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::CREATED)
+            .await;
+    let sub_category_id = sub_category["id"].as_i64().unwrap();
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/thread")
+        .set_json(json!({
+            "forum_sub_category_id": sub_category_id,
+            "name": "Secret plans",
+            "first_post": { "content": "first", "forum_thread_id": 0 }
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    sqlx::query("UPDATE users SET class = 'tracker' WHERE username = 'test_user'")
+        .execute(&pg_pool)
+        .await
+        .unwrap();
+    let tracker = common::login(&service, "test_user").await;
+
+    let req = common::authenticated(test::TestRequest::get(), &tracker.token)
+        .uri(&format!("/api/forum/sub-category?id={sub_category_id}"))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let req = common::authenticated(test::TestRequest::get(), &tracker.token)
+        .uri("/api/search/forum?thread_name=secret&page=1&page_size=10")
+        .to_request();
+    let results =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK)
+            .await;
+    assert_eq!(results["total_items"], 0);
+}
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_user2"),
+    migrations = "../storage/migrations"
+)]
+async fn test_staff_only_thread_does_not_notify_newbies(pool: PgPool) {
+    use serde_json::{json, Value};
+
+    let pg_pool = pool.clone();
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, staff) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/sub-category")
+        .set_json(json!({
+            "forum_category_id": 1,
+            "name": "Staff room",
+            "sort_order": 0,
+            "min_class_read": "staff",
+            "min_class_post": "staff",
+            "min_class_create_thread": "staff"
+        }))
+        .to_request();
+    let sub_category =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::CREATED)
+            .await;
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/thread")
+        .set_json(json!({
+            "forum_sub_category_id": sub_category["id"],
+            "name": "Secret plans",
+            "first_post": { "content": "first", "forum_thread_id": 0 }
+        }))
+        .to_request();
+    let thread =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::CREATED)
+            .await;
+    let thread_id = thread["id"].as_i64().unwrap();
+
+    sqlx::query("UPDATE users SET class = 'newbie' WHERE username = 'test_user2'")
+        .execute(&pg_pool)
+        .await
+        .unwrap();
+    let newbie = common::login(&service, "test_user2").await;
+
+    let req = common::authenticated(test::TestRequest::post(), &newbie.token)
+        .uri(&format!(
+            "/api/subscriptions/forum-thread-posts?thread_id={thread_id}"
+        ))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    // a subscription made before the sub-category was restricted
+    sqlx::query(
+        "INSERT INTO subscriptions_forum_thread_posts (forum_thread_id, user_id)
+         SELECT $1, id FROM users WHERE username = 'test_user2'",
+    )
+    .bind(thread_id)
+    .execute(&pg_pool)
+    .await
+    .unwrap();
+
+    let post_mentioning_newbie = || {
+        common::authenticated(test::TestRequest::post(), &staff.token)
+            .uri("/api/forum/post")
+            .set_json(json!({
+                "content": "@test_user2 [quote=test_user2]hi[/quote]",
+                "forum_thread_id": thread_id
+            }))
+            .to_request()
+    };
+    let newbie_notifications = || async {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM notifications n
+             JOIN users u ON u.id = n.user_id
+             WHERE u.username = 'test_user2'",
+        )
+        .fetch_one(&pg_pool)
+        .await
+        .unwrap()
+    };
+
+    let resp = test::call_service(&service, post_mentioning_newbie()).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    assert_eq!(newbie_notifications().await, 0);
+
+    // once promoted, the same post notifies the thread post, the mention and the quote
+    sqlx::query("UPDATE users SET class = 'staff' WHERE username = 'test_user2'")
+        .execute(&pg_pool)
+        .await
+        .unwrap();
+
+    let resp = test::call_service(&service, post_mentioning_newbie()).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    assert_eq!(newbie_notifications().await, 3);
+}
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_forum_sub_category_min_classes_are_enforced(pool: PgPool) {
+    use serde_json::{json, Value};
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    // the class is part of the token, so the first login stays a newbie
+    let (newbie_service, newbie) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+    let (staff_service, staff) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    let req = common::authenticated(test::TestRequest::post(), &newbie.token)
+        .uri("/api/forum/category")
+        .set_json(json!({ "name": "Staff", "sort_order": -1 }))
+        .to_request();
+    let resp = test::call_service(&newbie_service, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/category")
+        .set_json(json!({ "name": "Staff", "sort_order": -1 }))
+        .to_request();
+    let category = common::call_and_read_body_json_with_status::<Value, _>(
+        &staff_service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/sub-category")
+        .set_json(json!({
+            "forum_category_id": category["id"],
+            "name": "Staff room",
+            "sort_order": 0,
+            "min_class_read": "staff",
+            "min_class_post": "staff",
+            "min_class_create_thread": "staff"
+        }))
+        .to_request();
+    let sub_category = common::call_and_read_body_json_with_status::<Value, _>(
+        &staff_service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+    let sub_category_id = sub_category["id"].as_i64().unwrap();
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/forum/thread")
+        .set_json(json!({
+            "forum_sub_category_id": sub_category_id,
+            "name": "Secret plans",
+            "first_post": { "content": "first", "forum_thread_id": 0 }
+        }))
+        .to_request();
+    let thread = common::call_and_read_body_json_with_status::<Value, _>(
+        &staff_service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+    let thread_id = thread["id"].as_i64().unwrap();
+
+    // the newbie can neither see nor use the sub-category
+    for uri in [
+        format!("/api/forum/sub-category?id={sub_category_id}"),
+        format!("/api/forum/thread?id={thread_id}"),
+        format!("/api/forum/thread/posts?thread_id={thread_id}&page_size=10"),
+    ] {
+        let req = common::authenticated(test::TestRequest::get(), &newbie.token)
+            .uri(&uri)
+            .to_request();
+        let resp = test::call_service(&newbie_service, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN, "{uri}");
+    }
+    let req = common::authenticated(test::TestRequest::post(), &newbie.token)
+        .uri("/api/forum/post")
+        .set_json(json!({ "content": "let me in", "forum_thread_id": thread_id }))
+        .to_request();
+    let resp = test::call_service(&newbie_service, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let req = common::authenticated(test::TestRequest::get(), &newbie.token)
+        .uri("/api/search/forum?thread_name=secret&page=1&page_size=10")
+        .to_request();
+    let results = common::call_and_read_body_json_with_status::<Value, _>(
+        &newbie_service,
+        req,
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(results["total_items"], 0);
+
+    let req = common::authenticated(test::TestRequest::get(), &newbie.token)
+        .uri("/api/forum")
+        .to_request();
+    let overview = common::call_and_read_body_json_with_status::<Value, _>(
+        &newbie_service,
+        req,
+        StatusCode::OK,
+    )
+    .await;
+    let categories = overview["forum_categories"].as_array().unwrap();
+    // ordered by sort_order, the staff category comes first but is empty for the newbie
+    assert_eq!(categories[0]["name"], "Staff");
+    assert!(categories[0]["sub_categories"].is_null());
+    assert_eq!(categories[1]["sub_categories"][0]["name"], "Announcements");
+
+    let req = common::authenticated(test::TestRequest::get(), &staff.token)
+        .uri("/api/search/forum?thread_name=secret&page=1&page_size=10")
+        .to_request();
+    let results = common::call_and_read_body_json_with_status::<Value, _>(
+        &staff_service,
+        req,
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(results["total_items"], 1);
+
+    // non empty categories and sub-categories cannot be deleted
+    let req = common::authenticated(test::TestRequest::delete(), &staff.token)
+        .uri(&format!("/api/forum/sub-category?id={sub_category_id}"))
+        .to_request();
+    let resp = test::call_service(&staff_service, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let req = common::authenticated(test::TestRequest::delete(), &staff.token)
+        .uri(&format!("/api/forum/category?id={}", category["id"]))
+        .to_request();
+    let resp = test::call_service(&staff_service, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    // opening the sub-category to everyone makes it visible
+    let req = common::authenticated(test::TestRequest::put(), &staff.token)
+        .uri("/api/forum/sub-category")
+        .set_json(json!({
+            "id": sub_category_id,
+            "forum_category_id": category["id"],
+            "name": "Staff room",
+            "sort_order": 0,
+            "min_class_read": "newbie",
+            "min_class_post": "staff",
+            "min_class_create_thread": "staff"
+        }))
+        .to_request();
+    common::call_and_read_body_json_with_status::<Value, _>(&staff_service, req, StatusCode::OK)
+        .await;
+
+    let req = common::authenticated(test::TestRequest::get(), &newbie.token)
+        .uri(&format!("/api/forum/thread?id={thread_id}"))
+        .to_request();
+    common::call_and_read_body_json_with_status::<Value, _>(&newbie_service, req, StatusCode::OK)
+        .await;
+    let req = common::authenticated(test::TestRequest::post(), &newbie.token)
+        .uri("/api/forum/post")
+        .set_json(json!({ "content": "read only", "forum_thread_id": thread_id }))
+        .to_request();
+    let resp = test::call_service(&newbie_service, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_forum_poll_results_are_hidden_until_voting(pool: PgPool) {
+    use serde_json::{json, Value};
+
+    let pg_pool = pool.clone();
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/thread")
+        .set_json(json!({
+            "forum_sub_category_id": 1,
+            "name": "Best format?",
+            "first_post": { "content": "vote below", "forum_thread_id": 0 },
+            "poll": {
+                "question": "Which one?",
+                "options": ["FLAC"],
+                "multiple_choice": false,
+                "anonymous": false,
+                "closes_at": null
+            }
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let too_many_options: Vec<String> = (0..21).map(|i| format!("option {i}")).collect();
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/thread")
+        .set_json(json!({
+            "forum_sub_category_id": 1,
+            "name": "Best format?",
+            "first_post": { "content": "vote below", "forum_thread_id": 0 },
+            "poll": {
+                "question": "Which one?",
+                "options": too_many_options,
+                "multiple_choice": false,
+                "anonymous": false,
+                "closes_at": null
+            }
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/thread")
+        .set_json(json!({
+            "forum_sub_category_id": 1,
+            "name": "Best format?",
+            "first_post": { "content": "vote below", "forum_thread_id": 0 },
+            "poll": {
+                "question": "Which one?",
+                "options": ["FLAC", "MP3 V0"],
+                "multiple_choice": false,
+                "anonymous": false,
+                "closes_at": null
+            }
+        }))
+        .to_request();
+    let thread =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::CREATED)
+            .await;
+    let thread_id = thread["id"].as_i64().unwrap();
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri(&format!("/api/forum/thread?id={thread_id}"))
+        .to_request();
+    let thread =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK)
+            .await;
+    let poll = &thread["poll"];
+    assert_eq!(poll["results_visible"], false);
+    assert!(poll["voters_amount"].is_null());
+    assert!(poll["options"][0]["votes_amount"].is_null());
+    let poll_id = poll["id"].as_i64().unwrap();
+    let option_ids: Vec<i64> = poll["options"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|option| option["id"].as_i64().unwrap())
+        .collect();
+
+    // single choice poll
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/poll/vote")
+        .set_json(json!({ "forum_poll_id": poll_id, "option_ids": option_ids }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/poll/vote")
+        .set_json(json!({ "forum_poll_id": poll_id, "option_ids": [option_ids[0]] }))
+        .to_request();
+    let poll =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::CREATED)
+            .await;
+    assert_eq!(poll["results_visible"], true);
+    assert_eq!(poll["voters_amount"], 1);
+    assert_eq!(poll["voted_option_ids"], json!([option_ids[0]]));
+    assert_eq!(poll["options"][0]["votes_amount"], 1);
+    assert_eq!(poll["options"][1]["votes_amount"], 0);
+    // public poll
+    assert_eq!(poll["options"][0]["voters"][0]["username"], "test_user");
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/poll/vote")
+        .set_json(json!({ "forum_poll_id": poll_id, "option_ids": [option_ids[1]] }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    sqlx::query("UPDATE forum_threads SET locked = TRUE WHERE id = $1")
+        .bind(thread_id)
+        .execute(&pg_pool)
+        .await
+        .unwrap();
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/poll/vote")
+        .set_json(json!({ "forum_poll_id": poll_id, "option_ids": [option_ids[1]] }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_forum_read_tracking_finds_first_unread_page(pool: PgPool) {
+    use serde_json::{json, Value};
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+
+    let get_json = |uri: String| {
+        let req = common::authenticated(test::TestRequest::get(), &user.token)
+            .uri(&uri)
+            .to_request();
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK)
+    };
+
+    // the seeded thread was never opened
+    let sub_category = get_json("/api/forum/sub-category?id=1&posts_page_size=2".into()).await;
+    assert_eq!(sub_category["threads"][0]["has_unread"], true);
+    assert_eq!(sub_category["threads"][0]["first_unread_page"], 1);
+
+    let mut post_ids = vec![];
+    for content in ["second", "third", "fourth"] {
+        let req = common::authenticated(test::TestRequest::post(), &user.token)
+            .uri("/api/forum/post")
+            .set_json(json!({ "content": content, "forum_thread_id": 1 }))
+            .to_request();
+        let post = common::call_and_read_body_json_with_status::<Value, _>(
+            &service,
+            req,
+            StatusCode::CREATED,
+        )
+        .await;
+        post_ids.push(post["id"].as_i64().unwrap());
+    }
+
+    // reading the first page marks its posts as read
+    get_json("/api/forum/thread/posts?thread_id=1&page=1&page_size=2".into()).await;
+    let thread = get_json("/api/forum/thread?id=1&posts_page_size=2".into()).await;
+    assert_eq!(thread["has_unread"], true);
+    assert_eq!(thread["first_unread_post_id"], post_ids[1]);
+    assert_eq!(thread["first_unread_page"], 2);
+
+    // going back to the first page doesn't move the marker backwards
+    get_json("/api/forum/thread/posts?thread_id=1&page=2&page_size=2".into()).await;
+    get_json("/api/forum/thread/posts?thread_id=1&page=1&page_size=2".into()).await;
+    let thread = get_json("/api/forum/thread?id=1&posts_page_size=2".into()).await;
+    assert_eq!(thread["has_unread"], false);
+    assert!(thread["first_unread_page"].is_null());
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/forum/post")
+        .set_json(json!({ "content": "fifth", "forum_thread_id": 1 }))
+        .to_request();
+    common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::CREATED)
+        .await;
+    let sub_category = get_json("/api/forum/sub-category?id=1&posts_page_size=2".into()).await;
+    assert_eq!(sub_category["threads"][0]["has_unread"], true);
+    assert_eq!(sub_category["threads"][0]["first_unread_page"], 3);
+
+    // catching up on the whole sub-category
+    let req = common::authenticated(test::TestRequest::put(), &user.token)
+        .uri("/api/forum/sub-category/read?id=1")
+        .to_request();
+    common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK).await;
+    let sub_category = get_json("/api/forum/sub-category?id=1".into()).await;
+    assert_eq!(sub_category["threads"][0]["has_unread"], false);
+}
@@ -0,0 +1,463 @@
+pub mod common;
+pub mod mocks;
+
+use std::sync::Arc;
+
+use actix_web::{http::StatusCode, test};
+use arcadia_storage::connection_pool::ConnectionPool;
+use mocks::mock_redis::MockRedisPool;
+use serde::Deserialize;
+use sqlx::PgPool;
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_notification_filters_on_upload(pool: PgPool) {
+    use arcadia_common::error::Error;
+    use arcadia_storage::models::{
+        feed::TorrentFeedFilter,
+        notification::{NotificationPreference, NotificationQuery, NotificationType},
+        notification_filter::UserCreatedNotificationFilter,
+        title_group::ContentType,
+    };
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+
+    #[derive(Debug, Deserialize)]
+    struct NotificationFilter {
+        name: String,
+        containers: Vec<String>,
+    }
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/notification-filters")
+        .set_json(serde_json::json!({
+            "name": "flac singles",
+            "content_types": ["music"],
+            "containers": ["FLAC"],
+        }))
+        .to_request();
+    let filter = common::call_and_read_body_json_with_status::<NotificationFilter, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+    assert_eq!(filter.name, "flac singles");
+    assert_eq!(filter.containers, ["flac"]);
+
+    // filters of another user, both match the upload but only the second one is in the feed
+    let user_filter =
+        |name: &str, min_size: Option<i64>, include_in_feed: bool| UserCreatedNotificationFilter {
+            name: name.to_string(),
+            artist_ids: vec![],
+            tags: vec!["Pop".to_string()],
+            content_types: vec![ContentType::Music],
+            containers: vec![],
+            audio_codecs: vec![],
+            video_resolutions: vec![],
+            release_groups: vec![],
+            uploader_ids: vec![2],
+            min_size,
+            include_in_feed,
+        };
+    pool.create_notification_filter(&user_filter("pop", None, false), 1, 2)
+        .await
+        .unwrap();
+    pool.create_notification_filter(&user_filter("pop feed", Some(1), true), 1, 2)
+        .await
+        .unwrap();
+    assert!(matches!(
+        pool.create_notification_filter(&user_filter("too many", None, true), 1, 2)
+            .await,
+        Err(Error::NotificationFilterLimitReached(2))
+    ));
+
+    let form = common::torrent_upload_form(&[]);
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+
+    #[derive(Debug, Deserialize)]
+    struct Torrent {
+        id: i32,
+    }
+
+    let torrent = common::call_and_read_body_json_with_status::<Torrent, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    let query = NotificationQuery {
+        include_read: false,
+        notification_type: Some(NotificationType::FilteredTorrent),
+        page: 1,
+        page_size: 10,
+    };
+    let notifications = pool.find_notifications(1, &query).await.unwrap().results;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].target_id, torrent.id as i64);
+    assert_eq!(notifications[0].payload["notification_filter_name"], "pop");
+
+    // the uploader isn't notified of their own torrent
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri("/api/notifications?include_read=true&notification_type=filtered_torrent&page=1&page_size=10")
+        .to_request();
+    let own_notifications = common::call_and_read_body_json_with_status::<serde_json::Value, _>(
+        &service,
+        req,
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(own_notifications["total_items"], 0);
+
+    let feed_torrents = pool
+        .find_feed_torrents(&TorrentFeedFilter {
+            notified_user_id: Some(1),
+            limit: 50,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(feed_torrents.len(), 1);
+    assert_eq!(feed_torrents[0].id, torrent.id);
+
+    // the feed doesn't depend on the notification nor on the notification preferences
+    pool.delete_notification(notifications[0].id, 1)
+        .await
+        .unwrap();
+    pool.update_notification_preferences(
+        &[NotificationPreference {
+            notification_type: NotificationType::FilteredTorrent,
+            enabled: false,
+        }],
+        1,
+    )
+    .await
+    .unwrap();
+    let feed_torrents = pool
+        .find_feed_torrents(&TorrentFeedFilter {
+            notified_user_id: Some(1),
+            limit: 50,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(feed_torrents.len(), 1);
+}
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_artist_and_series_subscriptions_notify_on_upload(pool: PgPool) {
+    sqlx::raw_sql(
+        r#"
+        INSERT INTO artists (id, name, description, pictures, created_by_id)
+        VALUES (1, 'The Beatles', '', '{}', 1);
+        INSERT INTO affiliated_artists (title_group_id, artist_id, roles, created_by_id)
+        VALUES (1, 1, '{}', 1);
+        INSERT INTO series (id, name, description, tags, covers, banners, created_by_id)
+        VALUES (1, 'Beatles singles', '', '{}', '{}', '{}', 1);
+        UPDATE title_groups SET series_id = 1 WHERE id = 1;
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let pg_pool = pool.clone();
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+
+    for (method, uri, status) in [
+        (
+            test::TestRequest::post(),
+            "/api/subscriptions/artist-torrents?artist_id=1",
+            StatusCode::CREATED,
+        ),
+        (
+            test::TestRequest::post(),
+            "/api/subscriptions/series-torrents?series_id=1",
+            StatusCode::CREATED,
+        ),
+        (
+            test::TestRequest::delete(),
+            "/api/subscriptions/series-torrents?series_id=1",
+            StatusCode::OK,
+        ),
+    ] {
+        let req = common::authenticated(method, &user.token)
+            .uri(uri)
+            .to_request();
+        let resp = test::call_service(&service, req).await;
+        assert_eq!(resp.status(), status);
+    }
+
+    // the uploader is test_user, another user follows the artist and the series
+    pool.create_subscription_artist_torrents(1, 1)
+        .await
+        .unwrap();
+    pool.create_subscription_series_torrents(1, 1)
+        .await
+        .unwrap();
+
+    let form = common::torrent_upload_form(&[]);
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let count_notifications = |notification_type: &'static str, user_id: i32| {
+        let pg_pool = pg_pool.clone();
+        async move {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM notifications WHERE notification_type = $1::notification_type_enum AND user_id = $2",
+            )
+            .bind(notification_type)
+            .bind(user_id)
+            .fetch_one(&pg_pool)
+            .await
+            .unwrap()
+        }
+    };
+
+    assert_eq!(count_notifications("artist_torrent", 1).await, 1);
+    assert_eq!(count_notifications("series_torrent", 1).await, 1);
+    // the uploader isn't notified of their own torrent
+    assert_eq!(count_notifications("artist_torrent", 2).await, 0);
+}
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_notification_center_read_state_and_preferences(pool: PgPool) {
+    use arcadia_storage::models::gift::UserCreatedGift;
+    use serde_json::{json, Value};
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+
+    let gift = UserCreatedGift {
+        message: String::from("thanks for the uploads"),
+        receiver_id: 2,
+        bonus_points: 10,
+        freeleech_tokens: 0,
+    };
+    pool.create_gift(&gift, 1).await.unwrap();
+
+    let get_notifications = |include_read: bool| {
+        common::authenticated(test::TestRequest::get(), &user.token)
+            .uri(&format!(
+                "/api/notifications?include_read={include_read}&page=1&page_size=20"
+            ))
+            .to_request()
+    };
+
+    let notifications = common::call_and_read_body_json_with_status::<Value, _>(
+        &service,
+        get_notifications(false),
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(notifications["total_items"], 1);
+    let notification = &notifications["results"][0];
+    assert_eq!(notification["notification_type"], "gift_received");
+    assert_eq!(notification["actor_id"], 1);
+    assert_eq!(notification["payload"]["bonus_points"], 10);
+    let notification_id = notification["id"].as_i64().unwrap();
+
+    let req = common::authenticated(test::TestRequest::put(), &user.token)
+        .uri("/api/notifications/read")
+        .set_json(json!({ "notification_ids": [notification_id] }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let unread = common::call_and_read_body_json_with_status::<Value, _>(
+        &service,
+        get_notifications(false),
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(unread["total_items"], 0);
+
+    // disabled notification types are not stored anymore
+    let req = common::authenticated(test::TestRequest::put(), &user.token)
+        .uri("/api/notifications/preferences")
+        .set_json(json!([{ "notification_type": "gift_received", "enabled": false }]))
+        .to_request();
+    let preferences =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK)
+            .await;
+    let gift_preference = preferences
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|preference| preference["notification_type"] == "gift_received")
+        .unwrap();
+    assert_eq!(gift_preference["enabled"], false);
+
+    pool.create_gift(&gift, 1).await.unwrap();
+
+    let all = common::call_and_read_body_json_with_status::<Value, _>(
+        &service,
+        get_notifications(true),
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(all["total_items"], 1);
+    assert_eq!(all["results"][0]["read_status"], true);
+
+    let req = common::authenticated(test::TestRequest::delete(), &user.token)
+        .uri(&format!("/api/notifications?id={notification_id}"))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = common::authenticated(test::TestRequest::delete(), &user.token)
+        .uri(&format!("/api/notifications?id={notification_id}"))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_notifications_stream_pushes_published_notifications(pool: PgPool) {
+    use actix_web::body::MessageBody;
+    use arcadia_api::services::push_service::{PushEvent, PushMessage, PUSH_CHANNEL};
+    use arcadia_storage::{models::gift::UserCreatedGift, redis::RedisInterface};
+    use mocks::mock_redis::MockRedis;
+    use std::{future::poll_fn, pin::pin, time::Duration};
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    // stands for the redis connection of another backend instance
+    let mut redis_conn = MockRedis::default();
+    let (service, user) = common::create_test_app_and_login(
+        Arc::clone(&pool),
+        MockRedisPool::with_conn(redis_conn.clone()),
+        100,
+        100,
+    )
+    .await;
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri("/api/notifications/stream")
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+
+    let gift = UserCreatedGift {
+        message: String::from("thanks for the uploads"),
+        receiver_id: 2,
+        bonus_points: 10,
+        freeleech_tokens: 0,
+    };
+    pool.create_gift(&gift, 1).await.unwrap();
+
+    // only the recipients of a message get it pushed
+    for user_ids in [vec![1], vec![2]] {
+        let message = PushMessage {
+            user_ids,
+            event: PushEvent::Notifications,
+        };
+        redis_conn
+            .publish(PUSH_CHANNEL, serde_json::to_string(&message).unwrap())
+            .await
+            .unwrap();
+    }
+
+    let mut body = pin!(resp.into_body());
+    // skips the keep-alive comments
+    let mut read_event = async || loop {
+        let frame = tokio::time::timeout(
+            Duration::from_secs(5),
+            poll_fn(|cx| body.as_mut().poll_next(cx)),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+        let frame = String::from_utf8(frame.to_vec()).unwrap();
+        if !frame.starts_with(':') {
+            return frame;
+        }
+    };
+
+    let notification = read_event().await;
+    assert!(notification.starts_with("event: notification"));
+    assert!(notification.contains("gift_received"));
+
+    let unread_amounts = read_event().await;
+    assert!(unread_amounts.starts_with("event: unread_amounts"));
+    assert!(unread_amounts.contains(r#""unread_notifications_amount":1"#));
+}
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_notifications_stream_ends_when_the_token_expires(pool: PgPool) {
+    use actix_web::body::MessageBody;
+    use arcadia_api::env::Env;
+    use arcadia_storage::models::user::{Claims, UserClass};
+    use chrono::Utc;
+    use envconfig::Envconfig;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use std::{future::poll_fn, pin::pin, time::Duration};
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let service = common::create_test_app(
+        pool,
+        MockRedisPool::default(),
+        arcadia_api::OpenSignups::Disabled,
+        100,
+        100,
+    )
+    .await;
+
+    let now = Utc::now().timestamp();
+    let claims = Claims {
+        sub: 1,
+        iat: now,
+        exp: now + 2,
+        class: UserClass::Newbie,
+    };
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(Env::init_from_env().unwrap().jwt_secret.as_bytes()),
+    )
+    .unwrap();
+
+    let req = common::authenticated(test::TestRequest::get(), &token)
+        .uri("/api/notifications/stream")
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let mut body = pin!(resp.into_body());
+    let ended = tokio::time::timeout(Duration::from_secs(10), async {
+        while poll_fn(|cx| body.as_mut().poll_next(cx)).await.is_some() {}
+    })
+    .await;
+    assert!(ended.is_ok(), "the stream outlived its token");
+}
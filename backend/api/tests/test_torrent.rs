@@ -136,6 +136,417 @@ async fn test_upload_torrent(pool: PgPool) {
     assert_eq!(torrent.created_by_id, 2);
 }
 
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_upload_and_download_hybrid_torrent(pool: PgPool) {
+    use actix_multipart_rfc7578::client::multipart;
+    use bip_metainfo::Metainfo;
+
+    let mut form = multipart::Form::default();
+
+    form.add_text("release_name", "test hybrid release");
+    form.add_text("languages", "English");
+    form.add_text("container", "MKV");
+    form.add_text("edition_group_id", "1");
+    form.add_text("features", "");
+    form.add_text("subtitle_languages", "");
+    form.add_text("extras", "");
+    form.add_text("uploaded_as_anonymous", "false");
+
+    let torrent_data = include_bytes!("data/hybrid.torrent");
+    let uploaded = Metainfo::from_bytes(torrent_data).expect("test torrent is valid");
+
+    form.add_reader_file(
+        "torrent_file",
+        std::io::Cursor::new(bytes::Bytes::from_static(torrent_data)),
+        "hybrid.torrent",
+    );
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(pool, MockRedisPool::default(), 100, 100).await;
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+
+    #[derive(Debug, Deserialize)]
+    struct Torrent {
+        id: i32,
+        info_hash: Vec<u8>,
+        info_hash_v2: Option<Vec<u8>>,
+    }
+
+    let torrent = common::call_and_read_body_json_with_status::<Torrent, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri(&format!("/api/torrents?id={}", torrent.id))
+        .to_request();
+
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let downloaded =
+        Metainfo::from_bytes(test::read_body(resp).await).expect("downloaded torrent is valid");
+    let info = downloaded.info();
+
+    assert!(info.is_hybrid(), "expected downloaded torrent to be hybrid");
+    assert_eq!(info.is_private(), Some(true));
+    assert_eq!(downloaded.piece_layers(), uploaded.piece_layers());
+    assert_eq!(info.files().count(), 1);
+
+    // the stored info hashes are the ones of the downloaded (private) torrent
+    assert_eq!(torrent.info_hash, info.info_hash().as_ref());
+    assert_eq!(
+        torrent.info_hash_v2.as_deref(),
+        info.info_hash_v2().as_ref().map(|hash| &hash[..20])
+    );
+}
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_upload_hybrid_torrent_skips_padding_files(pool: PgPool) {
+    use actix_multipart_rfc7578::client::multipart;
+
+    let mut form = multipart::Form::default();
+
+    form.add_text("release_name", "test padded hybrid release");
+    form.add_text("languages", "English");
+    form.add_text("container", "MKV");
+    form.add_text("edition_group_id", "1");
+    form.add_text("features", "");
+    form.add_text("subtitle_languages", "");
+    form.add_text("extras", "");
+    form.add_text("uploaded_as_anonymous", "false");
+    // a.bin is padded to the second piece boundary by a .pad file of 12768 bytes
+    form.add_reader_file(
+        "torrent_file",
+        std::io::Cursor::new(bytes::Bytes::from_static(include_bytes!(
+            "data/hybrid_padded.torrent"
+        ))),
+        "hybrid_padded.torrent",
+    );
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(pool, MockRedisPool::default(), 100, 100).await;
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+
+    #[derive(Debug, Deserialize)]
+    struct Torrent {
+        size: i64,
+        file_list: serde_json::Value,
+        file_amount_per_type: serde_json::Value,
+    }
+
+    let torrent = common::call_and_read_body_json_with_status::<Torrent, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    assert_eq!(
+        torrent.file_list,
+        serde_json::json!({
+            "parent_folder": "padded release",
+            "files": [
+                {"name": "a.bin", "size": 20000},
+                {"name": "b.bin", "size": 10000},
+            ],
+        })
+    );
+    assert_eq!(torrent.file_amount_per_type, serde_json::json!({"bin": 2}));
+    assert_eq!(torrent.size, 30000);
+}
+
+async fn cross_seed_request(token: &str, torrent_file: &'static [u8]) -> actix_http::Request {
+    use actix_multipart_rfc7578::client::multipart;
+
+    let mut form = multipart::Form::default();
+    form.add_reader_file(
+        "torrent_file",
+        std::io::Cursor::new(bytes::Bytes::from_static(torrent_file)),
+        "torrent_file.torrent",
+    );
+
+    common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents/cross-seed"),
+        token,
+        form,
+    )
+    .await
+}
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_cross_seed_torrent(pool: PgPool) {
+    use actix_multipart_rfc7578::client::multipart;
+    use bip_metainfo::Metainfo;
+
+    let torrent_data = include_bytes!("data/debian-12.10.0-i386-netinst.iso.torrent");
+    let original = Metainfo::from_bytes(torrent_data).expect("test torrent is valid");
+
+    let mut form = multipart::Form::default();
+
+    form.add_text("release_name", "test release name");
+    form.add_text("languages", "English");
+    form.add_text("container", "ISO");
+    form.add_text("edition_group_id", "1");
+    form.add_text("features", "");
+    form.add_text("subtitle_languages", "");
+    form.add_text("extras", "");
+    form.add_text("uploaded_as_anonymous", "false");
+    form.add_reader_file(
+        "torrent_file",
+        std::io::Cursor::new(bytes::Bytes::from_static(torrent_data)),
+        "torrent_file.torrent",
+    );
+
+    let pg_pool = pool.clone();
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(pool, MockRedisPool::default(), 100, 100).await;
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+
+    #[derive(Debug, Deserialize)]
+    struct Torrent {
+        info_hash: Vec<u8>,
+    }
+
+    let torrent = common::call_and_read_body_json_with_status::<Torrent, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    // the source tag makes the info hash unique to this site
+    assert_ne!(torrent.info_hash, original.info().info_hash().as_ref());
+
+    let resp = test::call_service(
+        &service,
+        cross_seed_request(&user.token, torrent_data).await,
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let cross_seedable =
+        Metainfo::from_bytes(test::read_body(resp).await).expect("returned torrent is valid");
+    let info = cross_seedable.info();
+
+    assert_eq!(info.info_hash().as_ref(), torrent.info_hash);
+    assert!(info.source().is_some());
+    assert_eq!(info.is_private(), Some(true));
+    assert!(info
+        .files()
+        .map(|f| (f.path(), f.length()))
+        .eq(original.info().files().map(|f| (f.path(), f.length()))));
+
+    let resp = test::call_service(
+        &service,
+        cross_seed_request(&user.token, include_bytes!("data/hybrid.torrent")).await,
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    // a torrent held from the tracker can't be cross seeded until it is approved
+    sqlx::query("UPDATE torrents SET held_from_tracker = TRUE")
+        .execute(&pg_pool)
+        .await
+        .unwrap();
+
+    let resp = test::call_service(
+        &service,
+        cross_seed_request(&user.token, torrent_data).await,
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_upload_torrent_with_mismatching_mediainfo(pool: PgPool) {
+    use actix_multipart_rfc7578::client::multipart;
+
+    let mut form = multipart::Form::default();
+
+    form.add_text("release_name", "test release name");
+    form.add_text("uploaded_as_anonymous", "false");
+    form.add_text(
+        "mediainfo",
+        "General\nFormat : Matroska\n\nVideo\nFormat : AVC\nWidth : 1 920 pixels\nHeight : 1 080 pixels\n",
+    );
+    form.add_text("languages", "English");
+    form.add_text("container", "MKV");
+    form.add_text("edition_group_id", "1");
+    form.add_text("video_codec", "h265");
+    form.add_text("features", "");
+    form.add_text("subtitle_languages", "");
+    form.add_text("extras", "");
+
+    let torrent_data = bytes::Bytes::from_static(include_bytes!(
+        "data/debian-12.10.0-i386-netinst.iso.torrent"
+    ));
+
+    form.add_reader_file(
+        "torrent_file",
+        std::io::Cursor::new(torrent_data),
+        "torrent_file.torrent",
+    );
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(pool, MockRedisPool::default(), 100, 100).await;
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+
+    let error = common::call_and_read_body_json_with_status::<common::ErrorResponse, _>(
+        &service,
+        req,
+        StatusCode::BAD_REQUEST,
+    )
+    .await;
+
+    assert_eq!(
+        error.error,
+        "mediainfo does not match the submitted video codec"
+    );
+}
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_upload_torrent_with_rip_logs(pool: PgPool) {
+    sqlx::query("UPDATE edition_groups SET source = 'CD' WHERE id = 1")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let form = common::torrent_upload_form(&[
+        include_bytes!("../src/services/testdata/eac.log"),
+        include_bytes!("../src/services/testdata/xld.log"),
+    ]);
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(pool, MockRedisPool::default(), 100, 100).await;
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+
+    #[derive(Debug, Deserialize)]
+    struct Torrent {
+        id: i32,
+        log_score: Option<i16>,
+    }
+
+    let torrent = common::call_and_read_body_json_with_status::<Torrent, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    assert_eq!(torrent.log_score, Some(95));
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri(&format!("/api/torrents/logs?torrent_id={}", torrent.id))
+        .to_request();
+
+    #[derive(Debug, Deserialize)]
+    struct TorrentLog {
+        ripper: String,
+        score: i16,
+        checksum: String,
+    }
+
+    let logs = common::call_and_read_body_json_with_status::<Vec<TorrentLog>, _>(
+        &service,
+        req,
+        StatusCode::OK,
+    )
+    .await;
+
+    assert_eq!(
+        logs.iter().map(|l| l.ripper.as_str()).collect::<Vec<_>>(),
+        vec!["eac", "xld"]
+    );
+    assert!(logs
+        .iter()
+        .all(|l| l.score == 95 && l.checksum == "unverified"));
+}
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_upload_torrent_with_rip_log_on_non_cd_source(pool: PgPool) {
+    // the test edition group is a vinyl
+    let form = common::torrent_upload_form(&[include_bytes!("../src/services/testdata/eac.log")]);
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(pool, MockRedisPool::default(), 100, 100).await;
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+
+    let error = common::call_and_read_body_json_with_status::<common::ErrorResponse, _>(
+        &service,
+        req,
+        StatusCode::BAD_REQUEST,
+    )
+    .await;
+
+    assert_eq!(
+        error.error,
+        "rip logs can only be attached to CD sourced edition groups"
+    );
+}
+
 #[derive(Debug, Deserialize)]
 struct TitleGroupLite {
     id: i64,
@@ -274,3 +685,129 @@ async fn test_find_torrents_no_link_or_name_provided(pool: PgPool) {
         "expected unfiltered results to include both title_group id=1 and id=2"
     );
 }
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_upload_duplicate_torrent(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(pool, MockRedisPool::default(), 100, 100).await;
+
+    #[derive(Debug, Deserialize)]
+    struct Torrent {
+        id: i32,
+    }
+
+    let mut uploaded_torrent_id = None;
+    for status in [StatusCode::CREATED, StatusCode::CONFLICT] {
+        let form = common::torrent_upload_form(&[]);
+
+        let req = common::multipart_request(
+            test::TestRequest::post().uri("/api/torrents"),
+            &user.token,
+            form,
+        )
+        .await;
+
+        match uploaded_torrent_id {
+            None => {
+                let torrent = common::call_and_read_body_json_with_status::<Torrent, _>(
+                    &service, req, status,
+                )
+                .await;
+                uploaded_torrent_id = Some(torrent.id);
+            }
+            Some(id) => {
+                let error =
+                    common::call_and_read_body_json_with_status::<common::ErrorResponse, _>(
+                        &service, req, status,
+                    )
+                    .await;
+                assert_eq!(
+                    error.error,
+                    format!("this torrent is a duplicate of torrent {id}")
+                );
+            }
+        }
+    }
+}
+
+#[sqlx::test(
+    fixtures("with_test_user", "with_test_title_group", "with_test_edition_group"),
+    migrations = "../storage/migrations"
+)]
+async fn test_upload_torrent_breaking_upload_rules(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login_as_staff(pool, MockRedisPool::default(), 100, 100).await;
+
+    // the test title group is a music single
+    for rule in [
+        serde_json::json!({
+            "content_type": "music",
+            "category": null,
+            "allowed_file_extensions": ["flac", "log", "cue"],
+            "allowed_containers": ["flac"],
+            "required_fields": ["audio_bitrate_sampling"],
+            "min_piece_length": null,
+            "max_piece_length": null,
+            "release_name_pattern": null,
+        }),
+        serde_json::json!({
+            "content_type": "music",
+            "category": "Single",
+            "allowed_file_extensions": [],
+            "allowed_containers": [],
+            "required_fields": ["audio_bitrate_sampling"],
+            "min_piece_length": null,
+            "max_piece_length": 65536,
+            "release_name_pattern": "^.+ - .+$",
+        }),
+    ] {
+        let req = common::authenticated(test::TestRequest::post(), &user.token)
+            .uri("/api/upload-rules")
+            .set_json(rule)
+            .to_request();
+        let resp = test::call_service(&service, req).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    let form = common::torrent_upload_form(&[]);
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct FieldError {
+        field: String,
+        message: String,
+    }
+
+    let error = common::call_and_read_body_json_with_status::<common::ErrorResponse, _>(
+        &service,
+        req,
+        StatusCode::BAD_REQUEST,
+    )
+    .await;
+
+    assert_eq!(error.error, "the torrent does not follow the upload rules");
+    assert_eq!(
+        error
+            .field_errors
+            .iter()
+            .map(|e| e.field.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "file_list",
+            "audio_bitrate_sampling",
+            "torrent_file",
+            "release_name"
+        ]
+    );
+}
@@ -0,0 +1,115 @@
+pub mod common;
+pub mod mocks;
+
+use std::sync::Arc;
+
+use actix_web::{http::StatusCode, test};
+use arcadia_storage::connection_pool::ConnectionPool;
+use mocks::mock_redis::MockRedisPool;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+
+#[sqlx::test(fixtures("with_test_user"), migrations = "../storage/migrations")]
+async fn test_staff_manages_torrent_clients(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+
+    let client = json!({
+        "name": "qBittorrent",
+        "rule": "allow",
+        "peer_id_prefix": "qB",
+        "min_version": "4500",
+        "max_version": "4620",
+        "user_agent_pattern": "^qBittorrent/",
+        "description": "recent versions only"
+    });
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/torrent-clients")
+        .set_json(&client)
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let (service, staff) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    let get_clients = async || {
+        let req = common::authenticated(test::TestRequest::get(), &staff.token)
+            .uri("/api/torrent-clients")
+            .to_request();
+        common::call_and_read_body_json_with_status::<Vec<Value>, _>(&service, req, StatusCode::OK)
+            .await
+    };
+    let seeded_clients_amount = get_clients().await.len();
+
+    for invalid_client in [
+        json!({ "name": "q".repeat(51) }),
+        json!({ "name": "" }),
+        json!({ "min_version": "4621" }),
+        json!({ "max_version": "46200" }),
+        json!({ "user_agent_pattern": "(" }),
+    ] {
+        let mut body = client.clone();
+        body.as_object_mut()
+            .unwrap()
+            .extend(invalid_client.as_object().unwrap().clone());
+        let req = common::authenticated(test::TestRequest::post(), &staff.token)
+            .uri("/api/torrent-clients")
+            .set_json(&body)
+            .to_request();
+        let resp = test::call_service(&service, req).await;
+        assert_eq!(
+            resp.status(),
+            StatusCode::BAD_REQUEST,
+            "{invalid_client} was accepted"
+        );
+    }
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/torrent-clients")
+        .set_json(&client)
+        .to_request();
+    let created =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::CREATED)
+            .await;
+    let client_id = created["id"].as_i64().unwrap();
+    assert_eq!(created["name"], "qBittorrent");
+    assert_eq!(created["min_version"], "4500");
+    assert_eq!(created["max_version"], "4620");
+    assert_eq!(get_clients().await.len(), seeded_clients_amount + 1);
+
+    let mut edited = client.clone();
+    edited["id"] = json!(client_id);
+    edited["rule"] = json!("deny");
+    edited["max_version"] = Value::Null;
+    let req = common::authenticated(test::TestRequest::put(), &staff.token)
+        .uri("/api/torrent-clients")
+        .set_json(&edited)
+        .to_request();
+    let updated =
+        common::call_and_read_body_json_with_status::<Value, _>(&service, req, StatusCode::OK)
+            .await;
+    assert_eq!(updated["rule"], "deny");
+    assert!(updated["max_version"].is_null());
+
+    let req = common::authenticated(test::TestRequest::delete(), &staff.token)
+        .uri(&format!("/api/torrent-clients?id={client_id}"))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(get_clients().await.len(), seeded_clients_amount);
+
+    let req = common::authenticated(test::TestRequest::delete(), &staff.token)
+        .uri(&format!("/api/torrent-clients?id={client_id}"))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
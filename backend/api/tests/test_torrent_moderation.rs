@@ -0,0 +1,758 @@
+pub mod common;
+pub mod mocks;
+
+use std::sync::Arc;
+
+use actix_web::{http::StatusCode, test};
+use arcadia_storage::{
+    connection_pool::ConnectionPool,
+    models::notification::{NotificationQuery, NotificationType},
+};
+use mocks::{mock_redis::MockRedisPool, mock_tracker::MockTracker};
+use serde::Deserialize;
+use sqlx::PgPool;
+
+fn unread_notifications(notification_type: NotificationType) -> NotificationQuery {
+    NotificationQuery {
+        include_read: false,
+        notification_type: Some(notification_type),
+        page: 1,
+        page_size: 10,
+    }
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../storage/migrations"
+)]
+async fn test_staff_trump_torrent_notifies_downloaders(pool: PgPool) {
+    // the fixture torrent is inserted with an explicit id
+    sqlx::query("SELECT setval('torrents_id_seq', 1)")
+        .execute(&pool)
+        .await
+        .unwrap();
+    // the creator of the fixture torrent downloaded it
+    sqlx::query("INSERT INTO torrent_activities (torrent_id, user_id) VALUES (1, 1)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    sqlx::query("UPDATE users SET class = 'staff' WHERE username = 'test_user'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let tracker = MockTracker::start().await;
+    let mut env = common::test_env();
+    env.tracker.url_internal = tracker.url.clone();
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let service =
+        common::create_test_app_with_env(Arc::clone(&pool), MockRedisPool::default(), env).await;
+    let user = common::login(&service, "test_user").await;
+
+    let form = common::torrent_upload_form(&[]);
+
+    let req = common::multipart_request(
+        test::TestRequest::post().uri("/api/torrents"),
+        &user.token,
+        form,
+    )
+    .await;
+
+    #[derive(Debug, Deserialize)]
+    struct Torrent {
+        id: i32,
+    }
+
+    let trumping = common::call_and_read_body_json_with_status::<Torrent, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/torrents/trump")
+        .set_json(serde_json::json!({
+            "trumped_torrent_id": 1,
+            "trumping_torrent_id": trumping.id,
+            "reason": "lossless version available",
+        }))
+        .to_request();
+
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // the trumped torrent is removed from the tracker
+    let removed = tracker
+        .upserted_torrents()
+        .into_iter()
+        .find(|torrent| torrent.id == 1)
+        .expect("the trumped torrent was not upserted in the tracker");
+    assert!(removed.is_deleted);
+
+    let notifications = pool
+        .find_notifications(1, &unread_notifications(NotificationType::TorrentTrumped))
+        .await
+        .unwrap()
+        .results;
+
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].target_id, 1);
+    assert_eq!(notifications[0].actor_id, Some(2));
+    assert_eq!(notifications[0].payload["trumping_torrent_id"], trumping.id);
+    assert_eq!(
+        notifications[0].payload["reason"],
+        "lossless version available"
+    );
+
+    // the trumped torrent is gone
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri("/api/torrents?id=1")
+        .to_request();
+
+    let resp = test::call_service(&service, req).await;
+    assert_ne!(resp.status(), StatusCode::OK);
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../storage/migrations"
+)]
+async fn test_staff_review_held_torrent(pool: PgPool) {
+    // the fixture torrent is uploaded by the creator
+    sqlx::query("UPDATE torrents SET held_from_tracker = TRUE WHERE id = 1")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    #[derive(Debug, Deserialize)]
+    struct TorrentToReview {
+        id: i32,
+        held_from_tracker: bool,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Queue {
+        results: Vec<TorrentToReview>,
+        total_items: i64,
+    }
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri("/api/torrents/review-queue?held_only=true&page=1&page_size=10")
+        .to_request();
+    let queue =
+        common::call_and_read_body_json_with_status::<Queue, _>(&service, req, StatusCode::OK)
+            .await;
+
+    assert_eq!(queue.total_items, 1);
+    assert_eq!(queue.results[0].id, 1);
+    assert!(queue.results[0].held_from_tracker);
+
+    let review = |decision: &str, reason: Option<&str>| {
+        common::authenticated(test::TestRequest::post(), &user.token)
+            .uri("/api/torrents/reviews")
+            .set_json(serde_json::json!({
+                "torrent_id": 1,
+                "decision": decision,
+                "reason": reason,
+            }))
+            .to_request()
+    };
+
+    let resp = test::call_service(&service, review("changes_requested", None)).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let resp = test::call_service(
+        &service,
+        review("changes_requested", Some("please add the log")),
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    // the followers of the title group are only notified once the held torrent is approved
+    pool.create_subscription_title_group_torrents(1, 2)
+        .await
+        .unwrap();
+    let title_group_notifications = || async {
+        pool.find_notifications(
+            2,
+            &unread_notifications(NotificationType::TitleGroupTorrent),
+        )
+        .await
+        .unwrap()
+        .results
+    };
+    assert!(title_group_notifications().await.is_empty());
+
+    let resp = test::call_service(&service, review("approved", None)).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let notifications = title_group_notifications().await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].target_id, 1);
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri("/api/torrents/review-queue?page=1&page_size=10")
+        .to_request();
+    let queue =
+        common::call_and_read_body_json_with_status::<Queue, _>(&service, req, StatusCode::OK)
+            .await;
+    assert_eq!(queue.total_items, 0);
+
+    #[derive(Debug, Deserialize)]
+    struct ReviewerStats {
+        approved: i64,
+        changes_requested: i64,
+        rejected: i64,
+    }
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri("/api/torrents/reviews/stats")
+        .to_request();
+    let stats = common::call_and_read_body_json_with_status::<Vec<ReviewerStats>, _>(
+        &service,
+        req,
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(stats.len(), 1);
+    assert_eq!(
+        (
+            stats[0].approved,
+            stats[0].changes_requested,
+            stats[0].rejected
+        ),
+        (1, 1, 0)
+    );
+
+    let notifications = pool
+        .find_notifications(1, &unread_notifications(NotificationType::TorrentReviewed))
+        .await
+        .unwrap()
+        .results;
+
+    assert_eq!(notifications.len(), 2);
+    assert!(notifications.iter().all(|n| n.target_id == 1));
+    assert!(notifications
+        .iter()
+        .any(|n| n.payload["decision"] == "changes_requested"
+            && n.payload["reason"] == "please add the log"));
+    assert!(notifications
+        .iter()
+        .any(|n| n.payload["decision"] == "approved"));
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_user2",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../storage/migrations"
+)]
+async fn test_staff_claim_and_resolve_torrent_report(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) =
+        common::create_test_app_and_login(Arc::clone(&pool), MockRedisPool::default(), 100, 100)
+            .await;
+    let staff = common::login(&service, "test_user2").await;
+
+    #[derive(Debug, Deserialize)]
+    struct TorrentReport {
+        id: i64,
+        claimed_by_id: Option<i32>,
+        outcome: Option<String>,
+    }
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/torrents/reports")
+        .set_json(serde_json::json!({
+            "reported_torrent_id": 1,
+            "description": "wrong bitrate",
+            "category": "bad_metadata",
+        }))
+        .to_request();
+    let report = common::call_and_read_body_json_with_status::<TorrentReport, _>(
+        &service,
+        req,
+        StatusCode::OK,
+    )
+    .await;
+
+    #[derive(Debug, Deserialize)]
+    struct Inbox {
+        total_items: i64,
+    }
+
+    let inbox_request = |status: &str| {
+        common::authenticated(test::TestRequest::get(), &staff.token)
+            .uri(&format!(
+                "/api/torrents/reports?status={status}&category=bad_metadata&page=1&page_size=10"
+            ))
+            .to_request()
+    };
+
+    let inbox = common::call_and_read_body_json_with_status::<Inbox, _>(
+        &service,
+        inbox_request("open"),
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(inbox.total_items, 1);
+
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/torrents/reports/claim")
+        .set_json(serde_json::json!({ "report_id": report.id }))
+        .to_request();
+    let claimed = common::call_and_read_body_json_with_status::<TorrentReport, _>(
+        &service,
+        req,
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(claimed.claimed_by_id, Some(3));
+
+    let inbox = common::call_and_read_body_json_with_status::<Inbox, _>(
+        &service,
+        inbox_request("claimed"),
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(inbox.total_items, 1);
+
+    let resolve = |outcome: &str| {
+        common::authenticated(test::TestRequest::post(), &staff.token)
+            .uri("/api/torrents/reports/resolve")
+            .set_json(serde_json::json!({
+                "report_id": report.id,
+                "outcome": outcome,
+                "comment": "deleted, thanks for the report",
+            }))
+            .to_request()
+    };
+
+    // editing requires the edited torrent
+    let resp = test::call_service(&service, resolve("edited")).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // the edit follows the upload rules
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/upload-rules")
+        .set_json(serde_json::json!({
+            "content_type": "music",
+            "category": null,
+            "allowed_file_extensions": [],
+            "allowed_containers": [],
+            "required_fields": [],
+            "min_piece_length": null,
+            "max_piece_length": null,
+            "release_name_pattern": "^.+ - .+$",
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let torrent = pool.find_torrent(1).await.unwrap();
+    let mut edited_torrent = serde_json::to_value(&torrent).unwrap();
+    edited_torrent["release_name"] = serde_json::json!("Edited.Release.Name");
+    let req = common::authenticated(test::TestRequest::post(), &staff.token)
+        .uri("/api/torrents/reports/resolve")
+        .set_json(serde_json::json!({
+            "report_id": report.id,
+            "outcome": "edited",
+            "comment": "renamed",
+            "edited_torrent": edited_torrent,
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        pool.find_torrent(1).await.unwrap().release_name,
+        torrent.release_name
+    );
+
+    let resolved = common::call_and_read_body_json_with_status::<TorrentReport, _>(
+        &service,
+        resolve("deleted"),
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(resolved.outcome.as_deref(), Some("deleted"));
+    assert!(pool.find_torrent(1).await.is_err());
+
+    // the uploader of the deleted torrent is told why, like on a staff deletion
+    let deletions = pool
+        .find_notifications(1, &unread_notifications(NotificationType::TorrentDeleted))
+        .await
+        .unwrap()
+        .results;
+    assert_eq!(deletions.len(), 1);
+    let displayed_reason = deletions[0].payload["displayed_reason"].as_str().unwrap();
+    assert!(displayed_reason.contains("Reason: deleted, thanks for the report"));
+    assert!(displayed_reason.contains("test_user2"));
+
+    let resp = test::call_service(&service, resolve("dismissed")).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri("/api/notifications?include_read=false&notification_type=torrent_report_resolved&page=1&page_size=10")
+        .to_request();
+    let notifications = common::call_and_read_body_json_with_status::<serde_json::Value, _>(
+        &service,
+        req,
+        StatusCode::OK,
+    )
+    .await;
+
+    assert_eq!(notifications["total_items"], 1);
+    let notification = &notifications["results"][0];
+    assert_eq!(notification["target_id"], report.id);
+    assert_eq!(notification["payload"]["outcome"], "deleted");
+    assert_eq!(
+        notification["payload"]["resolution_comment"],
+        "deleted, thanks for the report"
+    );
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../storage/migrations"
+)]
+async fn test_edit_torrent_breaking_upload_rules(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    // the extensions are normalized, the fixture torrent only holds a test.txt file
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/upload-rules")
+        .set_json(serde_json::json!({
+            "content_type": "music",
+            "category": null,
+            "allowed_file_extensions": [" .TXT "],
+            "allowed_containers": [],
+            "required_fields": [],
+            "min_piece_length": null,
+            "max_piece_length": null,
+            "release_name_pattern": "^.+ - .+$",
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let torrent = pool.find_torrent(1).await.unwrap();
+    let mut edited_torrent = serde_json::to_value(&torrent).unwrap();
+
+    for (release_name, status) in [
+        ("Edited.Release.Name", StatusCode::BAD_REQUEST),
+        ("The Beatles - Love Me Do", StatusCode::OK),
+    ] {
+        edited_torrent["release_name"] = serde_json::json!(release_name);
+        let req = common::authenticated(test::TestRequest::put(), &user.token)
+            .uri("/api/torrents")
+            .set_json(&edited_torrent)
+            .to_request();
+        let resp = test::call_service(&service, req).await;
+        assert_eq!(resp.status(), status, "{release_name}");
+    }
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../storage/migrations"
+)]
+async fn test_torrent_edit_history_and_revert(pool: PgPool) {
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    let torrent = pool.find_torrent(1).await.unwrap();
+    let original_release_name = torrent.release_name.clone();
+
+    let mut edited_torrent = serde_json::to_value(&torrent).unwrap();
+    edited_torrent["release_name"] = serde_json::json!("Edited.Release.Name");
+
+    let req = common::authenticated(test::TestRequest::put(), &user.token)
+        .uri("/api/torrents")
+        .set_json(&edited_torrent)
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    #[derive(Debug, Deserialize)]
+    struct Revision {
+        id: i64,
+        edited_by_id: i32,
+        diff: serde_json::Value,
+    }
+
+    let history_request = || {
+        common::authenticated(test::TestRequest::get(), &user.token)
+            .uri("/api/revisions?resource_type=torrent&resource_id=1")
+            .to_request()
+    };
+
+    let revisions = common::call_and_read_body_json_with_status::<Vec<Revision>, _>(
+        &service,
+        history_request(),
+        StatusCode::OK,
+    )
+    .await;
+
+    // the original state of the torrent, then the edit
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].edited_by_id, 2);
+    assert_eq!(
+        revisions[0].diff,
+        serde_json::json!({
+            "release_name": { "old": original_release_name, "new": "Edited.Release.Name" }
+        })
+    );
+    assert_eq!(revisions[1].edited_by_id, torrent.created_by_id);
+    assert_eq!(revisions[1].diff, serde_json::json!({}));
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/revisions/revert")
+        .set_json(serde_json::json!({ "revision_id": revisions[1].id }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let torrent = pool.find_torrent(1).await.unwrap();
+    assert_eq!(torrent.release_name, original_release_name);
+
+    let revisions = common::call_and_read_body_json_with_status::<Vec<Revision>, _>(
+        &service,
+        history_request(),
+        StatusCode::OK,
+    )
+    .await;
+    assert_eq!(revisions.len(), 3);
+
+    // reverting to the edit would break the upload rules
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/upload-rules")
+        .set_json(serde_json::json!({
+            "content_type": "music",
+            "category": null,
+            "allowed_file_extensions": [],
+            "allowed_containers": [],
+            "required_fields": [],
+            "min_piece_length": null,
+            "max_piece_length": null,
+            "release_name_pattern": "^.+ - .+$",
+        }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/revisions/revert")
+        .set_json(serde_json::json!({ "revision_id": revisions[1].id }))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let torrent = pool.find_torrent(1).await.unwrap();
+    assert_eq!(torrent.release_name, original_release_name);
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../storage/migrations"
+)]
+async fn test_staff_merge_title_groups_and_move_torrents(pool: PgPool) {
+    let pg_pool = pool.clone();
+    let pool = Arc::new(ConnectionPool::with_pg_pool(pool));
+    let (service, user) = common::create_test_app_and_login_as_staff(
+        Arc::clone(&pool),
+        MockRedisPool::default(),
+        100,
+        100,
+    )
+    .await;
+
+    // title group 3 is similar to the source, which is similar to the target
+    sqlx::raw_sql(
+        r#"
+        CREATE TEMPORARY TABLE title_group_copy AS SELECT * FROM title_groups WHERE id = 2;
+        UPDATE title_group_copy SET id = 3, external_links = '{}';
+        INSERT INTO title_groups SELECT * FROM title_group_copy;
+        INSERT INTO similar_title_groups (group_1_id, group_2_id) VALUES (3, 1), (1, 2);
+        "#,
+    )
+    .execute(&pg_pool)
+    .await
+    .unwrap();
+
+    #[derive(Debug, Deserialize)]
+    struct TitleGroup {
+        id: i32,
+        external_links: Vec<String>,
+    }
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/title-groups/merge")
+        .set_json(serde_json::json!({
+            "source_title_group_id": 1,
+            "target_title_group_id": 2,
+        }))
+        .to_request();
+    let merged =
+        common::call_and_read_body_json_with_status::<TitleGroup, _>(&service, req, StatusCode::OK)
+            .await;
+
+    assert_eq!(merged.id, 2);
+    assert!(merged
+        .external_links
+        .contains(&"https://www.discogs.com/master/1154826-The-Beatles-Love-Me-Do".to_string()));
+    assert!(pool.find_title_group(1).await.is_err());
+    assert_eq!(pool.find_edition_group(1).await.unwrap().title_group_id, 2);
+    let similar_title_groups: Vec<(i64, i64)> =
+        sqlx::query_as("SELECT group_1_id, group_2_id FROM similar_title_groups")
+            .fetch_all(&pg_pool)
+            .await
+            .unwrap();
+    assert_eq!(similar_title_groups, [(3, 2)]);
+
+    #[derive(Debug, Deserialize)]
+    struct EditionGroup {
+        id: i32,
+        title_group_id: i32,
+    }
+
+    let req = common::authenticated(test::TestRequest::post(), &user.token)
+        .uri("/api/edition-groups/split")
+        .set_json(serde_json::json!({ "edition_group_id": 1, "torrent_ids": [1] }))
+        .to_request();
+    let new_edition_group = common::call_and_read_body_json_with_status::<EditionGroup, _>(
+        &service,
+        req,
+        StatusCode::CREATED,
+    )
+    .await;
+
+    assert_eq!(new_edition_group.title_group_id, 2);
+    assert_eq!(
+        pool.find_torrent(1).await.unwrap().edition_group_id,
+        new_edition_group.id
+    );
+
+    #[derive(Debug, Deserialize)]
+    struct Torrent {
+        edition_group_id: i32,
+    }
+
+    let move_request = || {
+        common::authenticated(test::TestRequest::post(), &user.token)
+            .uri("/api/torrents/move")
+            .set_json(serde_json::json!({ "torrent_id": 1, "edition_group_id": 1 }))
+            .to_request()
+    };
+
+    // the moved torrent follows the upload rules of the target edition group
+    sqlx::query(
+        "INSERT INTO upload_rules (content_type, release_name_pattern)
+         SELECT content_type, '^Not the release name$' FROM title_groups WHERE id = 2",
+    )
+    .execute(&pg_pool)
+    .await
+    .unwrap();
+    let resp = test::call_service(&service, move_request()).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        pool.find_torrent(1).await.unwrap().edition_group_id,
+        new_edition_group.id
+    );
+
+    sqlx::query("DELETE FROM upload_rules")
+        .execute(&pg_pool)
+        .await
+        .unwrap();
+    let req = move_request();
+    let moved =
+        common::call_and_read_body_json_with_status::<Torrent, _>(&service, req, StatusCode::OK)
+            .await;
+    assert_eq!(moved.edition_group_id, 1);
+
+    #[derive(Debug, Deserialize)]
+    struct SiteLog {
+        action: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct SiteLogs {
+        results: Vec<SiteLog>,
+        total_items: i64,
+    }
+
+    let req = common::authenticated(test::TestRequest::get(), &user.token)
+        .uri("/api/site-logs?page=1&page_size=10")
+        .to_request();
+    let site_logs =
+        common::call_and_read_body_json_with_status::<SiteLogs, _>(&service, req, StatusCode::OK)
+            .await;
+
+    assert_eq!(site_logs.total_items, 3);
+    let actions: Vec<&str> = site_logs
+        .results
+        .iter()
+        .map(|log| log.action.as_str())
+        .collect();
+    assert_eq!(
+        actions,
+        [
+            "torrent_moved",
+            "edition_group_split",
+            "title_groups_merged"
+        ]
+    );
+}
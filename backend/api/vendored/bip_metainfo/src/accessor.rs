@@ -25,6 +25,14 @@ pub trait Accessor {
     /// Access the sequential pieces that make up all of the files.
     fn access_pieces<C>(&self, callback: C) -> io::Result<()>
         where C: for<'a> FnMut(PieceAccess<'a>) -> io::Result<()>;
+
+    /// Access an already built info dictionary that should be reused as is.
+    ///
+    /// Used for v2 and hybrid torrents, whose file tree cannot be rebuilt
+    /// from the metadata alone.
+    fn access_raw_info(&self) -> Option<&[u8]> {
+        None
+    }
 }
 
 impl<'a, T> Accessor for &'a T
@@ -45,6 +53,10 @@ impl<'a, T> Accessor for &'a T
     {
         Accessor::access_pieces(*self, callback)
     }
+
+    fn access_raw_info(&self) -> Option<&[u8]> {
+        Accessor::access_raw_info(*self)
+    }
 }
 
 // ----------------------------------------------------------------------------//
@@ -1,10 +1,12 @@
+use std::borrow::Cow;
 use std::iter::ExactSizeIterator;
 
-use bip_bencode::{BencodeMut, BMutAccess, BRefAccess};
+use bip_bencode::{BencodeMut, BencodeRef, BencodeRefKind, BDecodeOpt, BMutAccess, BRefAccess};
+use bip_bencode::ext::BRefAccessExt;
 use bip_util::sha::{self, ShaHash};
 
 use accessor::{Accessor, IntoAccessor};
-use error::ParseResult;
+use error::{ParseError, ParseErrorKind, ParseResult};
 use parse;
 
 mod buffer;
@@ -159,6 +161,26 @@ impl<'a> MetainfoBuilder<'a> {
         self
     }
 
+    /// Set or unset the bencoded piece layers of a v2 or hybrid torrent file.
+    ///
+    /// Piece layers that are not valid bencode are left unset.
+    pub fn set_piece_layers(mut self, opt_piece_layers: Option<&'a [u8]>) -> MetainfoBuilder<'a> {
+        {
+            let dict_access = self.root.dict_mut().unwrap();
+
+            match opt_piece_layers.and_then(|bytes| BencodeRef::decode(bytes, BDecodeOpt::default()).ok()) {
+                Some(piece_layers) => {
+                    dict_access.insert(parse::PIECE_LAYERS_KEY.into(), to_bencode_mut(&piece_layers));
+                }
+                None => {
+                    dict_access.remove(parse::PIECE_LAYERS_KEY);
+                }
+            }
+        }
+
+        self
+    }
+
     /// Set or unset the private flag for the torrent file.
     pub fn set_private_flag(mut self, opt_is_private: Option<bool>) -> MetainfoBuilder<'a> {
         self.info = self.info.set_private_flag(opt_is_private);
@@ -166,6 +188,13 @@ impl<'a> MetainfoBuilder<'a> {
         self
     }
 
+    /// Set or unset the source tag of the torrent file.
+    pub fn set_source(mut self, opt_source: Option<&'a str>) -> MetainfoBuilder<'a> {
+        self.info = self.info.set_source(opt_source);
+
+        self
+    }
+
     /// Sets the piece length for the torrent file.
     pub fn set_piece_length(mut self, piece_length: PieceLength) -> MetainfoBuilder<'a> {
         self.info = self.info.set_piece_length(piece_length);
@@ -251,6 +280,23 @@ impl<'a> InfoBuilder<'a> {
         self
     }
 
+    /// Set or unset the source tag for the torrent file.
+    ///
+    /// The source is part of the info dictionary, so setting it changes the info hash.
+    pub fn set_source(mut self, opt_source: Option<&'a str>) -> InfoBuilder<'a> {
+        {
+            let dict_access = self.info.dict_mut().unwrap();
+
+            if let Some(source) = opt_source {
+                dict_access.insert(parse::SOURCE_KEY.into(), ben_bytes!(source));
+            } else {
+                dict_access.remove(parse::SOURCE_KEY);
+            }
+        }
+
+        self
+    }
+
     /// Sets the piece length for the torrent file.
     pub fn set_piece_length(mut self, piece_length: PieceLength) -> InfoBuilder<'a> {
         self.piece_length = piece_length;
@@ -285,6 +331,10 @@ fn build_with_accessor<'a, A, C>(threads:       usize,
             panic!("bip_metainfo: Cannot Build Metainfo File With threads == 0");
         }
 
+        if let Some(raw_info) = accessor.access_raw_info() {
+            return build_with_raw_info(raw_info, opt_root, info);
+        }
+
         // Collect all of the file information into a list
         let mut files_info = Vec::new();
         try!(accessor.access_metadata(|len, path| {
@@ -400,6 +450,83 @@ fn build_with_accessor<'a, A, C>(threads:       usize,
         }
 }
 
+/// Build the metainfo file around an existing info dictionary.
+///
+/// Only the private flag and the source are taken from the builder, everything else (including the
+/// piece length and the v2 file tree) is kept as found in the given dictionary.
+fn build_with_raw_info<'a>(raw_info: &'a [u8],
+                           opt_root: Option<BencodeMut<'a>>,
+                           mut info: BencodeMut<'a>) -> ParseResult<Vec<u8>> {
+    let raw_bencode = try!(BencodeRef::decode(raw_info, BDecodeOpt::default()));
+    let mut new_info = to_bencode_mut(&raw_bencode);
+
+    {
+        let new_info_access = try!(new_info.dict_mut().ok_or_else(|| {
+            ParseError::from_kind(ParseErrorKind::MissingData { details: "Info Is Not A Dictionary".to_owned() })
+        }));
+
+        match info.dict_mut().unwrap().remove(parse::PRIVATE_KEY) {
+            Some(private) => {
+                new_info_access.insert(parse::PRIVATE_KEY.into(), private);
+            }
+            None => {
+                new_info_access.remove(parse::PRIVATE_KEY);
+            }
+        }
+
+        match info.dict_mut().unwrap().remove(parse::SOURCE_KEY) {
+            Some(source) => {
+                new_info_access.insert(parse::SOURCE_KEY.into(), source);
+            }
+            None => {
+                new_info_access.remove(parse::SOURCE_KEY);
+            }
+        }
+    }
+
+    if let Some(mut root) = opt_root {
+        root.dict_mut().unwrap().insert(parse::INFO_KEY.into(), new_info);
+
+        Ok(root.encode())
+    } else {
+        Ok(new_info.encode())
+    }
+}
+
+/// Convert decoded bencode into its mutable counterpart, borrowing the same data.
+fn to_bencode_mut<'a>(bencode: &BencodeRef<'a>) -> BencodeMut<'a> {
+    match bencode.kind() {
+        BencodeRefKind::Int(value) => BencodeMut::new_int(value),
+        BencodeRefKind::Bytes(_) => BencodeMut::new_bytes(Cow::Borrowed(bencode.bytes_ext().unwrap())),
+        BencodeRefKind::List(list) => {
+            let mut bencode_list = BencodeMut::new_list();
+
+            {
+                let list_access = bencode_list.list_mut().unwrap();
+
+                for item in list {
+                    list_access.push(to_bencode_mut(item));
+                }
+            }
+
+            bencode_list
+        }
+        BencodeRefKind::Dict(dict) => {
+            let mut bencode_dict = BencodeMut::new_dict();
+
+            {
+                let dict_access = bencode_dict.dict_mut().unwrap();
+
+                for (key, value) in dict.to_list() {
+                    dict_access.insert(Cow::Borrowed(*key), to_bencode_mut(value));
+                }
+            }
+
+            bencode_dict
+        }
+    }
+}
+
 /// Calculate the final piece length given the total file size and piece length strategy.
 ///
 /// Lower piece length will result in a bigger file but better transfer reliability and vice versa.
@@ -64,6 +64,7 @@ extern crate bip_bencode;
 extern crate bip_util;
 extern crate crossbeam;
 extern crate walkdir;
+extern crate sha2;
 #[macro_use]
 extern crate error_chain;
 
@@ -82,4 +83,4 @@ pub use bip_util::bt::InfoHash;
 
 pub use accessor::{Accessor, IntoAccessor, DirectAccessor, FileAccessor, PieceAccess};
 pub use builder::{MetainfoBuilder, PieceLength, InfoBuilder};
-pub use metainfo::{Info, Metainfo, File};
+pub use metainfo::{Info, Metainfo, File, V2_INFO_HASH_LEN};
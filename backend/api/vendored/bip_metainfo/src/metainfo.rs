@@ -5,6 +5,7 @@ use std::io;
 use bip_bencode::{BencodeRef, BDictAccess, BDecodeOpt, BRefAccess};
 use bip_util::bt::InfoHash;
 use bip_util::sha::{self, ShaHash};
+use sha2::{Digest, Sha256};
 
 use accessor::{Accessor, PieceAccess, IntoAccessor};
 use builder::{MetainfoBuilder, InfoBuilder, PieceLength};
@@ -21,6 +22,8 @@ pub struct Metainfo {
     encoding: Option<String>,
     created_by: Option<String>,
     creation_date: Option<i64>,
+    // Raw bencoded dictionary, present only for v2 and hybrid torrents.
+    piece_layers: Option<Vec<u8>>,
     info: Info,
 }
 
@@ -64,6 +67,11 @@ impl Metainfo {
         self.creation_date
     }
 
+    /// Bencoded `piece layers` dictionary of a v2 or hybrid metainfo file.
+    pub fn piece_layers(&self) -> Option<&[u8]> {
+        self.piece_layers.as_ref().map(|p| &p[..])
+    }
+
     /// Info dictionary for the metainfo file.
     pub fn info(&self) -> &Info {
         &self.info
@@ -78,8 +86,10 @@ impl Metainfo {
             .set_comment(self.comment())
             .set_created_by(self.created_by())
             .set_private_flag(self.info().is_private())
+            .set_source(self.info().source())
             // TODO: Revisit this cast...
             .set_piece_length(PieceLength::Custom(self.info().piece_length() as usize))
+            .set_piece_layers(self.piece_layers())
             .build(1, &self.info, |_| ())
             .unwrap()
     }
@@ -94,6 +104,7 @@ impl From<Info> for Metainfo {
             encoding: None,
             created_by: None,
             creation_date: None,
+            piece_layers: None,
             info: info
         }
     }
@@ -116,6 +127,7 @@ fn parse_meta_bytes(bytes: &[u8]) -> ParseResult<Metainfo> {
     let opt_encoding = parse::parse_encoding(root_dict).map(|e| e.to_owned());
     let opt_created_by = parse::parse_created_by(root_dict).map(|e| e.to_owned());
     let opt_creation_date = parse::parse_creation_date(root_dict);
+    let opt_piece_layers = parse::parse_piece_layers(root_dict).map(|p| p.buffer().to_vec());
 
     let info_bencode = try!(parse::parse_info_bencode(root_dict));
     let info = try!(parse_info_dictionary(info_bencode));
@@ -127,6 +139,7 @@ fn parse_meta_bytes(bytes: &[u8]) -> ParseResult<Metainfo> {
         encoding: opt_encoding,
         created_by: opt_created_by,
         creation_date: opt_creation_date,
+        piece_layers: opt_piece_layers,
         info: info
     })
 }
@@ -141,10 +154,23 @@ pub struct Info {
     pieces:         Vec<[u8; sha::SHA_HASH_LEN]>,
     piece_len:      u64,
     is_private:     Option<bool>,
+    source:         Option<String>,
     // Present only for multi file torrents.
     file_directory: Option<PathBuf>,
+    meta_version:   Option<i64>,
+    // Present only for v2 and hybrid torrents.
+    info_hash_v2:   Option<[u8; V2_INFO_HASH_LEN]>,
+    // Present only for v2 and hybrid torrents, as their file tree
+    // cannot be rebuilt from the list of files.
+    raw_info:       Option<Vec<u8>>,
 }
 
+/// Length of the SHA-256 info hash of v2 torrents.
+pub const V2_INFO_HASH_LEN: usize = 32;
+
+/// Meta version of the info dictionary of v2 and hybrid torrents.
+const META_VERSION_V2: i64 = 2;
+
 impl Info {
     /// Read an `Info` from info dictionary bytes.
     pub fn from_bytes<B>(bytes: B) -> ParseResult<Info>
@@ -160,6 +186,34 @@ impl Info {
         self.info_hash
     }
 
+    /// SHA-256 hash identifying the v2 side of a v2 or hybrid torrent.
+    ///
+    /// Trackers and peers using 20 byte hashes (including announces) refer to
+    /// this hash truncated to its first 20 bytes.
+    pub fn info_hash_v2(&self) -> Option<[u8; V2_INFO_HASH_LEN]> {
+        self.info_hash_v2
+    }
+
+    /// Meta version of the info dictionary, 2 for v2 and hybrid torrents.
+    pub fn meta_version(&self) -> Option<i64> {
+        self.meta_version
+    }
+
+    /// Whether or not the torrent carries v1 metadata (v1 or hybrid torrent).
+    pub fn is_v1(&self) -> bool {
+        self.meta_version != Some(META_VERSION_V2) || !self.pieces.is_empty()
+    }
+
+    /// Whether or not the torrent carries v2 metadata (v2 or hybrid torrent).
+    pub fn is_v2(&self) -> bool {
+        self.meta_version == Some(META_VERSION_V2)
+    }
+
+    /// Whether or not the torrent carries both v1 and v2 metadata.
+    pub fn is_hybrid(&self) -> bool {
+        self.is_v1() && self.is_v2()
+    }
+
     /// Some file directory if this is a multi-file torrent, otherwise None.
     ///
     /// If you want to check to see if this is a multi-file torrent, you should
@@ -179,6 +233,11 @@ impl Info {
         self.is_private
     }
 
+    /// Source tag of the torrent, usually identifying the site it was made for.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_ref().map(|s| &s[..])
+    }
+
     /// Iterator over each of the pieces SHA-1 hash.
     ///
     /// Ordering of pieces yielded in the iterator is guaranteed to be the order in
@@ -202,6 +261,7 @@ impl Info {
         // Since there are no file system accesses here, should be fine to unwrap
         InfoBuilder::new()
             .set_private_flag(self.is_private())
+            .set_source(self.source())
             // TODO: Revisit this cast...
             .set_piece_length(PieceLength::Custom(self.piece_length() as usize))
             .build(1, self, |_| ())
@@ -247,6 +307,10 @@ impl Accessor for Info {
         
         Ok(())
     }
+
+    fn access_raw_info(&self) -> Option<&[u8]> {
+        self.raw_info.as_ref().map(|r| &r[..])
+    }
 }
 
 /// Parses the given info dictionary bytes and builds a Metainfo from them.
@@ -263,6 +327,43 @@ fn parse_info_dictionary<'a>(info_bencode: &BencodeRef<'a>) -> ParseResult<Info>
     let info_dict = try!(parse::parse_root_dict(info_bencode));
     let piece_len = try!(parse::parse_piece_length(info_dict));
     let is_private = parse::parse_private(info_dict);
+    let source = parse::parse_source(info_dict).map(|s| s.to_owned());
+    let meta_version = parse::parse_meta_version(info_dict);
+
+    let (info_hash_v2, raw_info) = if meta_version == Some(META_VERSION_V2) {
+        let mut hash = [0u8; V2_INFO_HASH_LEN];
+        hash.copy_from_slice(&Sha256::digest(info_bencode.buffer()));
+
+        (Some(hash), Some(info_bencode.buffer().to_vec()))
+    } else {
+        (None, None)
+    };
+
+    // Pure v2 torrents only carry piece hashes in the file tree and piece layers
+    if meta_version == Some(META_VERSION_V2) && parse::parse_pieces(info_dict).is_err() {
+        let name = try!(parse::parse_name(info_dict));
+        let file_tree = try!(parse::parse_file_tree(info_dict));
+
+        let mut files_list = Vec::new();
+        try!(collect_file_tree(file_tree, PathBuf::new(), &mut files_list));
+
+        // A lone file named after the torrent is how v2 describes single file torrents
+        let is_single_file = files_list.len() == 1 && files_list[0].path == Path::new(name);
+        let file_directory = if is_single_file { None } else { Some(PathBuf::from(name)) };
+
+        return Ok(Info {
+            info_hash: info_hash,
+            files: files_list,
+            pieces: Vec::new(),
+            piece_len: piece_len,
+            is_private: is_private,
+            source: source,
+            file_directory: file_directory,
+            meta_version: meta_version,
+            info_hash_v2: info_hash_v2,
+            raw_info: raw_info,
+        });
+    }
 
     let pieces = try!(parse::parse_pieces(info_dict));
     let piece_buffers = try!(allocate_pieces(pieces));
@@ -288,7 +389,11 @@ fn parse_info_dictionary<'a>(info_bencode: &BencodeRef<'a>) -> ParseResult<Info>
             pieces: piece_buffers,
             piece_len: piece_len,
             is_private: is_private,
+            source: source,
             file_directory: Some(file_directory_path),
+            meta_version: meta_version,
+            info_hash_v2: info_hash_v2,
+            raw_info: raw_info,
         })
     } else {
         let file = try!(File::as_single_file(info_dict));
@@ -299,11 +404,47 @@ fn parse_info_dictionary<'a>(info_bencode: &BencodeRef<'a>) -> ParseResult<Info>
             pieces: piece_buffers,
             piece_len: piece_len,
             is_private: is_private,
+            source: source,
             file_directory: None,
+            meta_version: meta_version,
+            info_hash_v2: info_hash_v2,
+            raw_info: raw_info,
         })
     }
 }
 
+/// Walks a v2 file tree, collecting every file found under the given path.
+///
+/// Files are the nodes holding an empty key, which maps to their length and pieces root.
+fn collect_file_tree<'a>(tree: &BDictAccess<&'a [u8], BencodeRef<'a>>,
+                         path: PathBuf,
+                         files: &mut Vec<File>) -> ParseResult<()> {
+    for (key, node) in tree.to_list() {
+        let node_dict = try!(parse::parse_file_tree_node(node));
+
+        if key.is_empty() {
+            let length = try!(parse::parse_length(node_dict));
+            let attr = parse::parse_attr(node_dict).map(|a| a.to_owned());
+
+            files.push(File {
+                len: length,
+                path: path.clone(),
+                md5sum: None,
+                attr: attr,
+            });
+        } else {
+            let name = try!(::std::str::from_utf8(key).map_err(|_| {
+                let error_msg = format!("File Tree Entry Of {:?} Is Not Valid UTF-8", key);
+                ParseError::from_kind(ParseErrorKind::MissingData { details: error_msg })
+            }));
+
+            try!(collect_file_tree(node_dict, path.join(name), files));
+        }
+    }
+
+    Ok(())
+}
+
 /// Returns whether or not this is a multi file torrent.
 fn is_multi_file_torrent<B>(info_dict: &BDictAccess<B::BKey, B>) -> bool
     where B: BRefAccess {
@@ -339,6 +480,7 @@ pub struct File {
     len:    u64,
     path:   PathBuf,
     md5sum: Option<Vec<u8>>,
+    attr:   Option<String>,
 }
 
 impl File {
@@ -347,12 +489,14 @@ impl File {
         where B: BRefAccess {
         let length = try!(parse::parse_length(info_dict));
         let md5sum = parse::parse_md5sum(info_dict).map(|m| m.to_owned());
+        let attr = parse::parse_attr(info_dict).map(|a| a.to_owned());
         let name = try!(parse::parse_name(info_dict));
 
         Ok(File {
             len: length,
             path: name.to_owned().into(),
             md5sum: md5sum,
+            attr: attr,
         })
     }
 
@@ -361,6 +505,7 @@ impl File {
         where B: BRefAccess<BType=B> {
         let length = try!(parse::parse_length(file_dict));
         let md5sum = parse::parse_md5sum(file_dict).map(|m| m.to_owned());
+        let attr = parse::parse_attr(file_dict).map(|a| a.to_owned());
 
         let path_list_bencode = try!(parse::parse_path_list(file_dict));
 
@@ -375,6 +520,7 @@ impl File {
             len: length,
             path: path_buf,
             md5sum: md5sum,
+            attr: attr,
         })
     }
 
@@ -394,6 +540,19 @@ impl File {
     pub fn path(&self) -> &Path {
         &self.path
     }
+
+    /// Optional BEP 47 attributes of the file, one character per attribute.
+    pub fn attr(&self) -> Option<&str> {
+        self.attr.as_ref().map(|a| &a[..])
+    }
+
+    /// Whether or not this is a BEP 47 padding file.
+    ///
+    /// Padding files only align the next file on a piece boundary, they are not
+    /// part of the content but still count when reconstructing pieces.
+    pub fn is_padding(&self) -> bool {
+        self.attr().map_or(false, |a| a.contains('p'))
+    }
 }
 
 #[cfg(test)]
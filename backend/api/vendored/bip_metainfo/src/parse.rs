@@ -28,17 +28,22 @@ pub const COMMENT_KEY:       &'static [u8] = b"comment";
 pub const CREATED_BY_KEY:    &'static [u8] = b"created by";
 pub const ENCODING_KEY:      &'static [u8] = b"encoding";
 pub const INFO_KEY:          &'static [u8] = b"info";
+pub const PIECE_LAYERS_KEY:  &'static [u8] = b"piece layers";
 
 /// Keys found within the info dictionary of a metainfo file.
 pub const PIECE_LENGTH_KEY: &'static [u8] = b"piece length";
 pub const PIECES_KEY:       &'static [u8] = b"pieces";
 pub const PRIVATE_KEY:      &'static [u8] = b"private";
+pub const SOURCE_KEY:       &'static [u8] = b"source";
 pub const NAME_KEY:         &'static [u8] = b"name";
 pub const FILES_KEY:        &'static [u8] = b"files";
+pub const META_VERSION_KEY: &'static [u8] = b"meta version";
+pub const FILE_TREE_KEY:    &'static [u8] = b"file tree";
 
 /// Keys found within the files dictionary of a metainfo file.
 pub const LENGTH_KEY: &'static [u8] = b"length";
 pub const MD5SUM_KEY: &'static [u8] = b"md5sum";
+pub const ATTR_KEY:   &'static [u8] = b"attr";
 pub const PATH_KEY:   &'static [u8] = b"path";
 
 /// Parses the root bencode as a dictionary.
@@ -97,6 +102,12 @@ pub fn parse_encoding<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Option<&
     CONVERT.lookup_and_convert_str(root_dict, ENCODING_KEY).ok()
 }
 
+/// Parses the v2 piece layers from the root dictionary.
+pub fn parse_piece_layers<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a B>
+    where B: BRefAccess {
+    CONVERT.lookup(root_dict, PIECE_LAYERS_KEY).ok()
+}
+
 /// Parses the info dictionary from the root dictionary.
 pub fn parse_info_bencode<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> ParseResult<&B>
     where B: BRefAccess {
@@ -123,12 +134,30 @@ pub fn parse_private<B>(info_dict: &BDictAccess<B::BKey, B>) -> Option<bool>
     CONVERT.lookup_and_convert_int(info_dict, PRIVATE_KEY).ok().map(|p| p == 1)
 }
 
+/// Parses the source tag from the info dictionary.
+pub fn parse_source<'a, B>(info_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a str>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_str(info_dict, SOURCE_KEY).ok()
+}
+
 /// Parses the name from the info dictionary.
 pub fn parse_name<'a, B>(info_dict: &'a BDictAccess<B::BKey, B>) -> ParseResult<&'a str>
     where B: BRefAccess + 'a {
     CONVERT.lookup_and_convert_str(info_dict, NAME_KEY)
 }
 
+/// Parses the meta version from the info dictionary.
+pub fn parse_meta_version<B>(info_dict: &BDictAccess<B::BKey, B>) -> Option<i64>
+    where B: BRefAccess {
+    CONVERT.lookup_and_convert_int(info_dict, META_VERSION_KEY).ok()
+}
+
+/// Parses the v2 file tree from the info dictionary.
+pub fn parse_file_tree<B>(info_dict: &BDictAccess<B::BKey, B>) -> ParseResult<&BDictAccess<B::BKey, B::BType>>
+    where B: BRefAccess {
+    CONVERT.lookup_and_convert_dict(info_dict, FILE_TREE_KEY)
+}
+
 /// Parses the files list from the info dictionary.
 pub fn parse_files_list<B>(info_dict: &BDictAccess<B::BKey, B>) -> ParseResult<&BListAccess<B>>
     where B: BRefAccess<BType=B> {
@@ -143,6 +172,12 @@ pub fn parse_file_dict<B>(file_bencode: &B) -> ParseResult<&BDictAccess<B::BKey,
     CONVERT.convert_dict(file_bencode, FILES_KEY)
 }
 
+/// Parses a directory or file node from the v2 file tree.
+pub fn parse_file_tree_node<B>(node_bencode: &B) -> ParseResult<&BDictAccess<B::BKey, B::BType>>
+    where B: BRefAccess {
+    CONVERT.convert_dict(node_bencode, FILE_TREE_KEY)
+}
+
 /// Parses the length from the info or file dictionary.
 pub fn parse_length<B>(info_or_file_dict: &BDictAccess<B::BKey, B>) -> ParseResult<u64>
     where B: BRefAccess {
@@ -155,6 +190,12 @@ pub fn parse_md5sum<'a, B>(info_or_file_dict: &'a BDictAccess<B::BKey, B>) -> Op
     CONVERT.lookup_and_convert_bytes(info_or_file_dict, MD5SUM_KEY).ok()
 }
 
+/// Parses the BEP 47 attributes from the info or file dictionary.
+pub fn parse_attr<'a, B>(info_or_file_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a str>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_str(info_or_file_dict, ATTR_KEY).ok()
+}
+
 /// Parses the path list from the file dictionary.
 pub fn parse_path_list<B>(file_dict: &BDictAccess<B::BKey, B>) -> ParseResult<&BListAccess<B>>
     where B: BRefAccess<BType=B> {
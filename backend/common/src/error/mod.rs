@@ -1,3 +1,10 @@
+// a form field that failed validation, returned alongside the error message
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("database error: {0}")]
@@ -9,6 +16,12 @@ pub enum Error {
     #[error("account banned")]
     AccountBanned,
 
+    #[error("ip address banned")]
+    IpBanned,
+
+    #[error("could not determine the client ip address")]
+    CouldNotDetermineClientIp,
+
     #[error("could not create user application")]
     CouldNotCreateUserApplication(#[source] sqlx::Error),
 
@@ -21,6 +34,12 @@ pub enum Error {
     #[error("could not create artist")]
     CouldNotCreateArtist(#[source] sqlx::Error),
 
+    #[error("artist with id '{0}' not found")]
+    ArtistNotFound(i64),
+
+    #[error("error while updating artist: '{0}'")]
+    ErrorWhileUpdatingArtist(String),
+
     #[error("could not create artist affiliation")]
     CouldNotCreateArtistAffiliation(#[source] sqlx::Error),
 
@@ -39,6 +58,12 @@ pub enum Error {
     #[error("could not create edition group")]
     CouldNotCreateEditionGroup(#[source] sqlx::Error),
 
+    #[error("edition group with id '{0}' not found")]
+    EditionGroupNotFound(i32),
+
+    #[error("error while updating edition_group: '{0}'")]
+    ErrorWhileUpdatingEditionGroup(String),
+
     #[error("could not create invitation")]
     CouldNotCreateInvitation(#[source] sqlx::Error),
 
@@ -87,6 +112,18 @@ pub enum Error {
     #[error("could not create torrent report")]
     CouldNotCreateTorrentReport(#[source] sqlx::Error),
 
+    #[error("could not update torrent report")]
+    CouldNotUpdateTorrentReport(#[source] sqlx::Error),
+
+    #[error("torrent report with id '{0}' not found")]
+    TorrentReportNotFound(i64),
+
+    #[error("torrent report already resolved")]
+    TorrentReportAlreadyResolved,
+
+    #[error("torrent report claimed by another user")]
+    TorrentReportClaimedByAnotherUser,
+
     #[error("could not create series")]
     CouldNotCreateSeries(#[source] sqlx::Error),
 
@@ -96,6 +133,33 @@ pub enum Error {
     #[error("series with id '{0}' not found")]
     SeriesWithIdNotFound(i64),
 
+    #[error("could not create saved torrent search")]
+    CouldNotCreateSavedTorrentSearch(#[source] sqlx::Error),
+
+    #[error("saved torrent search with id '{0}' not found")]
+    SavedTorrentSearchNotFound(i64),
+
+    #[error("could not create notification filter")]
+    CouldNotCreateNotificationFilter(#[source] sqlx::Error),
+
+    #[error("notification filter with id '{0}' not found")]
+    NotificationFilterNotFound(i64),
+
+    #[error("your class can't have more than {0} notification filters")]
+    NotificationFilterLimitReached(i64),
+
+    #[error("error while updating series: '{0}'")]
+    ErrorWhileUpdatingSeries(String),
+
+    #[error("could not create revision")]
+    CouldNotCreateRevision(#[source] sqlx::Error),
+
+    #[error("revision with id '{0}' not found")]
+    RevisionNotFound(i64),
+
+    #[error("invalid revision snapshot: '{0}'")]
+    InvalidRevisionSnapshot(String),
+
     #[error("invalid invitation key")]
     InvitationKeyInvalid,
 
@@ -126,6 +190,9 @@ pub enum Error {
     #[error("invalid API key or banned")]
     InvalidAPIKeyOrBanned,
 
+    #[error("invalid passkey or banned")]
+    InvalidPasskeyOrBanned,
+
     #[error("invalid or expired refresh token")]
     InvalidOrExpiredRefreshToken,
 
@@ -135,6 +202,9 @@ pub enum Error {
     #[error("JWT error")]
     JwtError(#[source] jsonwebtoken::errors::Error),
 
+    #[error("notification with id '{0}' not found")]
+    NotificationNotFound(i64),
+
     #[error("unsupported notification reason")]
     UnsupportedNotification,
 
@@ -150,6 +220,42 @@ pub enum Error {
     #[error("torrent file invalid")]
     TorrentFileInvalid,
 
+    #[error("mediainfo does not match the submitted {0}")]
+    MediainfoMismatch(String),
+
+    #[error("rip logs can only be attached to CD sourced edition groups")]
+    LogsOnlyAllowedForCdSource,
+
+    #[error("the torrent does not follow the upload rules")]
+    UploadRulesViolated(Vec<FieldError>),
+
+    #[error("could not create upload rule")]
+    CouldNotCreateUploadRule(#[source] sqlx::Error),
+
+    #[error("could not delete upload rule")]
+    CouldNotDeleteUploadRule(#[source] sqlx::Error),
+
+    #[error("upload rule with id '{0}' not found")]
+    UploadRuleNotFound(i32),
+
+    #[error("this torrent is a duplicate of torrent {0}")]
+    DuplicateTorrent(i32),
+
+    #[error("no torrent with the same files to cross-seed")]
+    CrossSeedableTorrentNotFound,
+
+    #[error("a torrent can only be trumped by another active torrent of the same title group")]
+    InvalidTorrentTrump,
+
+    #[error("could not create torrent review")]
+    CouldNotCreateTorrentReview(#[source] sqlx::Error),
+
+    #[error("could not trump torrent")]
+    CouldNotTrumpTorrent(#[source] sqlx::Error),
+
+    #[error("could not update trump rule")]
+    CouldNotUpdateTrumpRule(#[source] sqlx::Error),
+
     #[error("dottorrent file not found")]
     DottorrentFileNotFound,
 
@@ -210,6 +316,39 @@ pub enum Error {
     #[error("could not find first posts in threads of forum sub category")]
     CouldNotFindForumThreadsFirstPost(#[source] sqlx::Error),
 
+    #[error("forum post with id '{0}' not found")]
+    ForumPostNotFound(i64),
+
+    #[error("forum thread with id '{0}' not found")]
+    ForumThreadNotFound(i64),
+
+    #[error("this forum thread is locked")]
+    ForumThreadLocked,
+
+    #[error("forum category with id '{0}' not found")]
+    ForumCategoryNotFound(i32),
+
+    #[error("forum sub-category with id '{0}' not found")]
+    ForumSubCategoryNotFound(i32),
+
+    #[error("this forum category still has sub-categories")]
+    ForumCategoryNotEmpty,
+
+    #[error("this forum sub-category still has threads")]
+    ForumSubCategoryNotEmpty,
+
+    #[error("forum poll with id '{0}' not found")]
+    ForumPollNotFound(i64),
+
+    #[error("this forum poll is closed")]
+    ForumPollClosed,
+
+    #[error("you already voted in this forum poll")]
+    ForumPollAlreadyVoted,
+
+    #[error("both forum threads have a poll")]
+    ForumThreadsBothHavePolls,
+
     #[error("could not search forum threads")]
     CouldNotSearchForumThreads(#[source] sqlx::Error),
 
@@ -228,6 +367,48 @@ pub enum Error {
     #[error("could not find wiki article")]
     CouldNotFindWikiArticle(#[source] sqlx::Error),
 
+    #[error("could not create torrent client")]
+    CouldNotCreateTorrentClient(#[source] sqlx::Error),
+
+    #[error("could not find torrent clients")]
+    CouldNotFindTorrentClients(#[source] sqlx::Error),
+
+    #[error("could not update torrent client")]
+    CouldNotUpdateTorrentClient(#[source] sqlx::Error),
+
+    #[error("could not delete torrent client")]
+    CouldNotDeleteTorrentClient(#[source] sqlx::Error),
+
+    #[error("could not find seen torrent clients")]
+    CouldNotFindSeenTorrentClients(#[source] sqlx::Error),
+
+    #[error("torrent client with id '{0}' not found")]
+    TorrentClientNotFound(i32),
+
+    #[error("could not create ip ban")]
+    CouldNotCreateIpBan(#[source] sqlx::Error),
+
+    #[error("could not find ip bans")]
+    CouldNotFindIpBans(#[source] sqlx::Error),
+
+    #[error("could not delete ip ban")]
+    CouldNotDeleteIpBan(#[source] sqlx::Error),
+
+    #[error("ip ban with id '{0}' not found")]
+    IpBanNotFound(i32),
+
+    #[error("this ip range is already banned")]
+    IpBanAlreadyExists,
+
+    #[error("could not find tracker flags")]
+    CouldNotFindTrackerFlags(#[source] sqlx::Error),
+
+    #[error("could not resolve tracker flag")]
+    CouldNotResolveTrackerFlag(#[source] sqlx::Error),
+
+    #[error("tracker flag with id '{0}' not found")]
+    TrackerFlagNotFound(i64),
+
     #[error("could not create bookmark")]
     CouldNotCreateTitleGroupBookmark(#[source] sqlx::Error),
 
@@ -293,20 +474,46 @@ impl actix_web::ResponseError for Error {
             | Error::InvitationKeyAlreadyUsed
             | Error::WrongUsernameOrPassword
             | Error::TorrentFileInvalid
-            | Error::InvalidUserIdOrTorrentId => StatusCode::BAD_REQUEST,
+            | Error::MediainfoMismatch(_)
+            | Error::LogsOnlyAllowedForCdSource
+            | Error::InvalidTorrentTrump
+            | Error::UploadRulesViolated(_)
+            | Error::InvalidUserIdOrTorrentId
+            | Error::CouldNotDetermineClientIp => StatusCode::BAD_REQUEST,
 
             // 401 Unauthorized
-            Error::InvalidOrExpiredRefreshToken | Error::InvalidatedToken => {
-                StatusCode::UNAUTHORIZED
-            }
+            Error::InvalidOrExpiredRefreshToken
+            | Error::InvalidatedToken
+            | Error::InvalidPasskeyOrBanned => StatusCode::UNAUTHORIZED,
 
             // 403 Forbidden
-            Error::AccountBanned | Error::InsufficientPrivileges => StatusCode::FORBIDDEN,
+            Error::AccountBanned
+            | Error::IpBanned
+            | Error::InsufficientPrivileges
+            | Error::NotificationFilterLimitReached(_)
+            | Error::ForumThreadLocked => StatusCode::FORBIDDEN,
 
             // 404 Not Found
             Error::UserNotFound(_)
             | Error::UserWithIdNotFound(_)
             | Error::SeriesWithIdNotFound(_)
+            | Error::TorrentClientNotFound(_)
+            | Error::TrackerFlagNotFound(_)
+            | Error::IpBanNotFound(_)
+            | Error::UploadRuleNotFound(_)
+            | Error::CrossSeedableTorrentNotFound
+            | Error::TorrentReportNotFound(_)
+            | Error::EditionGroupNotFound(_)
+            | Error::ArtistNotFound(_)
+            | Error::RevisionNotFound(_)
+            | Error::SavedTorrentSearchNotFound(_)
+            | Error::NotificationFilterNotFound(_)
+            | Error::NotificationNotFound(_)
+            | Error::ForumPostNotFound(_)
+            | Error::ForumThreadNotFound(_)
+            | Error::ForumCategoryNotFound(_)
+            | Error::ForumSubCategoryNotFound(_)
+            | Error::ForumPollNotFound(_)
             | Error::DottorrentFileNotFound => StatusCode::NOT_FOUND,
 
             // 409 Conflict
@@ -316,7 +523,16 @@ impl actix_web::ResponseError for Error {
             | Error::TorrentRequestAlreadyFilled
             | Error::TorrentTitleGroupNotMatchingRequestedOne
             | Error::InsufficientBonusPointsForBounty
-            | Error::InsufficientUploadForBounty => StatusCode::CONFLICT,
+            | Error::InsufficientUploadForBounty
+            | Error::DuplicateTorrent(_)
+            | Error::TorrentReportAlreadyResolved
+            | Error::TorrentReportClaimedByAnotherUser
+            | Error::ForumCategoryNotEmpty
+            | Error::ForumSubCategoryNotEmpty
+            | Error::ForumPollClosed
+            | Error::ForumPollAlreadyVoted
+            | Error::ForumThreadsBothHavePolls
+            | Error::IpBanAlreadyExists => StatusCode::CONFLICT,
 
             // 500 Internal Server Error
             _ => StatusCode::INTERNAL_SERVER_ERROR,
@@ -325,8 +541,15 @@ impl actix_web::ResponseError for Error {
 
     fn error_response(&self) -> actix_web::HttpResponse {
         log::error!("The request generated this error: {self}");
-        actix_web::HttpResponse::build(self.status_code()).json(serde_json::json!({
-            "error": format!("{self}"),
-        }))
+        let body = match self {
+            Error::UploadRulesViolated(field_errors) => serde_json::json!({
+                "error": format!("{self}"),
+                "field_errors": field_errors,
+            }),
+            _ => serde_json::json!({
+                "error": format!("{self}"),
+            }),
+        };
+        actix_web::HttpResponse::build(self.status_code()).json(body)
     }
 }
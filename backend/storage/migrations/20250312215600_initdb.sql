@@ -3,6 +3,16 @@ CREATE TYPE user_class_enum AS ENUM (
     'staff',
     'tracker'
 );
+-- the order of the enum values is not a ranking, classes are compared with their rank
+-- (must match UserClass::rank), the tracker class is the tracker's own account
+CREATE FUNCTION user_class_rank(class user_class_enum)
+RETURNS INT AS $$
+    SELECT CASE class
+        WHEN 'tracker' THEN 0
+        WHEN 'newbie' THEN 1
+        WHEN 'staff' THEN 2
+    END;
+$$ LANGUAGE sql IMMUTABLE;
 
 CREATE TABLE users (
     id SERIAL PRIMARY KEY,
@@ -442,7 +452,11 @@ CREATE TABLE torrents (
     deleted_at TIMESTAMP WITH TIME ZONE DEFAULT NULL,
     deleted_by_id INT DEFAULT NULL,
     info_hash BYTEA NOT NULL CHECK(octet_length(info_hash) = 20),
+    -- v2 and hybrid torrents: SHA-256 info hash truncated to the 20 bytes clients announce with
+    info_hash_v2 BYTEA CHECK(octet_length(info_hash_v2) = 20),
     info_dict BYTEA NOT NULL,
+    -- v2 and hybrid torrents: bencoded piece layers, needed to rebuild the .torrent file
+    piece_layers BYTEA,
     languages language_enum[] NOT NULL DEFAULT ARRAY[]::language_enum[],
     release_name TEXT NOT NULL,
     -- maybe change the size
@@ -451,10 +465,18 @@ CREATE TABLE torrents (
     file_amount_per_type JSONB NOT NULL,
     uploaded_as_anonymous BOOLEAN NOT NULL DEFAULT FALSE,
     file_list JSONB NOT NULL,
+    -- looks up the torrents with the same files, ex: to cross seed
+    file_list_hash BYTEA NOT NULL GENERATED ALWAYS AS (decode(md5(file_list::TEXT), 'hex')) STORED,
     -- maybe change the size to the max length of a file name in a torrent
     mediainfo TEXT,
     trumpable TEXT,
+    -- set by staff when the torrent is replaced by a better one
+    trumped_by_id INT,
     staff_checked BOOLEAN NOT NULL DEFAULT FALSE,
+    -- uploads of some user classes are not announced on the tracker until approved by staff
+    held_from_tracker BOOLEAN NOT NULL DEFAULT FALSE,
+    -- lowest score of the rip logs attached to the torrent, NULL if there are none
+    log_score SMALLINT,
     container VARCHAR(8) NOT NULL,
     -- in bytes
     size BIGINT NOT NULL,
@@ -481,7 +503,60 @@ CREATE TABLE torrents (
 
     FOREIGN KEY (edition_group_id) REFERENCES edition_groups(id) ON DELETE CASCADE,
     FOREIGN KEY (created_by_id) REFERENCES users(id) ON DELETE SET NULL,
-    UNIQUE (info_hash)
+    FOREIGN KEY (trumped_by_id) REFERENCES torrents(id) ON DELETE SET NULL,
+    UNIQUE (info_hash),
+    UNIQUE (info_hash_v2)
+);
+CREATE INDEX torrents_file_list_hash_idx ON torrents (file_list_hash);
+-- checked when uploading a torrent, a rule without category applies to every
+-- category of its content type. empty arrays and NULL values are not checked
+CREATE TABLE upload_rules (
+    id SERIAL PRIMARY KEY,
+    content_type content_type_enum NOT NULL,
+    category title_group_category_enum,
+    -- lowercase, without the leading dot
+    allowed_file_extensions TEXT[] NOT NULL DEFAULT ARRAY[]::TEXT[],
+    allowed_containers TEXT[] NOT NULL DEFAULT ARRAY[]::TEXT[],
+    -- names of the upload form fields that must be filled
+    required_fields TEXT[] NOT NULL DEFAULT ARRAY[]::TEXT[],
+    -- in bytes
+    min_piece_length BIGINT,
+    max_piece_length BIGINT,
+    -- regex the release name must match
+    release_name_pattern TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE NULLS NOT DISTINCT (content_type, category)
+);
+-- rankings used to flag likely trumps on upload, ordered from worst to best.
+-- values missing from a ranking are not compared
+CREATE TABLE torrent_trump_rules (
+    content_type content_type_enum PRIMARY KEY,
+    audio_bitrate_sampling_ranking audio_bitrate_sampling_enum[] NOT NULL DEFAULT ARRAY[]::audio_bitrate_sampling_enum[],
+    audio_codec_ranking audio_codec_enum[] NOT NULL DEFAULT ARRAY[]::audio_codec_enum[],
+    video_resolution_ranking video_resolution_enum[] NOT NULL DEFAULT ARRAY[]::video_resolution_enum[],
+    video_codec_ranking video_codec_enum[] NOT NULL DEFAULT ARRAY[]::video_codec_enum[],
+    -- a higher rip log score is better
+    compare_log_score BOOLEAN NOT NULL DEFAULT FALSE
+);
+INSERT INTO torrent_trump_rules (content_type, audio_bitrate_sampling_ranking, compare_log_score) VALUES
+    ('music', ARRAY['64', '128', '192', '256', '320', 'V2 (VBR)', 'V0 (VBR)', 'Lossless']::audio_bitrate_sampling_enum[], TRUE);
+INSERT INTO torrent_trump_rules (content_type, video_resolution_ranking, video_codec_ranking) VALUES
+    ('movie', ARRAY['480p', '576p', '720p', '1080p', '2160p']::video_resolution_enum[], ARRAY['mpeg1', 'mpeg2', 'divX', 'Xvid', 'h264']::video_codec_enum[]),
+    ('tv_show', ARRAY['480p', '576p', '720p', '1080p', '2160p']::video_resolution_enum[], ARRAY['mpeg1', 'mpeg2', 'divX', 'Xvid', 'h264']::video_codec_enum[]);
+CREATE TYPE log_ripper_enum AS ENUM('eac', 'xld', 'unknown');
+CREATE TYPE log_checksum_enum AS ENUM('missing', 'unverified', 'valid', 'invalid');
+CREATE TABLE torrent_logs (
+    id BIGSERIAL PRIMARY KEY,
+    torrent_id INT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    ripper log_ripper_enum NOT NULL,
+    log TEXT NOT NULL,
+    score SMALLINT NOT NULL,
+    checksum log_checksum_enum NOT NULL,
+    -- [{"reason": "...", "points": 10}]
+    deductions JSONB NOT NULL DEFAULT '[]'::jsonb,
+    FOREIGN KEY (torrent_id) REFERENCES torrents(id) ON DELETE CASCADE
 );
 CREATE TABLE title_group_comments (
     id BIGSERIAL PRIMARY KEY,
@@ -546,14 +621,72 @@ CREATE TABLE torrent_request_comments (
     created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
     updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
 );
+CREATE TYPE torrent_review_decision_enum AS ENUM('approved', 'changes_requested', 'rejected');
+CREATE TABLE torrent_reviews (
+    id BIGSERIAL PRIMARY KEY,
+    torrent_id INT NOT NULL,
+    reviewed_by_id INT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    decision torrent_review_decision_enum NOT NULL,
+    -- required unless the torrent is approved
+    reason TEXT,
+    FOREIGN KEY (torrent_id) REFERENCES torrents(id) ON DELETE CASCADE,
+    FOREIGN KEY (reviewed_by_id) REFERENCES users(id)
+);
+CREATE TYPE torrent_report_category_enum AS ENUM('dupe', 'trump', 'bad_metadata', 'rules_broken', 'other');
+-- action taken by staff when resolving the report
+CREATE TYPE torrent_report_outcome_enum AS ENUM('deleted', 'edited', 'dismissed');
 CREATE TABLE torrent_reports (
     id BIGSERIAL PRIMARY KEY,
     reported_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
     reported_by_id INT NOT NULL,
     description TEXT NOT NULL,
     reported_torrent_id INT NOT NULL,
+    category torrent_report_category_enum NOT NULL DEFAULT 'other',
+    claimed_by_id INT,
+    claimed_at TIMESTAMP WITH TIME ZONE,
+    resolved_by_id INT,
+    resolved_at TIMESTAMP WITH TIME ZONE,
+    outcome torrent_report_outcome_enum,
+    -- sent to the reporter
+    resolution_comment TEXT,
     FOREIGN KEY (reported_by_id) REFERENCES users(id) ON DELETE CASCADE,
-    FOREIGN KEY (reported_torrent_id) REFERENCES torrents(id) ON DELETE CASCADE
+    FOREIGN KEY (reported_torrent_id) REFERENCES torrents(id) ON DELETE CASCADE,
+    FOREIGN KEY (claimed_by_id) REFERENCES users(id) ON DELETE SET NULL,
+    FOREIGN KEY (resolved_by_id) REFERENCES users(id) ON DELETE SET NULL
+);
+CREATE TYPE revision_resource_type_enum AS ENUM('torrent', 'edition_group', 'title_group', 'artist', 'series');
+CREATE TABLE revisions (
+    id BIGSERIAL PRIMARY KEY,
+    resource_type revision_resource_type_enum NOT NULL,
+    -- not a foreign key, as it points to a different table depending on resource_type
+    resource_id BIGINT NOT NULL,
+    edited_by_id INT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    -- {field: {old: value, new: value}}, only for the fields that changed
+    diff JSONB NOT NULL,
+    -- editable fields of the resource after the edit, used to revert to this revision
+    snapshot JSONB NOT NULL,
+    FOREIGN KEY (edited_by_id) REFERENCES users(id)
+);
+CREATE INDEX revisions_resource_idx ON revisions (resource_type, resource_id);
+CREATE TYPE site_log_action_enum AS ENUM(
+    'title_groups_merged',
+    'torrent_moved',
+    'edition_group_split',
+    'forum_post_deleted',
+    'forum_post_restored',
+    'forum_thread_moved',
+    'forum_thread_split',
+    'forum_threads_merged'
+);
+CREATE TABLE site_logs (
+    id BIGSERIAL PRIMARY KEY,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    performed_by_id INT NOT NULL,
+    action site_log_action_enum NOT NULL,
+    message TEXT NOT NULL,
+    FOREIGN KEY (performed_by_id) REFERENCES users(id)
 );
 CREATE TABLE peers (
     peer_id bytea NOT NULL,
@@ -580,6 +713,105 @@ ALTER TABLE peers
 ADD CONSTRAINT peers_torrent_id_foreign FOREIGN KEY (torrent_id) REFERENCES torrents (id) ON DELETE CASCADE ON UPDATE CASCADE;
 ALTER TABLE peers
 ADD CONSTRAINT peers_user_id_foreign FOREIGN KEY (user_id) REFERENCES users (id) ON UPDATE CASCADE;
+CREATE TYPE torrent_client_rule_enum AS ENUM (
+    'allow',
+    'deny'
+);
+-- a client is allowed to announce if it matches at least one 'allow' rule and no 'deny' rule
+CREATE TABLE torrent_clients (
+    id SERIAL PRIMARY KEY,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    created_by_id INT NOT NULL,
+    name VARCHAR(50) NOT NULL,
+    rule torrent_client_rule_enum NOT NULL DEFAULT 'allow',
+    -- matched against the peer_id, without its leading '-'
+    peer_id_prefix VARCHAR(19) NOT NULL,
+    -- compared with the 4 version characters of azureus-style peer_ids (-qB4620- -> 4620)
+    min_version VARCHAR(4),
+    max_version VARCHAR(4),
+    -- regex
+    user_agent_pattern TEXT,
+    description TEXT NOT NULL DEFAULT '',
+    FOREIGN KEY (created_by_id) REFERENCES users(id)
+);
+INSERT INTO torrent_clients (created_by_id, name, peer_id_prefix)
+VALUES
+    (1, 'BiglyBT', 'BI3500'),
+    (1, 'BiglyBT', 'BI3600'),
+    (1, 'BiglyBT', 'BI3700'),
+    (1, 'Deluge', 'DE12'),
+    (1, 'Deluge', 'DE13'),
+    (1, 'Deluge', 'DE2'),
+    (1, 'Flud', 'FL149'),
+    (1, 'Flud', 'FL1490'),
+    (1, 'Halite', 'HL04'),
+    (1, 'KTorrent', 'KT21'),
+    (1, 'KTorrent', 'KT23'),
+    (1, 'KTorrent', 'KT24'),
+    (1, 'KTorrent', 'KT5'),
+    (1, 'libtorrent (Rasterbar)', 'LT'),
+    (1, 'rTorrent', 'lt0D'),
+    (1, 'rTorrent', 'lt0E'),
+    (1, 'rTorrent', 'lt0F'),
+    (1, 'qBittorrent', 'qB2'),
+    (1, 'qBittorrent', 'qB3'),
+    (1, 'qBittorrent', 'qB40'),
+    (1, 'qBittorrent', 'qB41'),
+    (1, 'qBittorrent', 'qB42'),
+    (1, 'qBittorrent', 'qB43'),
+    (1, 'qBittorrent', 'qB44'),
+    (1, 'qBittorrent', 'qB45'),
+    (1, 'qBittorrent', 'qB46'),
+    (1, 'qBittorrent', 'qB50'),
+    (1, 'qBittorrent', 'qB51'),
+    (1, 'Transmission', 'TR2'),
+    (1, 'Transmission', 'TR3000'),
+    (1, 'Transmission', 'TR4010'),
+    (1, 'Transmission', 'TR4020'),
+    (1, 'Transmission', 'TR4030'),
+    (1, 'Transmission', 'TR4040'),
+    (1, 'Transmission', 'TR4050'),
+    (1, 'Transmission', 'TR4060');
+CREATE TABLE ip_bans (
+    id SERIAL PRIMARY KEY,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    created_by_id INT NOT NULL,
+    -- a single address is stored as a /32 (or /128) network
+    ip_range CIDR NOT NULL UNIQUE,
+    reason TEXT NOT NULL,
+    FOREIGN KEY (created_by_id) REFERENCES users(id)
+);
+CREATE TYPE tracker_flag_kind_enum AS ENUM (
+    'excessive_upload_speed',
+    'upload_without_leechers',
+    'upload_exceeding_swarm_download',
+    'peer_id_user_agent_mismatch'
+);
+-- suspicious announces, raised by the tracker's cheat detection
+CREATE TABLE tracker_flags (
+    id BIGSERIAL PRIMARY KEY,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    kind tracker_flag_kind_enum NOT NULL,
+    user_id INT NOT NULL,
+    torrent_id INT NOT NULL,
+    peer_id bytea NOT NULL,
+    ip INET NOT NULL,
+    agent VARCHAR(64) NOT NULL,
+    uploaded_delta BIGINT NOT NULL,
+    downloaded_delta BIGINT NOT NULL,
+    -- seconds since the peer's previous announce, if any
+    announce_interval BIGINT,
+    -- amount of flagged announces merged into this row
+    occurrences INT NOT NULL DEFAULT 1,
+    credit_withheld BOOLEAN NOT NULL,
+    resolved_by_id INT,
+    resolved_at TIMESTAMP WITH TIME ZONE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (torrent_id) REFERENCES torrents(id) ON DELETE CASCADE,
+    FOREIGN KEY (resolved_by_id) REFERENCES users(id) ON DELETE SET NULL
+);
+CREATE INDEX tracker_flags_user_id_index ON tracker_flags (user_id);
 CREATE TABLE torrent_activities (
     id BIGSERIAL PRIMARY KEY,
     torrent_id INT NOT NULL,
@@ -725,6 +957,7 @@ CREATE TABLE forum_categories (
     name TEXT NOT NULL,
     created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
     created_by_id INT NOT NULL,
+    sort_order INT NOT NULL DEFAULT 0,
 
     FOREIGN KEY (created_by_id) REFERENCES users(id)
 );
@@ -737,7 +970,10 @@ CREATE TABLE forum_sub_categories (
     created_by_id INT,
     threads_amount BIGINT NOT NULL DEFAULT 0,
     posts_amount BIGINT NOT NULL DEFAULT 0,
-    forbidden_classes VARCHAR(50) [] NOT NULL DEFAULT ARRAY[]::VARCHAR(50)[],
+    sort_order INT NOT NULL DEFAULT 0,
+    min_class_read user_class_enum NOT NULL DEFAULT 'newbie',
+    min_class_post user_class_enum NOT NULL DEFAULT 'newbie',
+    min_class_create_thread user_class_enum NOT NULL DEFAULT 'newbie',
 
     FOREIGN KEY (created_by_id) REFERENCES users(id),
     FOREIGN KEY (forum_category_id) REFERENCES forum_categories(id)
@@ -765,11 +1001,68 @@ CREATE TABLE forum_posts (
     created_by_id INT NOT NULL,
     content TEXT NOT NULL,
     sticky BOOLEAN NOT NULL DEFAULT FALSE,
+    deleted_at TIMESTAMP WITH TIME ZONE,
+    deleted_by_id INT,
 
     FOREIGN KEY (created_by_id) REFERENCES users(id),
-    FOREIGN KEY (forum_thread_id) REFERENCES forum_threads(id)
+    FOREIGN KEY (forum_thread_id) REFERENCES forum_threads(id),
+    FOREIGN KEY (deleted_by_id) REFERENCES users(id)
 );
 INSERT INTO forum_posts (created_by_id, forum_thread_id, content) VALUES (1, 1, 'Welcome!');
+CREATE TABLE forum_post_edits (
+    id BIGSERIAL PRIMARY KEY,
+    forum_post_id BIGINT NOT NULL,
+    edited_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    edited_by_id INT NOT NULL,
+    previous_content TEXT NOT NULL,
+
+    FOREIGN KEY (forum_post_id) REFERENCES forum_posts(id) ON DELETE CASCADE,
+    FOREIGN KEY (edited_by_id) REFERENCES users(id)
+);
+CREATE INDEX idx_forum_post_edits_forum_post_id ON forum_post_edits (forum_post_id);
+-- the posts up to last_read_post_id are read by the user
+CREATE TABLE forum_thread_reads (
+    user_id INT NOT NULL,
+    forum_thread_id BIGINT NOT NULL,
+    last_read_post_id BIGINT NOT NULL,
+    read_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (user_id, forum_thread_id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (forum_thread_id) REFERENCES forum_threads(id) ON DELETE CASCADE
+);
+CREATE TABLE forum_polls (
+    id BIGSERIAL PRIMARY KEY,
+    forum_thread_id BIGINT NOT NULL UNIQUE,
+    question TEXT NOT NULL,
+    multiple_choice BOOLEAN NOT NULL DEFAULT FALSE,
+    -- the voters of each option are only listed for public polls
+    anonymous BOOLEAN NOT NULL DEFAULT TRUE,
+    closes_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    FOREIGN KEY (forum_thread_id) REFERENCES forum_threads(id) ON DELETE CASCADE
+);
+CREATE TABLE forum_poll_options (
+    id BIGSERIAL PRIMARY KEY,
+    forum_poll_id BIGINT NOT NULL,
+    label TEXT NOT NULL,
+
+    FOREIGN KEY (forum_poll_id) REFERENCES forum_polls(id) ON DELETE CASCADE
+);
+CREATE INDEX idx_forum_poll_options_forum_poll_id ON forum_poll_options (forum_poll_id);
+CREATE TABLE forum_poll_votes (
+    forum_poll_id BIGINT NOT NULL,
+    forum_poll_option_id BIGINT NOT NULL,
+    user_id INT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (forum_poll_option_id, user_id),
+    FOREIGN KEY (forum_poll_id) REFERENCES forum_polls(id) ON DELETE CASCADE,
+    FOREIGN KEY (forum_poll_option_id) REFERENCES forum_poll_options(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+CREATE INDEX idx_forum_poll_votes_forum_poll_id_user_id ON forum_poll_votes (forum_poll_id, user_id);
 CREATE TABLE wiki_articles (
     id BIGSERIAL PRIMARY KEY,
     title TEXT NOT NULL,
@@ -791,6 +1084,15 @@ CREATE TABLE title_group_bookmarks (
     FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
     FOREIGN KEY (title_group_id) REFERENCES title_groups(id) ON DELETE CASCADE
 );
+CREATE TABLE saved_torrent_searches (
+    id BIGSERIAL PRIMARY KEY,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    user_id INT NOT NULL,
+    name TEXT NOT NULL,
+    -- the torrent search form, as sent to the search endpoint
+    search JSONB NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
 CREATE TABLE conversations (
     id BIGSERIAL PRIMARY KEY,
     created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
@@ -837,16 +1139,6 @@ CREATE TABLE subscriptions_forum_thread_posts (
     FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
     UNIQUE (forum_thread_id, user_id)
 );
-CREATE TABLE notifications_forum_thread_posts (
-    id BIGSERIAL PRIMARY KEY,
-    forum_thread_id BIGINT NOT NULL,
-    forum_post_id BIGINT NOT NULL,
-    user_id INT NOT NULL,
-    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
-    read_status BOOLEAN NOT NULL DEFAULT FALSE,
-    FOREIGN KEY (user_id) REFERENCES users(id),
-    FOREIGN KEY (forum_post_id) REFERENCES forum_posts(id) ON DELETE CASCADE
-);
 -- notifies of new torrents within a title group
 CREATE TABLE subscriptions_title_group_torrents (
     id BIGSERIAL PRIMARY KEY,
@@ -857,14 +1149,102 @@ CREATE TABLE subscriptions_title_group_torrents (
     FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
     UNIQUE (title_group_id, user_id)
 );
-CREATE TABLE notifications_title_group_torrents  (
+-- notifies of new torrents in the title groups affiliated to an artist
+CREATE TABLE subscriptions_artist_torrents (
+    id BIGSERIAL PRIMARY KEY,
+    artist_id BIGINT NOT NULL,
+    user_id INT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    FOREIGN KEY (artist_id) REFERENCES artists(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE (artist_id, user_id)
+);
+-- notifies of new torrents in the title groups of a series
+CREATE TABLE subscriptions_series_torrents (
     id BIGSERIAL PRIMARY KEY,
+    series_id BIGINT NOT NULL,
+    user_id INT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    FOREIGN KEY (series_id) REFERENCES series(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE (series_id, user_id)
+);
+-- notifies of new torrents matching the filters of the user,
+-- empty criteria match all the torrents
+CREATE TABLE notification_filters (
+    id BIGSERIAL PRIMARY KEY,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    user_id INT NOT NULL,
+    name TEXT NOT NULL,
+    artist_ids BIGINT[] NOT NULL,
+    tags TEXT[] NOT NULL,
+    content_types content_type_enum[] NOT NULL,
+    containers TEXT[] NOT NULL,
+    audio_codecs audio_codec_enum[] NOT NULL,
+    video_resolutions video_resolution_enum[] NOT NULL,
+    release_groups TEXT[] NOT NULL,
+    uploader_ids INT[] NOT NULL,
+    min_size BIGINT,
+    -- the matching torrents are listed in the notifications feed of the user
+    include_in_feed BOOLEAN NOT NULL DEFAULT FALSE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+-- the torrents each filter matched, the notifications feed is built from them
+-- so that it doesn't depend on the notifications the user deleted or disabled
+CREATE TABLE notification_filter_torrents (
+    notification_filter_id BIGINT NOT NULL,
     torrent_id INT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (notification_filter_id, torrent_id),
+    FOREIGN KEY (notification_filter_id) REFERENCES notification_filters(id) ON DELETE CASCADE,
+    FOREIGN KEY (torrent_id) REFERENCES torrents(id) ON DELETE CASCADE
+);
+-- generic notifications, listed in the notification center.
+-- target_id is the id of the resource the notification is about, its table depends on the type
+CREATE TYPE notification_type_enum AS ENUM (
+    'forum_post_mention',
+    'forum_post_quote',
+    'torrent_request_filled',
+    'torrent_request_voted',
+    'torrent_deleted',
+    'torrent_trumped',
+    'torrent_report_resolved',
+    'conversation_message',
+    'user_warning',
+    'gift_received',
+    -- new post in a subscribed thread, target_id is the thread
+    'forum_thread_post',
+    -- new torrent in a subscribed title group, artist or series, target_id is the torrent
+    'title_group_torrent',
+    'artist_torrent',
+    'series_torrent',
+    -- new torrent matching a notification filter, target_id is the torrent
+    'filtered_torrent',
+    -- the uploader's torrent got reviewed by staff, target_id is the torrent
+    'torrent_reviewed'
+);
+CREATE TABLE notifications (
+    id BIGSERIAL PRIMARY KEY,
     user_id INT NOT NULL,
+    notification_type notification_type_enum NOT NULL,
+    actor_id INT,
+    target_id BIGINT NOT NULL,
+    payload JSONB NOT NULL,
     created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
     read_status BOOLEAN NOT NULL DEFAULT FALSE,
-    FOREIGN KEY (user_id) REFERENCES users(id),
-    FOREIGN KEY (torrent_id) REFERENCES torrents(id) ON DELETE CASCADE
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (actor_id) REFERENCES users(id) ON DELETE SET NULL
+);
+CREATE INDEX notifications_user_id_created_at_idx ON notifications (user_id, created_at DESC);
+-- the notifications of a target, ex: the subscribers of a thread who didn't read its last posts
+CREATE INDEX notifications_notification_type_target_id_idx ON notifications (notification_type, target_id);
+-- all the notification types are enabled unless disabled here
+CREATE TABLE notification_preferences (
+    user_id INT NOT NULL,
+    notification_type notification_type_enum NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    PRIMARY KEY (user_id, notification_type),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
 );
 
 -- Views
@@ -904,6 +1284,7 @@ SELECT
     t.mediainfo,
     t.trumpable,
     t.staff_checked,
+    t.log_score,
     t.container,
     t.size,
     t.duration,
@@ -920,16 +1301,24 @@ SELECT
     (EXISTS (
         SELECT 1
         FROM torrent_reports tr
-        WHERE tr.reported_torrent_id = t.id
+        WHERE tr.reported_torrent_id = t.id AND tr.resolved_at IS NULL
     )) AS reported,
     CASE
-        WHEN EXISTS (SELECT 1 FROM torrent_reports WHERE reported_torrent_id = t.id) THEN json_agg(row_to_json(tr))
+        WHEN EXISTS (SELECT 1 FROM torrent_reports WHERE reported_torrent_id = t.id AND resolved_at IS NULL) THEN json_agg(row_to_json(tr))
         ELSE '[]'::json
-    END AS reports
+    END AS reports,
+    COALESCE((
+        SELECT json_agg(json_build_object(
+            'id', tl.id, 'ripper', tl.ripper, 'score', tl.score,
+            'checksum', tl.checksum, 'deductions', tl.deductions
+        ) ORDER BY tl.id)
+        FROM torrent_logs tl
+        WHERE tl.torrent_id = t.id
+    ), '[]'::json) AS logs
     FROM
         torrents t
     LEFT JOIN
-        torrent_reports tr ON t.id = tr.reported_torrent_id
+        torrent_reports tr ON t.id = tr.reported_torrent_id AND tr.resolved_at IS NULL
     LEFT JOIN
         users u ON t.created_by_id = u.id
     WHERE t.deleted_at IS NULL
@@ -959,7 +1348,7 @@ CREATE VIEW get_title_groups_and_edition_group_and_torrents_lite AS
                         'edition_group_id', tar.edition_group_id, 'created_at', tar.created_at, 'extras', tar.extras,
                         'release_name', tar.release_name, 'release_group', tar.release_group,
                         'file_amount_per_type', tar.file_amount_per_type, 'trumpable', tar.trumpable,
-                        'staff_checked', tar.staff_checked, 'languages', tar.languages,
+                        'staff_checked', tar.staff_checked, 'log_score', tar.log_score, 'languages', tar.languages,
                         'container', tar.container, 'size', tar.size, 'duration', tar.duration,
                         'audio_codec', tar.audio_codec, 'audio_bitrate', tar.audio_bitrate,
                         'audio_bitrate_sampling', tar.audio_bitrate_sampling, 'audio_channels', tar.audio_channels,
@@ -1058,6 +1447,7 @@ SELECT
     torrents.file_amount_per_type AS torrent_file_amount_per_type,
     torrents.trumpable AS torrent_trumpable,
     torrents.staff_checked AS torrent_staff_checked,
+    torrents.log_score AS torrent_log_score,
     torrents.languages AS torrent_languages,
     torrents.container AS torrent_container,
     torrents.size AS torrent_size,
@@ -1075,7 +1465,7 @@ SELECT
     (EXISTS (
         SELECT 1
         FROM torrent_reports tr
-        WHERE tr.reported_torrent_id = torrents.id
+        WHERE tr.reported_torrent_id = torrents.id AND tr.resolved_at IS NULL
     )) AS torrent_reported
 FROM title_groups
 JOIN edition_groups ON edition_groups.title_group_id = title_groups.id
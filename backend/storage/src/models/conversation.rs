@@ -28,7 +28,7 @@ pub struct UserCreatedConversation {
     pub first_message: UserCreatedConversationMessage,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct ConversationMessage {
     pub id: i64,
     pub conversation_id: i64,
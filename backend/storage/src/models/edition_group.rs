@@ -97,6 +97,28 @@ pub struct UserCreatedEditionGroup {
     pub title_group_id: i32,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EditedEditionGroup {
+    pub id: i32,
+    pub name: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub release_date: DateTime<Utc>,
+    pub description: Option<String>,
+    pub distributor: Option<String>,
+    pub covers: Vec<String>,
+    pub external_links: Vec<String>,
+    pub source: Option<Source>,
+    #[schema(value_type = HashMap<String, String>)]
+    pub additional_information: Option<Value>,
+}
+
+// the torrents are moved to a copy of the edition group, which can then be edited
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EditionGroupSplit {
+    pub edition_group_id: i32,
+    pub torrent_ids: Vec<i32>,
+}
+
 #[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct EditionGroupHierarchyLite {
     pub id: i32,
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Local};
+
+use super::{
+    title_group::{ContentType, TitleGroupCategory},
+    torrent::TorrentSearch,
+};
+
+// filters applied to the torrents listed in a feed, newest torrents first
+#[derive(Debug, Default)]
+pub struct TorrentFeedFilter {
+    // only torrents of the title groups the user is subscribed to
+    pub subscribed_by_id: Option<i32>,
+    // only torrents of the title groups the user bookmarked
+    pub bookmarked_by_id: Option<i32>,
+    // only torrents matching the notification filters the user included in their feed
+    pub notified_user_id: Option<i32>,
+    pub title_group_name: Option<String>,
+    pub staff_checked: Option<bool>,
+    // torrents with an unresolved report, or without any
+    pub reported: Option<bool>,
+    pub log_score_min: Option<i16>,
+    pub created_by_id: Option<i32>,
+    pub artist_id: Option<i64>,
+    pub collage_id: Option<i64>,
+    // matched against the names of the artists affiliated to the title group
+    pub artist_name: Option<String>,
+    // case insensitive regex matched against the external links of the title group
+    pub external_link_regex: Option<String>,
+    // only torrents of these content types, restricted to a category when it is set,
+    // all content types when empty
+    pub content_types: Vec<(ContentType, Option<TitleGroupCategory>)>,
+    pub offset: i64,
+    pub limit: i64,
+}
+
+impl From<&TorrentSearch> for TorrentFeedFilter {
+    fn from(search: &TorrentSearch) -> Self {
+        Self {
+            title_group_name: search
+                .title_group_name
+                .clone()
+                .filter(|name| !name.trim().is_empty()),
+            staff_checked: search.torrent_staff_checked,
+            reported: search.torrent_reported,
+            log_score_min: search.torrent_log_score_min,
+            created_by_id: search.torrent_created_by_id,
+            artist_id: search.artist_id,
+            collage_id: search.collage_id.map(i64::from),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FeedTorrent {
+    pub id: i32,
+    pub title_group_id: i32,
+    pub title_group_name: String,
+    pub edition_group_name: Option<String>,
+    pub release_name: Option<String>,
+    pub release_group: Option<String>,
+    pub size: i64,
+    pub created_at: DateTime<Local>,
+    pub seeders: i64,
+    pub leechers: i64,
+    pub times_completed: i32,
+    pub upload_factor: i16,
+    pub download_factor: i16,
+    pub content_type: ContentType,
+    pub category: Option<TitleGroupCategory>,
+    pub tags: Vec<String>,
+    pub external_links: Vec<String>,
+}
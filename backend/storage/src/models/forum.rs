@@ -1,9 +1,9 @@
 use chrono::{DateTime, Local, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::prelude::FromRow;
+use sqlx::{prelude::FromRow, types::Json};
 use utoipa::{IntoParams, ToSchema};
 
-use super::user::{UserLite, UserLiteAvatar};
+use super::user::{UserClass, UserLite, UserLiteAvatar};
 
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
 pub struct ForumCategory {
@@ -12,6 +12,20 @@ pub struct ForumCategory {
     #[schema(value_type = String, format = DateTime)]
     pub created_at: DateTime<Local>,
     pub created_by_id: i32,
+    pub sort_order: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UserCreatedForumCategory {
+    pub name: String,
+    pub sort_order: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct EditedForumCategory {
+    pub id: i32,
+    pub name: String,
+    pub sort_order: i32,
 }
 
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
@@ -21,10 +35,34 @@ pub struct ForumSubCategory {
     pub name: String,
     #[schema(value_type = String, format = DateTime)]
     pub created_at: DateTime<Local>,
-    pub created_by_id: i32,
+    pub created_by_id: Option<i32>,
     pub threads_amount: i64,
     pub posts_amount: i64,
-    pub forbidden_classes: Vec<String>,
+    pub sort_order: i32,
+    pub min_class_read: UserClass,
+    pub min_class_post: UserClass,
+    pub min_class_create_thread: UserClass,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UserCreatedForumSubCategory {
+    pub forum_category_id: i32,
+    pub name: String,
+    pub sort_order: i32,
+    pub min_class_read: UserClass,
+    pub min_class_post: UserClass,
+    pub min_class_create_thread: UserClass,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct EditedForumSubCategory {
+    pub id: i32,
+    pub forum_category_id: i32,
+    pub name: String,
+    pub sort_order: i32,
+    pub min_class_read: UserClass,
+    pub min_class_post: UserClass,
+    pub min_class_create_thread: UserClass,
 }
 
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
@@ -45,6 +83,53 @@ pub struct UserCreatedForumThread {
     pub forum_sub_category_id: i32,
     pub name: String,
     pub first_post: UserCreatedForumPost,
+    pub poll: Option<UserCreatedForumPoll>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UserCreatedForumPoll {
+    pub question: String,
+    pub options: Vec<String>,
+    pub multiple_choice: bool,
+    pub anonymous: bool,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub closes_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ForumPoll {
+    pub id: i64,
+    pub forum_thread_id: i64,
+    pub question: String,
+    pub multiple_choice: bool,
+    pub anonymous: bool,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub closes_at: Option<DateTime<Utc>>,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+    pub is_closed: bool,
+    // options the current user voted for
+    pub voted_option_ids: Vec<i64>,
+    // results are hidden until the user voted or the poll is closed
+    pub results_visible: bool,
+    pub voters_amount: Option<i64>,
+    pub options: Vec<ForumPollOption>,
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
+pub struct ForumPollOption {
+    pub id: i64,
+    pub label: String,
+    pub votes_amount: Option<i64>,
+    // only listed for public polls
+    #[schema(value_type = Option<Vec<UserLite>>)]
+    pub voters: Option<Json<Vec<UserLite>>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct UserCreatedForumPollVote {
+    pub forum_poll_id: i64,
+    pub option_ids: Vec<i64>,
 }
 
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
@@ -58,6 +143,9 @@ pub struct ForumPost {
     pub created_by_id: i32,
     pub content: String,
     pub sticky: bool,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by_id: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
@@ -66,6 +154,49 @@ pub struct UserCreatedForumPost {
     pub forum_thread_id: i64,
 }
 
+// sticky can only be changed by staff
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct EditedForumPost {
+    pub id: i64,
+    pub content: String,
+    pub sticky: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
+pub struct ForumPostEdit {
+    pub id: i64,
+    pub forum_post_id: i64,
+    #[schema(value_type = String, format = DateTime)]
+    pub edited_at: DateTime<Utc>,
+    pub edited_by_id: i32,
+    pub edited_by_username: String,
+    pub previous_content: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct EditedForumThread {
+    pub id: i64,
+    pub name: String,
+    pub forum_sub_category_id: i32,
+    pub sticky: bool,
+    pub locked: bool,
+}
+
+// the posts are moved to a new thread in the same sub-category
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ForumThreadSplit {
+    pub forum_thread_id: i64,
+    pub post_ids: Vec<i64>,
+    pub name: String,
+}
+
+// the posts of the source thread are moved to the target one, then the source thread is removed
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ForumThreadsMerge {
+    pub source_thread_id: i64,
+    pub target_thread_id: i64,
+}
+
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
 pub struct ForumOverview {
     forum_categories: Vec<ForumCategoryHierarchy>,
@@ -91,7 +222,8 @@ pub struct ForumSubCategoryHierarchy {
     pub name: String,
     pub threads_amount: i64,
     pub posts_amount: i64,
-    pub forbidden_classes: Vec<String>,
+    pub min_class_post: UserClass,
+    pub min_class_create_thread: UserClass,
     pub latest_post_in_thread: ForumThreadPostLite,
     pub threads: Option<Vec<ForumThreadHierarchy>>,
     pub category: ForumCategoryLite,
@@ -108,6 +240,9 @@ pub struct ForumThreadHierarchy {
     pub posts_amount: i64,
     pub sticky: bool,
     pub locked: bool,
+    pub has_unread: bool,
+    pub first_unread_post_id: Option<i64>,
+    pub first_unread_page: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
@@ -134,6 +269,12 @@ pub struct ForumThreadEnriched {
     pub forum_sub_category_id: i32,
     pub forum_category_name: String,
     pub forum_category_id: i32,
+    #[schema(value_type = Option<ForumPoll>)]
+    pub poll: Option<Json<ForumPoll>>,
+    pub has_unread: bool,
+    pub first_unread_post_id: Option<i64>,
+    // page of the first unread post for the requested posts page size
+    pub first_unread_page: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
@@ -147,6 +288,9 @@ pub struct ForumPostHierarchy {
     pub created_by: UserLiteAvatar,
     pub content: String,
     pub sticky: bool,
+    // only staff get the deleted posts
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{prelude::FromRow, types::ipnetwork::IpNetwork};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct IpBan {
+    pub id: i32,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+    pub created_by_id: i32,
+    #[schema(value_type = String, format = "0.0.0.0/0")]
+    pub ip_range: IpNetwork,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserCreatedIpBan {
+    // a single address or a CIDR range
+    #[schema(value_type = String, format = "0.0.0.0/0")]
+    pub ip_range: IpNetwork,
+    pub reason: String,
+}
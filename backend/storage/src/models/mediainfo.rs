@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::torrent::{AudioChannels, AudioCodec, Features, Language, VideoCodec, VideoResolution};
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MediainfoKind {
+    #[default]
+    Mediainfo,
+    Bdinfo,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MediainfoToParse {
+    // raw text output of MediaInfo or BDInfo
+    pub mediainfo: String,
+}
+
+// every field is optional, only what could be read from the text is set
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ParsedMediainfo {
+    pub kind: MediainfoKind,
+    pub container: Option<String>,
+    pub duration: Option<i32>, // in seconds
+    pub video_codec: Option<VideoCodec>,
+    pub video_resolution: Option<VideoResolution>,
+    pub video_resolution_other_x: Option<i32>,
+    pub video_resolution_other_y: Option<i32>,
+    pub audio_codec: Option<AudioCodec>,
+    pub audio_bitrate: Option<i32>, // in kb/s
+    pub audio_channels: Option<AudioChannels>,
+    pub languages: Vec<Language>, // of the audio tracks
+    pub subtitle_languages: Vec<Language>,
+    pub features: Vec<Features>,
+}
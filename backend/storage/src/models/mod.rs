@@ -4,14 +4,21 @@ pub mod common;
 pub mod conversation;
 pub mod edition_group;
 pub mod entity;
+pub mod feed;
 pub mod forum;
 pub mod gift;
 pub mod home_stats;
 pub mod invitation;
+pub mod ip_ban;
 pub mod master_group;
+pub mod mediainfo;
 pub mod notification;
+pub mod notification_filter;
 pub mod peer;
+pub mod revision;
+pub mod saved_torrent_search;
 pub mod series;
+pub mod site_log;
 pub mod staff_pm;
 pub mod subscription;
 pub mod title_group;
@@ -19,10 +26,16 @@ pub mod title_group_bookmark;
 pub mod title_group_comment;
 pub mod torrent;
 pub mod torrent_activity;
+pub mod torrent_client;
+pub mod torrent_log;
 pub mod torrent_report;
 pub mod torrent_request;
 pub mod torrent_request_comment;
 pub mod torrent_request_vote;
+pub mod torrent_review;
+pub mod torrent_trump;
+pub mod tracker_flag;
+pub mod upload_rule;
 pub mod user;
 pub mod user_application;
 pub mod wiki;
@@ -1,15 +1,62 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use sqlx::prelude::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[sqlx(type_name = "notification_type_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationType {
+    ForumPostMention,
+    ForumPostQuote,
+    TorrentRequestFilled,
+    TorrentRequestVoted,
+    TorrentDeleted,
+    TorrentTrumped,
+    TorrentReportResolved,
+    ConversationMessage,
+    UserWarning,
+    GiftReceived,
+    ForumThreadPost,
+    TitleGroupTorrent,
+    ArtistTorrent,
+    SeriesTorrent,
+    FilteredTorrent,
+    TorrentReviewed,
+}
 
 #[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
-pub struct NotificationForumThreadPost {
+pub struct Notification {
     pub id: i64,
-    pub forum_post_id: i64,
-    pub forum_thread_id: i64,
-    pub forum_thread_name: String,
+    pub notification_type: NotificationType,
+    // the user whose action triggered the notification
+    pub actor_id: Option<i32>,
+    pub actor_username: Option<String>,
+    pub target_id: i64,
+    // details depending on the type, ex: the reason of a deletion
+    #[schema(value_type = Object)]
+    pub payload: Value,
     #[schema(value_type = String, format = DateTime)]
     pub created_at: DateTime<Utc>,
     pub read_status: bool,
 }
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct NotificationQuery {
+    pub include_read: bool,
+    pub notification_type: Option<NotificationType>,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct NotificationsToMarkAsRead {
+    pub notification_ids: Vec<i64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
+pub struct NotificationPreference {
+    pub notification_type: NotificationType,
+    pub enabled: bool,
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{
+    title_group::ContentType,
+    torrent::{AudioCodec, VideoResolution},
+};
+
+// a new torrent matches the filter if it matches all of its non empty criteria,
+// and a criterion is matched by any of its values
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationFilter {
+    pub id: i64,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Local>,
+    pub user_id: i32,
+    pub name: String,
+    pub artist_ids: Vec<i64>,
+    pub tags: Vec<String>,
+    pub content_types: Vec<ContentType>,
+    pub containers: Vec<String>,
+    pub audio_codecs: Vec<AudioCodec>,
+    pub video_resolutions: Vec<VideoResolution>,
+    pub release_groups: Vec<String>,
+    pub uploader_ids: Vec<i32>,
+    // in bytes
+    pub min_size: Option<i64>,
+    pub include_in_feed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserCreatedNotificationFilter {
+    pub name: String,
+    #[serde(default)]
+    pub artist_ids: Vec<i64>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub content_types: Vec<ContentType>,
+    #[serde(default)]
+    pub containers: Vec<String>,
+    #[serde(default)]
+    pub audio_codecs: Vec<AudioCodec>,
+    #[serde(default)]
+    pub video_resolutions: Vec<VideoResolution>,
+    #[serde(default)]
+    pub release_groups: Vec<String>,
+    #[serde(default)]
+    pub uploader_ids: Vec<i32>,
+    pub min_size: Option<i64>,
+    #[serde(default)]
+    pub include_in_feed: bool,
+}
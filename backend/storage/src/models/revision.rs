@@ -0,0 +1,52 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::prelude::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[sqlx(type_name = "revision_resource_type_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionResourceType {
+    Torrent,
+    EditionGroup,
+    TitleGroup,
+    Artist,
+    Series,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Revision {
+    pub id: i64,
+    pub resource_type: RevisionResourceType,
+    pub resource_id: i64,
+    pub edited_by_id: i32,
+    pub edited_by_username: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Local>,
+    // {field: {old: value, new: value}}, empty for the revision holding the original state
+    #[schema(value_type = HashMap<String, RevisionFieldChange>)]
+    pub diff: Value,
+    // editable fields of the resource after the edit
+    #[schema(value_type = HashMap<String, String>)]
+    pub snapshot: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RevisionFieldChange {
+    #[schema(value_type = String)]
+    pub old: Value,
+    #[schema(value_type = String)]
+    pub new: Value,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct RevisionHistoryQuery {
+    pub resource_type: RevisionResourceType,
+    pub resource_id: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RevisionToRevert {
+    pub revision_id: i64,
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use utoipa::ToSchema;
+
+use super::torrent::TorrentSearch;
+
+// a torrent search saved by a user, which can be followed through a feed
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SavedTorrentSearch {
+    pub id: i64,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Local>,
+    pub user_id: i32,
+    pub name: String,
+    #[schema(value_type = TorrentSearch)]
+    pub search: Json<TorrentSearch>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserCreatedSavedTorrentSearch {
+    pub name: String,
+    pub search: TorrentSearch,
+}
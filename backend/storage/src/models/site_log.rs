@@ -0,0 +1,36 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[sqlx(type_name = "site_log_action_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SiteLogAction {
+    TitleGroupsMerged,
+    TorrentMoved,
+    EditionGroupSplit,
+    ForumPostDeleted,
+    ForumPostRestored,
+    ForumThreadMoved,
+    ForumThreadSplit,
+    ForumThreadsMerged,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct SiteLog {
+    pub id: i64,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Local>,
+    pub performed_by_id: i32,
+    pub performed_by_username: String,
+    pub action: SiteLogAction,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct SiteLogQuery {
+    pub action: Option<SiteLogAction>,
+    pub page: u32,
+    pub page_size: u32,
+}
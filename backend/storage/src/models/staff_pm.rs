@@ -13,7 +13,7 @@ pub struct StaffPm {
     pub resolved: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct StaffPmMessage {
     pub id: i64,
     pub staff_pm_id: i64,
@@ -16,7 +16,7 @@ use crate::models::{
     torrent_request::TorrentRequestHierarchyLite,
 };
 
-#[derive(Debug, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "content_type_enum")]
 pub enum ContentType {
     #[sqlx(rename = "movie")]
@@ -57,7 +57,7 @@ pub enum Platform {
 }
 
 // this is not to store the genre, but the format
-#[derive(Debug, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "title_group_category_enum")]
 pub enum TitleGroupCategory {
     //music
@@ -244,6 +244,14 @@ pub struct EditedTitleGroup {
     pub screenshots: Vec<String>,
 }
 
+// everything attached to the source title group is moved to the target one,
+// then the source title group is deleted
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TitleGroupMerge {
+    pub source_title_group_id: i32,
+    pub target_title_group_id: i32,
+}
+
 pub fn create_default_title_group() -> UserCreatedTitleGroup {
     UserCreatedTitleGroup {
         name: String::from(""),
@@ -9,9 +9,9 @@ use sqlx::{prelude::FromRow, types::Json};
 use strum::{Display, EnumString};
 use utoipa::{IntoParams, ToSchema};
 
-use super::{torrent_report::TorrentReport, user::UserLite};
+use super::{torrent_log::TorrentLogLite, torrent_report::TorrentReport, user::UserLite};
 
-#[derive(Debug, Deserialize, Serialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "audio_codec_enum")]
 pub enum AudioCodec {
     #[sqlx(rename = "mp2")]
@@ -46,7 +46,7 @@ pub enum AudioCodec {
     Dsd,
 }
 
-#[derive(Debug, Deserialize, Serialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "audio_channels_enum")]
 pub enum AudioChannels {
     #[sqlx(rename = "1.0")]
@@ -69,7 +69,7 @@ pub enum AudioChannels {
     SevenDotOne,
 }
 
-#[derive(Debug, Deserialize, Serialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "audio_bitrate_sampling_enum")]
 pub enum AudioBitrateSampling {
     #[sqlx(rename = "64")]
@@ -121,7 +121,7 @@ pub enum AudioBitrateSampling {
     Other,
 }
 
-#[derive(Debug, Deserialize, Serialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "video_codec_enum")]
 pub enum VideoCodec {
     #[sqlx(rename = "mpeg1")]
@@ -150,7 +150,9 @@ pub enum VideoCodec {
     UHD100,
 }
 
-#[derive(Debug, Deserialize, Serialize, sqlx::Type, ToSchema, EnumString)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, sqlx::Type, ToSchema, EnumString,
+)]
 #[sqlx(type_name = "language_enum")]
 pub enum Language {
     Albanian,
@@ -207,7 +209,7 @@ pub enum Language {
     Other,
 }
 
-#[derive(Debug, Deserialize, Serialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "video_resolution_enum")]
 pub enum VideoResolution {
     Other,
@@ -243,7 +245,7 @@ pub enum VideoResolution {
     P4320,
 }
 
-#[derive(Debug, Deserialize, Serialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "features_enum")]
 pub enum Features {
     #[sqlx(rename = "HDR")]
@@ -269,7 +271,7 @@ pub enum Features {
     Cue,
 }
 
-#[derive(Debug, Deserialize, Serialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Deserialize, Serialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "extras_enum", rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 pub enum Extras {
@@ -309,6 +311,7 @@ impl FromStr for Features {
 pub struct Torrent {
     pub id: i32,
     pub info_hash: InfoHash,
+    pub info_hash_v2: Option<InfoHash>, // truncated, only for v2 and hybrid torrents
     pub upload_factor: i16,
     pub download_factor: i16,
     pub seeders: i64,
@@ -336,6 +339,8 @@ pub struct Torrent {
     pub mediainfo: Option<String>,
     pub trumpable: Option<String>, // description of why it is trumpable
     pub staff_checked: bool,
+    pub held_from_tracker: bool, // not announceable until approved by staff
+    pub log_score: Option<i16>,  // lowest score of the attached rip logs
     pub languages: Vec<Language>, // (fallback to original language) (english, french, etc.)
     pub container: String, // container of the main file (ex: if mkv movie and srt subs, mkv is the main)
     pub size: i64,         // in bytes
@@ -355,6 +360,13 @@ pub struct Torrent {
     pub video_resolution_other_y: Option<i32>,
 }
 
+// a .torrent file from another site, to find the matching torrent to cross-seed
+#[derive(Debug, MultipartForm, ToSchema)]
+pub struct CrossSeedTorrent {
+    #[schema(value_type = String, format = Binary, content_media_type = "application/octet-stream")]
+    pub torrent_file: Bytes,
+}
+
 #[derive(Debug, MultipartForm, FromRow, ToSchema)]
 pub struct UploadedTorrent {
     #[schema(value_type = String)]
@@ -371,6 +383,9 @@ pub struct UploadedTorrent {
     pub mediainfo: Option<Text<String>>,
     #[schema(value_type = String, format = Binary, content_media_type = "application/octet-stream")]
     pub torrent_file: Bytes,
+    // EAC/XLD rip logs, only accepted for CD sourced edition groups
+    #[schema(value_type = Vec<String>, format = Binary, content_media_type = "text/plain")]
+    pub log_files: Vec<Bytes>,
     #[schema(value_type = String)]
     pub languages: Text<String>,
     #[schema(value_type = String)]
@@ -426,6 +441,41 @@ pub struct EditedTorrent {
     pub video_resolution_other_y: Option<i32>,
 }
 
+// the torrent as it is, ex: to check it against the upload rules of another edition group
+impl From<&Torrent> for EditedTorrent {
+    fn from(torrent: &Torrent) -> Self {
+        Self {
+            id: torrent.id,
+            edition_group_id: torrent.edition_group_id,
+            extras: torrent.extras.clone(),
+            release_name: torrent.release_name.clone(),
+            release_group: torrent.release_group.clone(),
+            description: torrent.description.clone(),
+            uploaded_as_anonymous: torrent.uploaded_as_anonymous,
+            mediainfo: torrent.mediainfo.clone(),
+            container: torrent.container.clone(),
+            languages: torrent.languages.clone(),
+            duration: torrent.duration,
+            audio_codec: torrent.audio_codec,
+            audio_bitrate: torrent.audio_bitrate,
+            audio_bitrate_sampling: torrent.audio_bitrate_sampling,
+            audio_channels: torrent.audio_channels,
+            video_codec: torrent.video_codec,
+            features: torrent.features.clone(),
+            subtitle_languages: torrent.subtitle_languages.clone(),
+            video_resolution: torrent.video_resolution,
+            video_resolution_other_x: torrent.video_resolution_other_x,
+            video_resolution_other_y: torrent.video_resolution_other_y,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TorrentToMove {
+    pub torrent_id: i32,
+    pub edition_group_id: i32,
+}
+
 #[derive(Debug, Deserialize, Serialize, ToSchema, Display)]
 pub enum TorrentSearchOrderByColumn {
     #[serde(rename = "torrent_created_at")]
@@ -460,6 +510,7 @@ pub struct TorrentSearch {
     // torrent fields
     pub torrent_reported: Option<bool>,
     pub torrent_staff_checked: Option<bool>,
+    pub torrent_log_score_min: Option<i16>,
     pub torrent_created_by_id: Option<i32>,
     pub torrent_snatched_by_id: Option<i32>,
     // link to other tables
@@ -489,6 +540,7 @@ pub struct TorrentHierarchyLite {
     pub release_group: Option<String>,
     pub trumpable: Option<String>,
     pub staff_checked: bool,
+    pub log_score: Option<i16>,
     pub languages: Vec<Language>,
     pub container: String,
     pub size: i64,
@@ -546,6 +598,7 @@ pub struct TorrentHierarchy {
     pub mediainfo: Option<String>,
     pub trumpable: Option<String>,
     pub staff_checked: bool,
+    pub log_score: Option<i16>,
     pub languages: Vec<Language>,
     pub container: String,
     pub size: i64,
@@ -561,6 +614,7 @@ pub struct TorrentHierarchy {
     pub video_resolution_other_x: Option<i32>,
     pub video_resolution_other_y: Option<i32>,
     pub reports: Vec<TorrentReport>,
+    pub logs: Vec<TorrentLogLite>,
     // pub peer_status: Option<TorrentStatus>,
 }
 
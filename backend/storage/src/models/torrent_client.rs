@@ -0,0 +1,53 @@
+use arcadia_shared::tracker::models::torrent_client::TorrentClientRule;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TorrentClient {
+    pub id: i32,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_at: DateTime<Utc>,
+    pub created_by_id: i32,
+    pub name: String,
+    pub rule: TorrentClientRule,
+    pub peer_id_prefix: String,
+    pub min_version: Option<String>,
+    pub max_version: Option<String>,
+    pub user_agent_pattern: Option<String>,
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserCreatedTorrentClient {
+    pub name: String,
+    pub rule: TorrentClientRule,
+    pub peer_id_prefix: String,
+    pub min_version: Option<String>,
+    pub max_version: Option<String>,
+    pub user_agent_pattern: Option<String>,
+    pub description: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EditedTorrentClient {
+    pub id: i32,
+    pub name: String,
+    pub rule: TorrentClientRule,
+    pub peer_id_prefix: String,
+    pub min_version: Option<String>,
+    pub max_version: Option<String>,
+    pub user_agent_pattern: Option<String>,
+    pub description: String,
+}
+
+// user agents currently announcing to the tracker, to help staff maintain the whitelist
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct SeenTorrentClient {
+    pub agent: String,
+    pub peers_amount: i64,
+    pub users_amount: i64,
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use sqlx::{prelude::FromRow, types::Json};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[sqlx(type_name = "log_ripper_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum LogRipper {
+    Eac,
+    Xld,
+    Unknown,
+}
+
+// EAC checksums are verified, XLD signatures are only flagged as unverified
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[sqlx(type_name = "log_checksum_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum LogChecksum {
+    Missing,
+    Unverified,
+    Valid,
+    Invalid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct LogDeduction {
+    pub reason: String,
+    pub points: i16,
+}
+
+// result of the log checker, before it is attached to a torrent
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CheckedLog {
+    pub ripper: LogRipper,
+    pub log: String,
+    pub score: i16, // out of 100
+    pub checksum: LogChecksum,
+    pub deductions: Vec<LogDeduction>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TorrentLog {
+    pub id: i64,
+    pub torrent_id: i32,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Local>,
+    pub ripper: LogRipper,
+    pub log: String,
+    pub score: i16,
+    pub checksum: LogChecksum,
+    #[schema(value_type = Vec<LogDeduction>)]
+    pub deductions: Json<Vec<LogDeduction>>,
+}
+
+// what is shown in the torrent hierarchy, without the log itself
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TorrentLogLite {
+    pub id: i64,
+    pub ripper: LogRipper,
+    pub score: i16,
+    pub checksum: LogChecksum,
+    pub deductions: Vec<LogDeduction>,
+}
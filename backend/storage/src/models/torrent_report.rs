@@ -1,7 +1,32 @@
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+
+use super::torrent::EditedTorrent;
+
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema, sqlx::Type,
+)]
+#[sqlx(type_name = "torrent_report_category_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TorrentReportCategory {
+    Dupe,
+    Trump,
+    BadMetadata,
+    RulesBroken,
+    #[default]
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[sqlx(type_name = "torrent_report_outcome_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TorrentReportOutcome {
+    Deleted,
+    Edited,
+    Dismissed,
+}
 
 #[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct TorrentReport {
@@ -11,10 +36,73 @@ pub struct TorrentReport {
     pub reported_by_id: i32,
     pub reported_torrent_id: i32,
     pub description: String,
+    pub category: TorrentReportCategory,
+    pub claimed_by_id: Option<i32>,
+    #[schema(value_type = String, format = DateTime)]
+    pub claimed_at: Option<DateTime<Local>>,
+    pub resolved_by_id: Option<i32>,
+    #[schema(value_type = String, format = DateTime)]
+    pub resolved_at: Option<DateTime<Local>>,
+    pub outcome: Option<TorrentReportOutcome>,
+    pub resolution_comment: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UserCreatedTorrentReport {
     pub reported_torrent_id: i32,
     pub description: String,
+    #[serde(default)]
+    pub category: TorrentReportCategory,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TorrentReportStatus {
+    Open, // not claimed yet
+    Claimed,
+    Resolved,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct TorrentReportInboxQuery {
+    pub category: Option<TorrentReportCategory>,
+    pub status: Option<TorrentReportStatus>,
+    pub claimed_by_id: Option<i32>,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+// a report as listed in the staff inbox
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TorrentReportInboxEntry {
+    pub id: i64,
+    #[schema(value_type = String, format = DateTime)]
+    pub reported_at: DateTime<Local>,
+    pub reported_by_id: i32,
+    pub reported_by_username: String,
+    pub reported_torrent_id: i32,
+    pub title_group_id: i32,
+    pub title_group_name: String,
+    pub description: String,
+    pub category: TorrentReportCategory,
+    pub claimed_by_id: Option<i32>,
+    pub claimed_by_username: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub resolved_at: Option<DateTime<Local>>,
+    pub outcome: Option<TorrentReportOutcome>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TorrentReportToClaim {
+    pub report_id: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TorrentReportResolution {
+    pub report_id: i64,
+    pub outcome: TorrentReportOutcome,
+    // sent to the reporter, and used as the deletion reason
+    pub comment: String,
+    // required when the outcome is "edited"
+    pub edited_torrent: Option<EditedTorrent>,
 }
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema, sqlx::Type)]
+#[sqlx(type_name = "torrent_review_decision_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TorrentReviewDecision {
+    Approved,
+    ChangesRequested,
+    Rejected,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TorrentReview {
+    pub id: i64,
+    pub torrent_id: i32,
+    pub reviewed_by_id: i32,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Local>,
+    pub decision: TorrentReviewDecision,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserCreatedTorrentReview {
+    pub torrent_id: i32,
+    pub decision: TorrentReviewDecision,
+    pub reason: Option<String>,
+}
+
+// an upload waiting to be checked by staff
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TorrentToReview {
+    pub id: i32,
+    pub title_group_id: i32,
+    pub title_group_name: String,
+    pub release_name: Option<String>,
+    pub created_by_id: i32,
+    // the uploader's account may have been deleted
+    pub created_by_username: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Local>,
+    pub held_from_tracker: bool,
+    // set if the uploader was asked for changes and the torrent wasn't approved since
+    pub last_decision: Option<TorrentReviewDecision>,
+    pub last_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct TorrentReviewQueueQuery {
+    pub held_only: Option<bool>,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TorrentReviewerStats {
+    pub reviewer_id: i32,
+    pub reviewer_username: String,
+    pub approved: i64,
+    pub changes_requested: i64,
+    pub rejected: i64,
+    #[schema(value_type = String, format = DateTime)]
+    pub last_reviewed_at: DateTime<Local>,
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use utoipa::ToSchema;
+
+use super::{
+    title_group::ContentType,
+    torrent::{AudioBitrateSampling, AudioCodec, VideoCodec, VideoResolution},
+};
+
+// rankings are ordered from worst to best, values missing from a ranking are not compared
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TrumpRule {
+    pub content_type: ContentType,
+    pub audio_bitrate_sampling_ranking: Vec<AudioBitrateSampling>,
+    pub audio_codec_ranking: Vec<AudioCodec>,
+    pub video_resolution_ranking: Vec<VideoResolution>,
+    pub video_codec_ranking: Vec<VideoCodec>,
+    pub compare_log_score: bool,
+}
+
+// the fields of a torrent the trump rules are applied to
+#[derive(Debug, Default, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TorrentQuality {
+    pub id: i32,
+    pub audio_bitrate_sampling: Option<AudioBitrateSampling>,
+    pub audio_codec: Option<AudioCodec>,
+    pub video_resolution: Option<VideoResolution>,
+    pub video_codec: Option<VideoCodec>,
+    pub log_score: Option<i16>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TorrentToTrump {
+    pub trumped_torrent_id: i32,
+    pub trumping_torrent_id: i32,
+    pub reason: String,
+}
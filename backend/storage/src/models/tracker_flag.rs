@@ -0,0 +1,28 @@
+use arcadia_shared::tracker::models::tracker_flag_update::TrackerFlagKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{prelude::FromRow, types::ipnetwork::IpNetwork};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TrackerFlag {
+    pub id: i64,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+    pub kind: TrackerFlagKind,
+    pub user_id: i32,
+    pub torrent_id: i32,
+    // hex encoded
+    pub peer_id: String,
+    #[schema(value_type = String, format = "0.0.0.0")]
+    pub ip: IpNetwork,
+    pub agent: String,
+    pub uploaded_delta: i64,
+    pub downloaded_delta: i64,
+    pub announce_interval: Option<i64>,
+    pub occurrences: i32,
+    pub credit_withheld: bool,
+    pub resolved_by_id: Option<i32>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub resolved_at: Option<DateTime<Utc>>,
+}
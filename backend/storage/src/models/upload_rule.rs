@@ -0,0 +1,36 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use utoipa::ToSchema;
+
+use super::title_group::{ContentType, TitleGroupCategory};
+
+// empty lists and missing values are not checked
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct UploadRule {
+    pub id: i32,
+    pub content_type: ContentType,
+    pub category: Option<TitleGroupCategory>, // applies to every category if not set
+    pub allowed_file_extensions: Vec<String>, // lowercase, without the leading dot
+    pub allowed_containers: Vec<String>,
+    pub required_fields: Vec<String>, // names of the upload form fields
+    pub min_piece_length: Option<i64>, // in bytes
+    pub max_piece_length: Option<i64>, // in bytes
+    pub release_name_pattern: Option<String>, // regex
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Local>,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_at: DateTime<Local>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserCreatedUploadRule {
+    pub content_type: ContentType,
+    pub category: Option<TitleGroupCategory>,
+    pub allowed_file_extensions: Vec<String>,
+    pub allowed_containers: Vec<String>,
+    pub required_fields: Vec<String>,
+    pub min_piece_length: Option<i64>,
+    pub max_piece_length: Option<i64>,
+    pub release_name_pattern: Option<String>,
+}
@@ -92,6 +92,18 @@ pub enum UserClass {
     Tracker,
 }
 
+impl UserClass {
+    // classes are compared with their rank, which must match user_class_rank() in the database.
+    // The tracker class is the tracker's own account, it ranks below everyone
+    pub fn rank(&self) -> u8 {
+        match self {
+            UserClass::Tracker => 0,
+            UserClass::Newbie => 1,
+            UserClass::Staff => 2,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct Register {
     pub username: String,
@@ -8,6 +8,8 @@ pub enum RedisError {
     ConnectionError(#[source] PoolError<redis::RedisError>),
     #[error("cmd execution error: '{0}'")]
     CmdError(#[source] redis::RedisError),
+    #[error("subscribe error")]
+    SubscribeError(#[source] redis::RedisError),
 }
 
 impl From<RedisError> for CommonError {
@@ -2,29 +2,59 @@ pub mod error;
 
 use self::error::{RedisError, Result};
 use deadpool_redis::{Config, Connection, Pool, Runtime};
-use redis::{cmd, ToRedisArgs};
+use futures_util::{future::ready, Stream, StreamExt};
+use redis::{cmd, Client, ToRedisArgs};
 
 pub trait RedisPoolInterface {
     fn connection(&self) -> impl Future<Output = Result<impl RedisInterface>> + Send;
+
+    /// Stream of the payloads published on a channel, pub/sub needs its own connection
+    fn subscribe(
+        &self,
+        channel: &str,
+    ) -> impl Future<Output = Result<impl Stream<Item = String> + Send + Unpin + 'static>> + Send;
 }
 
-pub struct RedisPool(Pool);
+pub struct RedisPool {
+    pool: Pool,
+    client: Client,
+}
 
 impl RedisPool {
     pub fn new(redis_host: &str, password: &str, port: u16) -> Self {
         let conn_string = format!("redis://:{}@{}:{}", password, redis_host, port);
+        let client = Client::open(conn_string.as_str()).unwrap();
         let config = Config::from_url(conn_string);
         let pool = config.create_pool(Some(Runtime::Tokio1)).unwrap();
 
-        Self(pool)
+        Self { pool, client }
     }
 }
 
 impl RedisPoolInterface for RedisPool {
     async fn connection(&self) -> Result<impl RedisInterface> {
-        let conn = self.0.get().await.map_err(RedisError::ConnectionError)?;
+        let conn = self.pool.get().await.map_err(RedisError::ConnectionError)?;
         Ok(Redis::new(conn))
     }
+
+    async fn subscribe(
+        &self,
+        channel: &str,
+    ) -> Result<impl Stream<Item = String> + Send + Unpin + 'static> {
+        let mut pubsub = self
+            .client
+            .get_async_pubsub()
+            .await
+            .map_err(RedisError::SubscribeError)?;
+        pubsub
+            .subscribe(channel)
+            .await
+            .map_err(RedisError::SubscribeError)?;
+
+        Ok(pubsub
+            .into_on_message()
+            .filter_map(|message| ready(message.get_payload::<String>().ok())))
+    }
 }
 
 pub trait RedisInterface {
@@ -49,6 +79,11 @@ pub trait RedisInterface {
     ) -> impl Future<Output = Result<Option<String>>> + Send;
 
     fn delete<K: ToRedisArgs + Send>(&mut self, key: K) -> impl Future<Output = Result<()>> + Send;
+
+    fn publish<C, M>(&mut self, channel: C, message: M) -> impl Future<Output = Result<()>> + Send
+    where
+        C: ToRedisArgs + Send,
+        M: ToRedisArgs + Send;
 }
 
 pub struct Redis(Connection);
@@ -102,4 +137,17 @@ impl RedisInterface for Redis {
             .await
             .map_err(RedisError::CmdError)
     }
+
+    async fn publish<C, M>(&mut self, channel: C, message: M) -> Result<()>
+    where
+        C: ToRedisArgs + Send,
+        M: ToRedisArgs + Send,
+    {
+        cmd("PUBLISH")
+            .arg(channel)
+            .arg(message)
+            .query_async(&mut self.0)
+            .await
+            .map_err(RedisError::CmdError)
+    }
 }
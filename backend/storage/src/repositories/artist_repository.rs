@@ -3,8 +3,9 @@ use crate::{
     models::{
         artist::{
             AffiliatedArtist, AffiliatedArtistHierarchy, Artist, ArtistAndTitleGroupsLite,
-            ArtistLite, UserCreatedAffiliatedArtist, UserCreatedArtist,
+            ArtistLite, EditedArtist, UserCreatedAffiliatedArtist, UserCreatedArtist,
         },
+        revision::RevisionResourceType,
         torrent::{TorrentSearch, TorrentSearchOrderByColumn, TorrentSearchOrderByDirection},
     },
 };
@@ -158,6 +159,7 @@ impl ConnectionPool {
             torrent_reported: None,
             torrent_snatched_by_id: None,
             torrent_staff_checked: None,
+            torrent_log_score_min: None,
             order_by_direction: TorrentSearchOrderByDirection::Desc,
             order_by_column: TorrentSearchOrderByColumn::TitleGroupOriginalReleaseDate,
             collage_id: None,
@@ -203,4 +205,69 @@ impl ConnectionPool {
 
         Ok(())
     }
+
+    pub async fn find_artist(&self, artist_id: i64) -> Result<Artist> {
+        let artist = sqlx::query_as!(
+            Artist,
+            r#"
+            SELECT *
+            FROM artists
+            WHERE id = $1
+            "#,
+            artist_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::ArtistNotFound(artist_id))?;
+
+        Ok(artist)
+    }
+
+    pub async fn update_artist(
+        &self,
+        edited_artist: &EditedArtist,
+        artist_id: i64,
+        edited_by_id: i32,
+    ) -> Result<Artist> {
+        let previous_artist = self.find_artist(artist_id).await?;
+
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let updated_artist = sqlx::query_as!(
+            Artist,
+            r#"
+            UPDATE artists
+            SET
+                name = $2,
+                description = $3,
+                pictures = $4
+            WHERE id = $1
+            RETURNING *
+            "#,
+            artist_id,
+            edited_artist.name,
+            edited_artist.description,
+            &edited_artist.pictures
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| Error::ErrorWhileUpdatingArtist(e.to_string()))?;
+
+        Self::record_revision(
+            &mut tx,
+            RevisionResourceType::Artist,
+            artist_id,
+            edited_by_id,
+            &previous_artist,
+            &updated_artist,
+            edited_artist,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(updated_artist)
+    }
 }
@@ -121,9 +121,26 @@ impl ConnectionPool {
             "#,
             api_key
         )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::InvalidAPIKeyOrBanned)?;
+
+        Ok(user)
+    }
+
+    pub async fn find_user_with_passkey(&self, passkey: &str) -> Result<User> {
+        let user = sqlx::query_as_unchecked!(
+            User,
+            r#"
+            SELECT *
+            FROM users
+            WHERE passkey = $1 AND banned = FALSE;
+            "#,
+            passkey
+        )
         .fetch_one(self.borrow())
         .await
-        .map_err(|_| Error::InvalidAPIKeyOrBanned)?;
+        .map_err(|_| Error::InvalidPasskeyOrBanned)?;
 
         Ok(user)
     }
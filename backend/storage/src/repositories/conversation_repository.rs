@@ -1,11 +1,16 @@
 use crate::{
     connection_pool::ConnectionPool,
-    models::conversation::{
-        Conversation, ConversationMessage, UserCreatedConversation, UserCreatedConversationMessage,
+    models::{
+        conversation::{
+            Conversation, ConversationMessage, UserCreatedConversation,
+            UserCreatedConversationMessage,
+        },
+        notification::NotificationType,
     },
 };
 use arcadia_common::error::{Error, Result};
-use serde_json::Value;
+use serde_json::{json, Value};
+use sqlx::PgPool;
 use std::borrow::Borrow;
 
 impl ConnectionPool {
@@ -42,6 +47,10 @@ impl ConnectionPool {
         message: &UserCreatedConversationMessage,
         current_user_id: i32,
     ) -> Result<ConversationMessage> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
         let message = sqlx::query_as!(
             ConversationMessage,
             r#"
@@ -53,13 +62,46 @@ impl ConnectionPool {
             current_user_id,
             message.content,
         )
-        .fetch_one(self.borrow())
+        .fetch_one(&mut *tx)
         .await
         .map_err(Error::CouldNotCreateConversation)?;
 
+        let participant_ids = sqlx::query!(
+            "SELECT sender_id, receiver_id FROM conversations WHERE id = $1",
+            message.conversation_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        Self::notify_users(
+            &mut tx,
+            NotificationType::ConversationMessage,
+            &[participant_ids.sender_id, participant_ids.receiver_id],
+            Some(current_user_id),
+            message.conversation_id,
+            json!({ "conversation_message_id": message.id }),
+        )
+        .await?;
+
+        tx.commit().await?;
+
         Ok(message)
     }
 
+    pub async fn find_conversation_participant_ids(
+        &self,
+        conversation_id: i64,
+    ) -> Result<Vec<i32>> {
+        let participants = sqlx::query!(
+            "SELECT sender_id, receiver_id FROM conversations WHERE id = $1",
+            conversation_id
+        )
+        .fetch_one(self.borrow())
+        .await?;
+
+        Ok(vec![participants.sender_id, participants.receiver_id])
+    }
+
     pub async fn find_user_conversations(&self, user_id: i32) -> Result<Value> {
         let conversations = sqlx::query!(
             r#"
@@ -1,8 +1,13 @@
 use crate::{
     connection_pool::ConnectionPool,
-    models::edition_group::{EditionGroup, UserCreatedEditionGroup},
+    models::{
+        edition_group::{EditedEditionGroup, EditionGroup, UserCreatedEditionGroup},
+        revision::RevisionResourceType,
+        site_log::SiteLogAction,
+    },
 };
 use arcadia_common::error::{Error, Result};
+use sqlx::PgPool;
 use std::borrow::Borrow;
 
 impl ConnectionPool {
@@ -34,4 +39,138 @@ impl ConnectionPool {
 
         Ok(created_edition_group)
     }
+
+    pub async fn find_edition_group(&self, edition_group_id: i32) -> Result<EditionGroup> {
+        let edition_group =
+            sqlx::query_as::<_, EditionGroup>("SELECT * FROM edition_groups WHERE id = $1")
+                .bind(edition_group_id)
+                .fetch_optional(self.borrow())
+                .await?
+                .ok_or(Error::EditionGroupNotFound(edition_group_id))?;
+
+        Ok(edition_group)
+    }
+
+    pub async fn update_edition_group(
+        &self,
+        edited_edition_group: &EditedEditionGroup,
+        edition_group_id: i32,
+        edited_by_id: i32,
+    ) -> Result<EditionGroup> {
+        const UPDATE_EDITION_GROUP_QUERY: &str = r#"
+            UPDATE edition_groups
+            SET
+                name = $2,
+                release_date = $3,
+                description = $4,
+                distributor = $5,
+                covers = $6,
+                external_links = $7,
+                source = $8::source_enum,
+                additional_information = $9,
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING *;
+        "#;
+
+        let previous_edition_group = self.find_edition_group(edition_group_id).await?;
+
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let updated_edition_group = sqlx::query_as::<_, EditionGroup>(UPDATE_EDITION_GROUP_QUERY)
+            .bind(edition_group_id)
+            .bind(&edited_edition_group.name)
+            .bind(edited_edition_group.release_date)
+            .bind(&edited_edition_group.description)
+            .bind(&edited_edition_group.distributor)
+            .bind(&edited_edition_group.covers)
+            .bind(&edited_edition_group.external_links)
+            .bind(&edited_edition_group.source)
+            .bind(&edited_edition_group.additional_information)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| Error::ErrorWhileUpdatingEditionGroup(e.to_string()))?;
+
+        Self::record_revision(
+            &mut tx,
+            RevisionResourceType::EditionGroup,
+            edition_group_id as i64,
+            edited_by_id,
+            &previous_edition_group,
+            &updated_edition_group,
+            edited_edition_group,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(updated_edition_group)
+    }
+
+    pub async fn split_edition_group(
+        &self,
+        edition_group: &EditionGroup,
+        torrent_ids: &[i32],
+        performed_by_id: i32,
+    ) -> Result<EditionGroup> {
+        const COPY_EDITION_GROUP_QUERY: &str = r#"
+            INSERT INTO edition_groups (title_group_id, name, release_date, created_by_id, description, distributor, covers, external_links, source, additional_information)
+            SELECT title_group_id, name, release_date, $2, description, distributor, covers, external_links, source, additional_information
+            FROM edition_groups
+            WHERE id = $1
+            RETURNING *;
+        "#;
+
+        let mut torrent_ids = torrent_ids.to_vec();
+        torrent_ids.sort_unstable();
+        torrent_ids.dedup();
+
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let new_edition_group = sqlx::query_as::<_, EditionGroup>(COPY_EDITION_GROUP_QUERY)
+            .bind(edition_group.id)
+            .bind(performed_by_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(Error::CouldNotCreateEditionGroup)?;
+
+        let moved_torrents = sqlx::query!(
+            r#"
+            UPDATE torrents
+            SET edition_group_id = $3, updated_at = NOW()
+            WHERE id = ANY($1) AND edition_group_id = $2 AND deleted_at IS NULL
+            "#,
+            &torrent_ids,
+            edition_group.id,
+            new_edition_group.id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if moved_torrents != torrent_ids.len() as u64 {
+            return Err(Error::BadRequest(
+                "all the torrents must belong to the edition group being split".into(),
+            ));
+        }
+
+        Self::create_site_log(
+            &mut tx,
+            SiteLogAction::EditionGroupSplit,
+            performed_by_id,
+            &format!(
+                "Edition group {} of title group {} was split, torrents {:?} were moved to the new edition group {}",
+                edition_group.id, edition_group.title_group_id, torrent_ids, new_edition_group.id
+            ),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(new_edition_group)
+    }
 }
@@ -0,0 +1,120 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::{
+        feed::{FeedTorrent, TorrentFeedFilter},
+        title_group::{ContentType, TitleGroupCategory},
+    },
+};
+use arcadia_common::error::Result;
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub async fn find_feed_torrents(&self, filter: &TorrentFeedFilter) -> Result<Vec<FeedTorrent>> {
+        let (content_types, categories): (Vec<ContentType>, Vec<Option<TitleGroupCategory>>) =
+            filter.content_types.iter().copied().unzip();
+
+        // torrents held from the tracker are not listed, they can't be downloaded yet
+        let torrents = sqlx::query_as!(
+            FeedTorrent,
+            r#"
+            SELECT
+                t.id,
+                tg.id AS title_group_id,
+                tg.name AS title_group_name,
+                eg.name AS edition_group_name,
+                t.release_name,
+                t.release_group,
+                t.size,
+                t.created_at,
+                t.seeders,
+                t.leechers,
+                t.times_completed,
+                t.upload_factor,
+                t.download_factor,
+                tg.content_type AS "content_type: _",
+                tg.category AS "category: _",
+                tg.tags,
+                tg.external_links
+            FROM torrents t
+            JOIN edition_groups eg ON eg.id = t.edition_group_id
+            JOIN title_groups tg ON tg.id = eg.title_group_id
+            WHERE t.deleted_at IS NULL AND NOT t.held_from_tracker
+            AND (
+                $1::INT IS NULL OR
+                tg.id IN (SELECT title_group_id FROM subscriptions_title_group_torrents WHERE user_id = $1)
+            )
+            AND (
+                $2::INT IS NULL OR
+                tg.id IN (SELECT title_group_id FROM title_group_bookmarks WHERE user_id = $2)
+            )
+            AND ($3::TEXT IS NULL OR tg.name ILIKE '%' || $3 || '%')
+            AND ($4::BOOLEAN IS NULL OR t.staff_checked = $4)
+            AND ($5::SMALLINT IS NULL OR t.log_score >= $5)
+            -- don't reveal anonymous uploads
+            AND ($6::INT IS NULL OR (t.created_by_id = $6 AND NOT t.uploaded_as_anonymous))
+            AND (
+                $7::BIGINT IS NULL OR
+                EXISTS (SELECT 1 FROM affiliated_artists aa WHERE aa.title_group_id = tg.id AND aa.artist_id = $7)
+            )
+            AND (
+                $8::BIGINT IS NULL OR
+                EXISTS (SELECT 1 FROM collage_entry ce WHERE ce.title_group_id = tg.id AND ce.collage_id = $8)
+            )
+            AND (
+                $9::TEXT IS NULL OR
+                EXISTS (
+                    SELECT 1 FROM affiliated_artists aa
+                    JOIN artists a ON a.id = aa.artist_id
+                    WHERE aa.title_group_id = tg.id AND a.name ILIKE '%' || $9 || '%'
+                )
+            )
+            AND ($10::TEXT IS NULL OR EXISTS (SELECT 1 FROM UNNEST(tg.external_links) link WHERE link ~* $10))
+            AND (
+                CARDINALITY($11::content_type_enum[]) = 0 OR
+                EXISTS (
+                    SELECT 1 FROM UNNEST($11::content_type_enum[], $12::title_group_category_enum[]) AS c(content_type, category)
+                    WHERE c.content_type = tg.content_type AND (c.category IS NULL OR c.category = tg.category)
+                )
+            )
+            AND (
+                $13::INT IS NULL OR
+                EXISTS (
+                    SELECT 1 FROM notification_filter_torrents m
+                    JOIN notification_filters f ON f.id = m.notification_filter_id
+                    WHERE m.torrent_id = t.id AND f.user_id = $13 AND f.include_in_feed
+                )
+            )
+            AND (
+                $14::BOOLEAN IS NULL OR
+                EXISTS (
+                    SELECT 1 FROM torrent_reports tr
+                    WHERE tr.reported_torrent_id = t.id AND tr.resolved_at IS NULL
+                ) = $14
+            )
+            ORDER BY t.created_at DESC
+            OFFSET $15
+            LIMIT $16
+            "#,
+            filter.subscribed_by_id,
+            filter.bookmarked_by_id,
+            filter.title_group_name,
+            filter.staff_checked,
+            filter.log_score_min,
+            filter.created_by_id,
+            filter.artist_id,
+            filter.collage_id,
+            filter.artist_name,
+            filter.external_link_regex,
+            &content_types as _,
+            &categories as _,
+            filter.notified_user_id,
+            filter.reported,
+            filter.offset,
+            filter.limit
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(torrents)
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::forum::{ForumPoll, ForumPollOption, UserCreatedForumPoll, UserCreatedForumPollVote},
+};
+use arcadia_common::error::{Error, Result};
+use chrono::Utc;
+use sqlx::{PgPool, Postgres, Transaction};
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub(crate) async fn create_forum_poll(
+        tx: &mut Transaction<'_, Postgres>,
+        forum_thread_id: i64,
+        poll: &UserCreatedForumPoll,
+    ) -> Result<()> {
+        let forum_poll_id = sqlx::query_scalar!(
+            r#"
+            INSERT INTO forum_polls (forum_thread_id, question, multiple_choice, anonymous, closes_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+            forum_thread_id,
+            poll.question,
+            poll.multiple_choice,
+            poll.anonymous,
+            poll.closes_at
+        )
+        .fetch_one(&mut **tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO forum_poll_options (forum_poll_id, label)
+            SELECT $1, label FROM UNNEST($2::TEXT[]) WITH ORDINALITY AS o(label, position)
+            ORDER BY position
+            "#,
+            forum_poll_id,
+            &poll.options
+        )
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_forum_poll(
+        &self,
+        forum_thread_id: i64,
+        user_id: i32,
+    ) -> Result<Option<ForumPoll>> {
+        let Some(poll) = sqlx::query!(
+            r#"
+            SELECT
+                p.id,
+                p.forum_thread_id,
+                p.question,
+                p.multiple_choice,
+                p.anonymous,
+                p.closes_at,
+                p.created_at,
+                ARRAY(
+                    SELECT forum_poll_option_id FROM forum_poll_votes
+                    WHERE forum_poll_id = p.id AND user_id = $2
+                ) AS "voted_option_ids!",
+                (SELECT COUNT(DISTINCT user_id) FROM forum_poll_votes WHERE forum_poll_id = p.id)
+                    AS "voters_amount!"
+            FROM forum_polls p
+            WHERE p.forum_thread_id = $1
+            "#,
+            forum_thread_id,
+            user_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let is_closed = poll
+            .closes_at
+            .is_some_and(|closes_at| closes_at <= Utc::now());
+        let results_visible = is_closed || !poll.voted_option_ids.is_empty();
+
+        let options = sqlx::query_as!(
+            ForumPollOption,
+            r#"
+            SELECT
+                o.id,
+                o.label,
+                CASE WHEN $2 THEN COUNT(v.user_id) END AS votes_amount,
+                CASE WHEN $2 AND NOT $3 THEN
+                    COALESCE(
+                        jsonb_agg(
+                            jsonb_build_object(
+                                'id', u.id,
+                                'username', u.username,
+                                'warned', u.warned,
+                                'banned', u.banned
+                            ) ORDER BY v.created_at
+                        ) FILTER (WHERE u.id IS NOT NULL),
+                        '[]'::jsonb
+                    )
+                END AS "voters: _"
+            FROM forum_poll_options o
+            LEFT JOIN forum_poll_votes v ON v.forum_poll_option_id = o.id
+            LEFT JOIN users u ON u.id = v.user_id
+            WHERE o.forum_poll_id = $1
+            GROUP BY o.id
+            ORDER BY o.id
+            "#,
+            poll.id,
+            results_visible,
+            poll.anonymous
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(Some(ForumPoll {
+            id: poll.id,
+            forum_thread_id: poll.forum_thread_id,
+            question: poll.question,
+            multiple_choice: poll.multiple_choice,
+            anonymous: poll.anonymous,
+            closes_at: poll.closes_at,
+            created_at: poll.created_at,
+            is_closed,
+            voted_option_ids: poll.voted_option_ids,
+            results_visible,
+            voters_amount: results_visible.then_some(poll.voters_amount),
+            options,
+        }))
+    }
+
+    pub async fn find_forum_poll_thread_id(&self, forum_poll_id: i64) -> Result<i64> {
+        sqlx::query_scalar!(
+            "SELECT forum_thread_id FROM forum_polls WHERE id = $1",
+            forum_poll_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::ForumPollNotFound(forum_poll_id))
+    }
+
+    pub async fn create_forum_poll_vote(
+        &self,
+        vote: &UserCreatedForumPollVote,
+        current_user_id: i32,
+    ) -> Result<()> {
+        let mut option_ids = vote.option_ids.clone();
+        option_ids.sort_unstable();
+        option_ids.dedup();
+
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let poll = sqlx::query!(
+            "SELECT multiple_choice, closes_at FROM forum_polls WHERE id = $1 FOR UPDATE",
+            vote.forum_poll_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumPollNotFound(vote.forum_poll_id))?;
+
+        if poll
+            .closes_at
+            .is_some_and(|closes_at| closes_at <= Utc::now())
+        {
+            return Err(Error::ForumPollClosed);
+        }
+        if option_ids.is_empty() {
+            return Err(Error::BadRequest(
+                "at least one option must be chosen".to_string(),
+            ));
+        }
+        if !poll.multiple_choice && option_ids.len() > 1 {
+            return Err(Error::BadRequest(
+                "only one option can be chosen in this poll".to_string(),
+            ));
+        }
+
+        let already_voted = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM forum_poll_votes WHERE forum_poll_id = $1 AND user_id = $2
+            ) AS "exists!"
+            "#,
+            vote.forum_poll_id,
+            current_user_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if already_voted {
+            return Err(Error::ForumPollAlreadyVoted);
+        }
+
+        let inserted = sqlx::query!(
+            r#"
+            INSERT INTO forum_poll_votes (forum_poll_id, forum_poll_option_id, user_id)
+            SELECT forum_poll_id, id, $3
+            FROM forum_poll_options
+            WHERE forum_poll_id = $1 AND id = ANY($2)
+            "#,
+            vote.forum_poll_id,
+            &option_ids,
+            current_user_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if inserted != option_ids.len() as u64 {
+            return Err(Error::BadRequest(
+                "some options do not belong to this poll".to_string(),
+            ));
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -3,17 +3,22 @@ use crate::{
     models::{
         common::PaginatedResults,
         forum::{
-            ForumPost, ForumPostAndThreadName, ForumPostHierarchy, ForumSearchQuery,
-            ForumSearchResult, ForumThread, ForumThreadEnriched, GetForumThreadPostsQuery,
-            UserCreatedForumPost, UserCreatedForumThread,
+            EditedForumCategory, EditedForumPost, EditedForumSubCategory, EditedForumThread,
+            ForumCategory, ForumPost, ForumPostAndThreadName, ForumPostEdit, ForumPostHierarchy,
+            ForumSearchQuery, ForumSearchResult, ForumSubCategory, ForumThread,
+            ForumThreadEnriched, ForumThreadSplit, ForumThreadsMerge, GetForumThreadPostsQuery,
+            UserCreatedForumCategory, UserCreatedForumPost, UserCreatedForumSubCategory,
+            UserCreatedForumThread,
         },
-        user::UserLiteAvatar,
+        notification::NotificationType,
+        site_log::SiteLogAction,
+        user::{UserClass, UserLiteAvatar},
     },
 };
 use arcadia_common::error::{Error, Result};
 use chrono::{DateTime, Utc};
 use serde_json::{json, Value};
-use sqlx::{prelude::FromRow, PgPool};
+use sqlx::{prelude::FromRow, types::Json, PgPool, Postgres, Transaction};
 use std::borrow::Borrow;
 
 impl ConnectionPool {
@@ -65,6 +70,14 @@ impl ConnectionPool {
         .await
         .map_err(Error::CouldNotCreateForumPost)?;
 
+        sqlx::query!(
+            "UPDATE users SET forum_posts = forum_posts + 1 WHERE id = $1",
+            current_user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(Error::CouldNotCreateForumPost)?;
+
         Self::notify_users_forum_thread_posts(
             &mut tx,
             forum_post.forum_thread_id,
@@ -73,6 +86,38 @@ impl ConnectionPool {
         )
         .await?;
 
+        // users are mentioned with @username and quoted with [quote=username],
+        // only the ones who can read the thread are notified
+        for (notification_type, pattern) in [
+            (NotificationType::ForumPostMention, r"@([\w.-]+)"),
+            (NotificationType::ForumPostQuote, r"\[quote=([^\]]+)\]"),
+        ] {
+            let user_ids = sqlx::query_scalar!(
+                r#"
+                SELECT u.id FROM users u
+                JOIN forum_threads t ON t.id = $3
+                JOIN forum_sub_categories fsc ON fsc.id = t.forum_sub_category_id
+                WHERE u.username IN (SELECT (REGEXP_MATCHES($1, $2, 'g'))[1])
+                AND user_class_rank(u.class) >= user_class_rank(fsc.min_class_read)
+                "#,
+                created_forum_post.content,
+                pattern,
+                created_forum_post.forum_thread_id
+            )
+            .fetch_all(&mut *tx)
+            .await?;
+
+            Self::notify_users(
+                &mut tx,
+                notification_type,
+                &user_ids,
+                Some(current_user_id),
+                created_forum_post.id,
+                json!({ "forum_thread_id": created_forum_post.forum_thread_id }),
+            )
+            .await?;
+        }
+
         tx.commit().await?;
 
         Ok(created_forum_post)
@@ -116,6 +161,18 @@ impl ConnectionPool {
         .await
         .map_err(Error::CouldNotCreateForumPost)?;
 
+        sqlx::query!(
+            "UPDATE users SET forum_threads = forum_threads + 1 WHERE id = $1",
+            current_user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(Error::CouldNotCreateForumThread)?;
+
+        if let Some(poll) = &forum_thread.poll {
+            Self::create_forum_poll(&mut tx, created_forum_thread.id, poll).await?;
+        }
+
         tx.commit().await?;
 
         // TODO: include this in the transaction
@@ -125,7 +182,7 @@ impl ConnectionPool {
         Ok(created_forum_thread)
     }
 
-    pub async fn find_forum_cateogries_hierarchy(&self) -> Result<Value> {
+    pub async fn find_forum_cateogries_hierarchy(&self, user_class: &UserClass) -> Result<Value> {
         let forum_overview = sqlx::query!(
             r#"
             SELECT
@@ -142,7 +199,8 @@ impl ConnectionPool {
                                             'name', fsc.name,
                                             'threads_amount', fsc.threads_amount,
                                             'posts_amount', fsc.posts_amount,
-                                            'forbidden_classes', '[]'::jsonb,
+                                            'min_class_post', fsc.min_class_post,
+                                            'min_class_create_thread', fsc.min_class_create_thread,
                                             'latest_post_in_thread', CASE
                                                 WHEN ft.id IS NOT NULL THEN json_build_object(
                                                     'id', ft.id,
@@ -156,7 +214,7 @@ impl ConnectionPool {
                                                 )
                                                 ELSE NULL
                                             END
-                                        ) ORDER BY fsc.name
+                                        ) ORDER BY fsc.sort_order, fsc.name
                                     )
                                 FROM
                                     forum_sub_categories fsc
@@ -176,19 +234,22 @@ impl ConnectionPool {
                                         users u ON fp_latest.created_by_id = u.id -- Joined with the users table
                                     WHERE
                                         ft_with_latest_post.forum_sub_category_id = fsc.id
+                                        AND fp_latest.deleted_at IS NULL
                                     ORDER BY
                                         fp_latest.created_at DESC
                                     LIMIT 1
                                 ) AS ft ON TRUE
                                 WHERE
                                     fsc.forum_category_id = fc.id
+                                    AND user_class_rank(fsc.min_class_read) <= user_class_rank($1)
                             )
-                        ) ORDER BY fc.id
+                        ) ORDER BY fc.sort_order, fc.id
                     )
                 ) AS forum_overview
             FROM
                 forum_categories fc;
-            "#
+            "#,
+            user_class as _
         )
         .fetch_one(self.borrow())
         .await
@@ -205,6 +266,8 @@ impl ConnectionPool {
     pub async fn find_forum_sub_category_threads(
         &self,
         forum_sub_category_id: i32,
+        user_id: i32,
+        posts_page_size: u32,
     ) -> Result<Value> {
         let forum_sub_category = sqlx::query!(
             r#"
@@ -215,7 +278,10 @@ impl ConnectionPool {
                         'name', fsc.name,
                         'threads_amount', fsc.threads_amount,
                         'posts_amount', fsc.posts_amount,
-                        'forbidden_classes', fsc.forbidden_classes,
+                        'sort_order', fsc.sort_order,
+                        'min_class_read', fsc.min_class_read,
+                        'min_class_post', fsc.min_class_post,
+                        'min_class_create_thread', fsc.min_class_create_thread,
                         'category', json_build_object(
                             'id', fc.id,
                             'name', fc.name
@@ -229,6 +295,11 @@ impl ConnectionPool {
                                             'name', ft.name,
                                             'created_at', ft.created_at,
                                             'posts_amount', ft.posts_amount,
+                                            'sticky', ft.sticky,
+                                            'locked', ft.locked,
+                                            'has_unread', fp_unread.first_unread_post_id IS NOT NULL,
+                                            'first_unread_post_id', fp_unread.first_unread_post_id,
+                                            'first_unread_page', fp_unread.read_posts_amount / $3 + 1,
                                             'latest_post', CASE
                                                 WHEN fp_latest.id IS NOT NULL THEN json_build_object(
                                                     'id', fp_latest.id,
@@ -240,7 +311,7 @@ impl ConnectionPool {
                                                 )
                                                 ELSE NULL
                                             END
-                                        ) ORDER BY ft.created_at DESC
+                                        ) ORDER BY ft.sticky DESC, ft.created_at DESC
                                     ),
                                     '[]'::json
                                 )
@@ -255,12 +326,30 @@ impl ConnectionPool {
                                     forum_posts fp
                                 WHERE
                                     fp.forum_thread_id = ft.id
+                                    AND fp.deleted_at IS NULL
                                 ORDER BY
                                     fp.created_at DESC
                                 LIMIT 1
                             ) AS fp_latest ON TRUE
                             LEFT JOIN
                                 users u_post ON fp_latest.created_by_id = u_post.id
+                            LEFT JOIN
+                                forum_thread_reads ftr ON ftr.forum_thread_id = ft.id AND ftr.user_id = $2
+                            LEFT JOIN LATERAL (
+                                SELECT
+                                    MIN(fp.id) FILTER (
+                                        WHERE fp.id > COALESCE(ftr.last_read_post_id, 0)
+                                    ) AS first_unread_post_id,
+                                    COUNT(*) FILTER (
+                                        WHERE fp.id <= COALESCE(ftr.last_read_post_id, 0)
+                                    ) AS read_posts_amount
+                                FROM
+                                    forum_posts fp
+                                WHERE
+                                    fp.forum_thread_id = ft.id
+                                    AND fp.deleted_at IS NULL
+                            -- nothing is joined when all the posts are read
+                            ) AS fp_unread ON fp_unread.first_unread_post_id IS NOT NULL
                             WHERE
                                 ft.forum_sub_category_id = fsc.id
                         )
@@ -275,7 +364,9 @@ impl ConnectionPool {
             GROUP BY
                 fsc.id, fc.id;
             "#,
-            forum_sub_category_id
+            forum_sub_category_id,
+            user_id,
+            posts_page_size as i64
         )
         .fetch_one(self.borrow())
         .await
@@ -289,8 +380,9 @@ impl ConnectionPool {
         &self,
         forum_thread_id: i64,
         user_id: i32,
+        posts_page_size: u32,
     ) -> Result<ForumThreadEnriched> {
-        let forum_thread = sqlx::query_as!(
+        let mut forum_thread = sqlx::query_as!(
             ForumThreadEnriched,
             r#"
             SELECT
@@ -305,7 +397,14 @@ impl ConnectionPool {
                 fsc.name AS forum_sub_category_name,
                 fc.name AS forum_category_name,
                 fc.id AS forum_category_id,
-                (sft.id IS NOT NULL) AS "is_subscribed!"
+                (sft.id IS NOT NULL) AS "is_subscribed!",
+                NULL::JSONB AS "poll: _",
+                (fp_unread.first_unread_post_id IS NOT NULL) AS "has_unread!",
+                fp_unread.first_unread_post_id,
+                CASE
+                    WHEN fp_unread.first_unread_post_id IS NOT NULL
+                    THEN fp_unread.read_posts_amount / $3 + 1
+                END AS first_unread_page
             FROM
                 forum_threads AS ft
             JOIN
@@ -315,16 +414,39 @@ impl ConnectionPool {
             LEFT JOIN
                 subscriptions_forum_thread_posts AS sft
                 ON sft.forum_thread_id = ft.id AND sft.user_id = $2
+            LEFT JOIN
+                forum_thread_reads AS ftr
+                ON ftr.forum_thread_id = ft.id AND ftr.user_id = $2
+            CROSS JOIN LATERAL (
+                SELECT
+                    MIN(fp.id) FILTER (
+                        WHERE fp.id > COALESCE(ftr.last_read_post_id, 0)
+                    ) AS first_unread_post_id,
+                    COUNT(*) FILTER (
+                        WHERE fp.id <= COALESCE(ftr.last_read_post_id, 0)
+                    ) AS read_posts_amount
+                FROM
+                    forum_posts AS fp
+                WHERE
+                    fp.forum_thread_id = ft.id
+                    AND fp.deleted_at IS NULL
+            ) AS fp_unread
             WHERE
                 ft.id = $1;
             "#,
             forum_thread_id,
-            user_id
+            user_id,
+            posts_page_size as i64
         )
         .fetch_one(self.borrow())
         .await
         .map_err(Error::CouldNotFindForumThread)?;
 
+        forum_thread.poll = self
+            .find_forum_poll(forum_thread_id, user_id)
+            .await?
+            .map(Json);
+
         if forum_thread.is_subscribed {
             Self::mark_notification_forum_thread_post_as_read(self, forum_thread_id, user_id)
                 .await?;
@@ -336,6 +458,7 @@ impl ConnectionPool {
     pub async fn find_forum_thread_posts(
         &self,
         form: GetForumThreadPostsQuery,
+        include_deleted: bool,
     ) -> Result<PaginatedResults<ForumPostHierarchy>> {
         let page_size = form.page_size as i64;
         let mut current_page = form.page.unwrap_or(1);
@@ -345,9 +468,11 @@ impl ConnectionPool {
                 r#"
                 SELECT COUNT(*)::BIGINT FROM forum_posts
                 WHERE forum_thread_id = $1 AND id < $2
+                AND ($3 OR deleted_at IS NULL)
                 "#,
                 form.thread_id,
-                post_id
+                post_id,
+                include_deleted
             )
             .fetch_one(self.borrow())
             .await?
@@ -367,6 +492,7 @@ impl ConnectionPool {
             created_at: DateTime<Utc>,
             updated_at: DateTime<Utc>,
             sticky: bool,
+            deleted_at: Option<DateTime<Utc>>,
             forum_thread_id: i64,
             created_by_user_id: i32,
             created_by_user_username: String,
@@ -384,6 +510,7 @@ impl ConnectionPool {
                 fp.created_at,
                 fp.updated_at,
                 fp.sticky,
+                fp.deleted_at,
                 fp.forum_thread_id,
                 u.id AS created_by_user_id,
                 u.username AS created_by_user_username,
@@ -393,21 +520,27 @@ impl ConnectionPool {
             FROM forum_posts fp
             JOIN users u ON fp.created_by_id = u.id
             WHERE fp.forum_thread_id = $1
+            AND ($4 OR fp.deleted_at IS NULL)
             ORDER BY fp.created_at ASC
             OFFSET $2
             LIMIT $3
             "#,
             form.thread_id,
             offset,
-            page_size
+            page_size,
+            include_deleted
         )
         .fetch_all(self.borrow())
         .await
         .map_err(Error::CouldNotFindForumThread)?;
 
         let total_forum_posts_in_thread = sqlx::query_scalar!(
-            r#"SELECT COUNT(id) FROM forum_posts WHERE forum_thread_id = $1"#,
-            form.thread_id
+            r#"
+            SELECT COUNT(id) FROM forum_posts
+            WHERE forum_thread_id = $1 AND ($2 OR deleted_at IS NULL)
+            "#,
+            form.thread_id,
+            include_deleted
         )
         .fetch_one(self.borrow())
         .await
@@ -422,6 +555,7 @@ impl ConnectionPool {
                 created_at: r.created_at,
                 updated_at: r.updated_at,
                 sticky: r.sticky,
+                deleted_at: r.deleted_at,
                 forum_thread_id: r.forum_thread_id,
                 created_by: UserLiteAvatar {
                     id: r.created_by_user_id,
@@ -447,6 +581,7 @@ impl ConnectionPool {
         &self,
         forum_sub_category_id: i32,
         limit: u32,
+        user_class: &UserClass,
     ) -> Result<Vec<ForumPostAndThreadName>> {
         sqlx::query_as!(
             ForumPostAndThreadName,
@@ -463,15 +598,19 @@ impl ConnectionPool {
             FROM
                 forum_threads AS ft
             JOIN
-                forum_posts AS fp ON ft.id = fp.forum_thread_id
+                forum_posts AS fp ON ft.id = fp.forum_thread_id AND fp.deleted_at IS NULL
+            JOIN
+                forum_sub_categories AS fsc ON fsc.id = ft.forum_sub_category_id
             WHERE
                 ft.forum_sub_category_id = $1
+                AND user_class_rank(fsc.min_class_read) <= user_class_rank($3)
             ORDER BY
                 ft.id DESC, fp.created_at ASC
             LIMIT $2
             "#,
             forum_sub_category_id,
-            limit as i32
+            limit as i32,
+            user_class as _
         )
         .fetch_all(self.borrow())
         .await
@@ -481,6 +620,7 @@ impl ConnectionPool {
     pub async fn search_forum_threads(
         &self,
         form: &ForumSearchQuery,
+        user_class: &UserClass,
     ) -> Result<PaginatedResults<ForumSearchResult>> {
         let limit = form.page as i64 * form.page_size as i64;
         let offset = (form.page - 1) as i64 * form.page_size as i64;
@@ -504,7 +644,7 @@ impl ConnectionPool {
             JOIN LATERAL (
                 SELECT p.*
                 FROM forum_posts p
-                WHERE p.forum_thread_id = t.id
+                WHERE p.forum_thread_id = t.id AND p.deleted_at IS NULL
                 ORDER BY p.created_at DESC
                 LIMIT 1
             ) p ON TRUE
@@ -512,7 +652,8 @@ impl ConnectionPool {
             JOIN forum_sub_categories s ON s.id = t.forum_sub_category_id
             JOIN forum_categories c ON c.id = s.forum_category_id
 
-            WHERE $1::TEXT IS NULL OR t.name ILIKE '%' || $1 || '%'
+            WHERE ($1::TEXT IS NULL OR t.name ILIKE '%' || $1 || '%')
+            AND user_class_rank(s.min_class_read) <= user_class_rank($4)
 
             ORDER BY p.created_at DESC
 
@@ -520,15 +661,23 @@ impl ConnectionPool {
             "#,
             form.thread_name,
             limit,
-            offset
+            offset,
+            user_class as _
         )
         .fetch_all(self.borrow())
         .await
         .map_err(Error::CouldNotFindForumThreadsFirstPost)?;
 
         let total_results = sqlx::query!(
-            "SELECT COUNT(*) AS total FROM forum_threads WHERE name ILIKE '%' || $1 || '%'",
-            form.thread_name
+            r#"
+            SELECT COUNT(*) AS total
+            FROM forum_threads t
+            JOIN forum_sub_categories s ON s.id = t.forum_sub_category_id
+            WHERE ($1::TEXT IS NULL OR t.name ILIKE '%' || $1 || '%')
+            AND user_class_rank(s.min_class_read) <= user_class_rank($2)
+            "#,
+            form.thread_name,
+            user_class as _
         )
         .fetch_one(self.borrow())
         .await
@@ -543,4 +692,848 @@ impl ConnectionPool {
             page_size: form.page_size,
         })
     }
+
+    pub async fn find_forum_post(&self, forum_post_id: i64) -> Result<ForumPost> {
+        sqlx::query_as!(
+            ForumPost,
+            "SELECT * FROM forum_posts WHERE id = $1",
+            forum_post_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::ForumPostNotFound(forum_post_id))
+    }
+
+    // without the user specific data of find_forum_thread
+    pub async fn find_forum_thread_raw(&self, forum_thread_id: i64) -> Result<ForumThread> {
+        sqlx::query_as!(
+            ForumThread,
+            "SELECT * FROM forum_threads WHERE id = $1",
+            forum_thread_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::ForumThreadNotFound(forum_thread_id))
+    }
+
+    pub async fn update_forum_post(
+        &self,
+        edited_post: &EditedForumPost,
+        current_user_id: i32,
+    ) -> Result<ForumPost> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        // only the content is versioned
+        sqlx::query!(
+            r#"
+            INSERT INTO forum_post_edits (forum_post_id, edited_by_id, previous_content)
+            SELECT id, $2, content
+            FROM forum_posts
+            WHERE id = $1 AND content != $3
+            "#,
+            edited_post.id,
+            current_user_id,
+            edited_post.content
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let updated_post = sqlx::query_as!(
+            ForumPost,
+            r#"
+            UPDATE forum_posts
+            SET content = $2, sticky = $3, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+            edited_post.id,
+            edited_post.content,
+            edited_post.sticky
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumPostNotFound(edited_post.id))?;
+
+        tx.commit().await?;
+
+        Ok(updated_post)
+    }
+
+    pub async fn find_forum_post_edits(&self, forum_post_id: i64) -> Result<Vec<ForumPostEdit>> {
+        let edits = sqlx::query_as!(
+            ForumPostEdit,
+            r#"
+            SELECT
+                e.id,
+                e.forum_post_id,
+                e.edited_at,
+                e.edited_by_id,
+                u.username AS edited_by_username,
+                e.previous_content
+            FROM forum_post_edits e
+            JOIN users u ON u.id = e.edited_by_id
+            WHERE e.forum_post_id = $1
+            ORDER BY e.edited_at DESC
+            "#,
+            forum_post_id
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(edits)
+    }
+
+    // keeps the posts_amount of a thread and of its sub-category in sync with its visible posts
+    async fn increment_forum_posts_amounts(
+        tx: &mut Transaction<'_, Postgres>,
+        forum_thread_id: i64,
+        amount: i64,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            WITH updated_thread AS (
+                UPDATE forum_threads
+                SET posts_amount = posts_amount + $2
+                WHERE id = $1
+                RETURNING forum_sub_category_id
+            )
+            UPDATE forum_sub_categories
+            SET posts_amount = posts_amount + $2
+            WHERE id = (SELECT forum_sub_category_id FROM updated_thread)
+            "#,
+            forum_thread_id,
+            amount
+        )
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_forum_post(&self, forum_post_id: i64, current_user_id: i32) -> Result<()> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let deleted_post = sqlx::query!(
+            r#"
+            UPDATE forum_posts
+            SET deleted_at = NOW(), deleted_by_id = $2
+            WHERE id = $1 AND deleted_at IS NULL
+            RETURNING forum_thread_id, created_by_id
+            "#,
+            forum_post_id,
+            current_user_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumPostNotFound(forum_post_id))?;
+
+        Self::increment_forum_posts_amounts(&mut tx, deleted_post.forum_thread_id, -1).await?;
+
+        sqlx::query!(
+            "UPDATE users SET forum_posts = forum_posts - 1 WHERE id = $1",
+            deleted_post.created_by_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        Self::create_site_log(
+            &mut tx,
+            SiteLogAction::ForumPostDeleted,
+            current_user_id,
+            &format!(
+                "Forum post {} in thread {} was deleted",
+                forum_post_id, deleted_post.forum_thread_id
+            ),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn restore_forum_post(
+        &self,
+        forum_post_id: i64,
+        current_user_id: i32,
+    ) -> Result<ForumPost> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let restored_post = sqlx::query_as!(
+            ForumPost,
+            r#"
+            UPDATE forum_posts
+            SET deleted_at = NULL, deleted_by_id = NULL
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            RETURNING *
+            "#,
+            forum_post_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumPostNotFound(forum_post_id))?;
+
+        Self::increment_forum_posts_amounts(&mut tx, restored_post.forum_thread_id, 1).await?;
+
+        sqlx::query!(
+            "UPDATE users SET forum_posts = forum_posts + 1 WHERE id = $1",
+            restored_post.created_by_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        Self::create_site_log(
+            &mut tx,
+            SiteLogAction::ForumPostRestored,
+            current_user_id,
+            &format!(
+                "Forum post {} in thread {} was restored",
+                forum_post_id, restored_post.forum_thread_id
+            ),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(restored_post)
+    }
+
+    pub async fn update_forum_thread(
+        &self,
+        edited_thread: &EditedForumThread,
+        current_user_id: i32,
+    ) -> Result<ForumThread> {
+        self.find_forum_sub_category(edited_thread.forum_sub_category_id)
+            .await?;
+
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let previous_sub_category_id = sqlx::query_scalar!(
+            "SELECT forum_sub_category_id FROM forum_threads WHERE id = $1 FOR UPDATE",
+            edited_thread.id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumThreadNotFound(edited_thread.id))?;
+
+        let updated_thread = sqlx::query_as!(
+            ForumThread,
+            r#"
+            UPDATE forum_threads
+            SET name = $2, forum_sub_category_id = $3, sticky = $4, locked = $5
+            WHERE id = $1
+            RETURNING *
+            "#,
+            edited_thread.id,
+            edited_thread.name,
+            edited_thread.forum_sub_category_id,
+            edited_thread.sticky,
+            edited_thread.locked
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if previous_sub_category_id != updated_thread.forum_sub_category_id {
+            sqlx::query!(
+                r#"
+                UPDATE forum_sub_categories
+                SET
+                    threads_amount = threads_amount + CASE WHEN id = $2 THEN 1 ELSE -1 END,
+                    posts_amount = posts_amount + CASE WHEN id = $2 THEN $3::BIGINT ELSE -$3::BIGINT END
+                WHERE id IN ($1, $2)
+                "#,
+                previous_sub_category_id,
+                updated_thread.forum_sub_category_id,
+                updated_thread.posts_amount
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            Self::create_site_log(
+                &mut tx,
+                SiteLogAction::ForumThreadMoved,
+                current_user_id,
+                &format!(
+                    "Forum thread {} ({}) was moved from sub-category {} to sub-category {}",
+                    updated_thread.id,
+                    updated_thread.name,
+                    previous_sub_category_id,
+                    updated_thread.forum_sub_category_id
+                ),
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(updated_thread)
+    }
+
+    // the moved posts keep their notifications, which point to their new thread.
+    // a read marker only covers posts the reader has seen in either thread, so it
+    // stops right before the first post that is unread in its original thread
+    async fn move_forum_posts(
+        tx: &mut Transaction<'_, Postgres>,
+        post_ids: &[i64],
+        from_thread_id: i64,
+        to_thread_id: i64,
+    ) -> Result<Vec<ForumPost>> {
+        let moved_posts = sqlx::query_as!(
+            ForumPost,
+            r#"
+            UPDATE forum_posts
+            SET forum_thread_id = $3
+            WHERE id = ANY($1) AND forum_thread_id = $2
+            RETURNING *
+            "#,
+            post_ids,
+            from_thread_id,
+            to_thread_id
+        )
+        .fetch_all(&mut **tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE notifications
+            SET
+                target_id = $2,
+                payload = payload || jsonb_build_object(
+                    'forum_thread_name', (SELECT name FROM forum_threads WHERE id = $2)
+                )
+            WHERE notification_type = 'forum_thread_post'
+            AND target_id = $3
+            AND (payload->>'forum_post_id')::BIGINT = ANY($1)
+            "#,
+            post_ids,
+            to_thread_id,
+            from_thread_id
+        )
+        .execute(&mut **tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            WITH readers AS (
+                SELECT
+                    user_id,
+                    COALESCE(MAX(last_read_post_id) FILTER (WHERE forum_thread_id = $2), 0) AS from_read,
+                    COALESCE(MAX(last_read_post_id) FILTER (WHERE forum_thread_id = $3), 0) AS to_read
+                FROM forum_thread_reads
+                WHERE forum_thread_id IN ($2, $3)
+                GROUP BY user_id
+            )
+            INSERT INTO forum_thread_reads (user_id, forum_thread_id, last_read_post_id)
+            SELECT
+                r.user_id,
+                $3,
+                COALESCE(
+                    (
+                        SELECT MIN(fp.id) - 1
+                        FROM forum_posts fp
+                        WHERE fp.forum_thread_id = $3
+                        AND fp.deleted_at IS NULL
+                        AND fp.id > CASE WHEN fp.id = ANY($1) THEN r.from_read ELSE r.to_read END
+                    ),
+                    (SELECT MAX(fp.id) FROM forum_posts fp WHERE fp.forum_thread_id = $3),
+                    0
+                )
+            FROM readers r
+            ON CONFLICT (user_id, forum_thread_id) DO UPDATE
+            SET last_read_post_id = EXCLUDED.last_read_post_id
+            "#,
+            post_ids,
+            from_thread_id,
+            to_thread_id
+        )
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(moved_posts)
+    }
+
+    pub async fn split_forum_thread(
+        &self,
+        split: &ForumThreadSplit,
+        current_user_id: i32,
+    ) -> Result<ForumThread> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let source_thread = sqlx::query_as!(
+            ForumThread,
+            "SELECT * FROM forum_threads WHERE id = $1 FOR UPDATE",
+            split.forum_thread_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumThreadNotFound(split.forum_thread_id))?;
+
+        // the new thread is attributed to the author of its first post
+        let new_thread = sqlx::query_as!(
+            ForumThread,
+            r#"
+            INSERT INTO forum_threads (name, created_by_id, forum_sub_category_id)
+            SELECT $1, created_by_id, $2
+            FROM forum_posts
+            WHERE id = ANY($3) AND forum_thread_id = $4
+            ORDER BY created_at
+            LIMIT 1
+            RETURNING *
+            "#,
+            split.name,
+            source_thread.forum_sub_category_id,
+            &split.post_ids,
+            source_thread.id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| Error::BadRequest("no post of the thread to split was given".into()))?;
+
+        let moved_posts =
+            Self::move_forum_posts(&mut tx, &split.post_ids, source_thread.id, new_thread.id)
+                .await?;
+        let moved_visible_posts = moved_posts
+            .iter()
+            .filter(|post| post.deleted_at.is_none())
+            .count() as i64;
+
+        sqlx::query!(
+            r#"
+            UPDATE forum_threads
+            SET posts_amount = posts_amount + CASE WHEN id = $2 THEN $3::BIGINT ELSE -$3::BIGINT END
+            WHERE id IN ($1, $2)
+            "#,
+            source_thread.id,
+            new_thread.id,
+            moved_visible_posts
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE forum_sub_categories
+            SET threads_amount = threads_amount + 1
+            WHERE id = $1
+            "#,
+            source_thread.forum_sub_category_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE users SET forum_threads = forum_threads + 1 WHERE id = $1
+            "#,
+            new_thread.created_by_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        Self::create_site_log(
+            &mut tx,
+            SiteLogAction::ForumThreadSplit,
+            current_user_id,
+            &format!(
+                "{} posts of forum thread {} ({}) were split into forum thread {} ({})",
+                moved_posts.len(),
+                source_thread.id,
+                source_thread.name,
+                new_thread.id,
+                new_thread.name
+            ),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        self.find_forum_thread_raw(new_thread.id).await
+    }
+
+    pub async fn merge_forum_threads(
+        &self,
+        merge: &ForumThreadsMerge,
+        current_user_id: i32,
+    ) -> Result<ForumThread> {
+        if merge.source_thread_id == merge.target_thread_id {
+            return Err(Error::BadRequest(
+                "a forum thread cannot be merged into itself".into(),
+            ));
+        }
+
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let source_thread = sqlx::query_as!(
+            ForumThread,
+            "SELECT * FROM forum_threads WHERE id = $1 FOR UPDATE",
+            merge.source_thread_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumThreadNotFound(merge.source_thread_id))?;
+
+        let target_thread = sqlx::query_as!(
+            ForumThread,
+            "SELECT * FROM forum_threads WHERE id = $1 FOR UPDATE",
+            merge.target_thread_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumThreadNotFound(merge.target_thread_id))?;
+
+        // a thread has at most one poll, the source's one would be lost with it
+        let polls = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE forum_thread_id = $1) AS "source!",
+                COUNT(*) FILTER (WHERE forum_thread_id = $2) AS "target!"
+            FROM forum_polls
+            WHERE forum_thread_id IN ($1, $2)
+            "#,
+            source_thread.id,
+            target_thread.id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if polls.source > 0 {
+            if polls.target > 0 {
+                return Err(Error::ForumThreadsBothHavePolls);
+            }
+            sqlx::query!(
+                "UPDATE forum_polls SET forum_thread_id = $2 WHERE forum_thread_id = $1",
+                source_thread.id,
+                target_thread.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let post_ids = sqlx::query_scalar!(
+            "SELECT id FROM forum_posts WHERE forum_thread_id = $1",
+            source_thread.id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        Self::move_forum_posts(&mut tx, &post_ids, source_thread.id, target_thread.id).await?;
+
+        // the source thread's posts_amount only counts its visible posts
+        Self::increment_forum_posts_amounts(&mut tx, target_thread.id, source_thread.posts_amount)
+            .await?;
+        sqlx::query!(
+            r#"
+            UPDATE forum_sub_categories
+            SET threads_amount = threads_amount - 1, posts_amount = posts_amount - $2
+            WHERE id = $1
+            "#,
+            source_thread.forum_sub_category_id,
+            source_thread.posts_amount
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!(
+            "UPDATE users SET forum_threads = forum_threads - 1 WHERE id = $1",
+            source_thread.created_by_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO subscriptions_forum_thread_posts (forum_thread_id, user_id)
+            SELECT $2, user_id
+            FROM subscriptions_forum_thread_posts
+            WHERE forum_thread_id = $1
+            ON CONFLICT (forum_thread_id, user_id) DO NOTHING
+            "#,
+            source_thread.id,
+            target_thread.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM forum_threads WHERE id = $1", source_thread.id)
+            .execute(&mut *tx)
+            .await?;
+
+        Self::create_site_log(
+            &mut tx,
+            SiteLogAction::ForumThreadsMerged,
+            current_user_id,
+            &format!(
+                "Forum thread {} ({}) was merged into forum thread {} ({})",
+                source_thread.id, source_thread.name, target_thread.id, target_thread.name
+            ),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        self.find_forum_thread_raw(target_thread.id).await
+    }
+
+    pub async fn find_forum_sub_category(
+        &self,
+        forum_sub_category_id: i32,
+    ) -> Result<ForumSubCategory> {
+        sqlx::query_as!(
+            ForumSubCategory,
+            r#"
+            SELECT id, forum_category_id, name, created_at, created_by_id, threads_amount,
+                   posts_amount, sort_order, min_class_read AS "min_class_read: _",
+                   min_class_post AS "min_class_post: _",
+                   min_class_create_thread AS "min_class_create_thread: _"
+            FROM forum_sub_categories
+            WHERE id = $1
+            "#,
+            forum_sub_category_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::ForumSubCategoryNotFound(forum_sub_category_id))
+    }
+
+    pub async fn create_forum_category(
+        &self,
+        category: &UserCreatedForumCategory,
+        current_user_id: i32,
+    ) -> Result<ForumCategory> {
+        let created_category = sqlx::query_as!(
+            ForumCategory,
+            r#"
+            INSERT INTO forum_categories (name, sort_order, created_by_id)
+            VALUES ($1, $2, $3)
+            RETURNING *
+            "#,
+            category.name,
+            category.sort_order,
+            current_user_id
+        )
+        .fetch_one(self.borrow())
+        .await?;
+
+        Ok(created_category)
+    }
+
+    pub async fn update_forum_category(
+        &self,
+        edited_category: &EditedForumCategory,
+    ) -> Result<ForumCategory> {
+        sqlx::query_as!(
+            ForumCategory,
+            r#"
+            UPDATE forum_categories
+            SET name = $2, sort_order = $3
+            WHERE id = $1
+            RETURNING *
+            "#,
+            edited_category.id,
+            edited_category.name,
+            edited_category.sort_order
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::ForumCategoryNotFound(edited_category.id))
+    }
+
+    // only empty categories can be deleted, their sub-categories must be moved or deleted first
+    pub async fn delete_forum_category(&self, forum_category_id: i32) -> Result<()> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        sqlx::query_scalar!(
+            "SELECT id FROM forum_categories WHERE id = $1 FOR UPDATE",
+            forum_category_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumCategoryNotFound(forum_category_id))?;
+
+        let has_sub_categories = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM forum_sub_categories WHERE forum_category_id = $1) AS "exists!""#,
+            forum_category_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if has_sub_categories {
+            return Err(Error::ForumCategoryNotEmpty);
+        }
+
+        sqlx::query!(
+            "DELETE FROM forum_categories WHERE id = $1",
+            forum_category_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn create_forum_sub_category(
+        &self,
+        sub_category: &UserCreatedForumSubCategory,
+        current_user_id: i32,
+    ) -> Result<ForumSubCategory> {
+        sqlx::query_as!(
+            ForumSubCategory,
+            r#"
+            INSERT INTO forum_sub_categories (forum_category_id, name, sort_order, min_class_read,
+                                              min_class_post, min_class_create_thread, created_by_id)
+            SELECT id, $2, $3, $4, $5, $6, $7
+            FROM forum_categories
+            WHERE id = $1
+            RETURNING id, forum_category_id, name, created_at, created_by_id, threads_amount,
+                      posts_amount, sort_order, min_class_read AS "min_class_read: _",
+                      min_class_post AS "min_class_post: _",
+                      min_class_create_thread AS "min_class_create_thread: _"
+            "#,
+            sub_category.forum_category_id,
+            sub_category.name,
+            sub_category.sort_order,
+            sub_category.min_class_read as _,
+            sub_category.min_class_post as _,
+            sub_category.min_class_create_thread as _,
+            current_user_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::ForumCategoryNotFound(sub_category.forum_category_id))
+    }
+
+    pub async fn update_forum_sub_category(
+        &self,
+        edited_sub_category: &EditedForumSubCategory,
+    ) -> Result<ForumSubCategory> {
+        // also checks that the sub-category exists
+        self.find_forum_sub_category(edited_sub_category.id).await?;
+
+        sqlx::query_as!(
+            ForumSubCategory,
+            r#"
+            UPDATE forum_sub_categories
+            SET forum_category_id = fc.id, name = $3, sort_order = $4, min_class_read = $5,
+                min_class_post = $6, min_class_create_thread = $7
+            FROM forum_categories fc
+            WHERE forum_sub_categories.id = $1 AND fc.id = $2
+            RETURNING forum_sub_categories.id, forum_category_id, forum_sub_categories.name,
+                      forum_sub_categories.created_at, forum_sub_categories.created_by_id,
+                      threads_amount, posts_amount, forum_sub_categories.sort_order,
+                      min_class_read AS "min_class_read: _", min_class_post AS "min_class_post: _",
+                      min_class_create_thread AS "min_class_create_thread: _"
+            "#,
+            edited_sub_category.id,
+            edited_sub_category.forum_category_id,
+            edited_sub_category.name,
+            edited_sub_category.sort_order,
+            edited_sub_category.min_class_read as _,
+            edited_sub_category.min_class_post as _,
+            edited_sub_category.min_class_create_thread as _
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::ForumCategoryNotFound(
+            edited_sub_category.forum_category_id,
+        ))
+    }
+
+    // only empty sub-categories can be deleted, their threads must be moved first
+    pub async fn delete_forum_sub_category(&self, forum_sub_category_id: i32) -> Result<()> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        sqlx::query_scalar!(
+            "SELECT id FROM forum_sub_categories WHERE id = $1 FOR UPDATE",
+            forum_sub_category_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::ForumSubCategoryNotFound(forum_sub_category_id))?;
+
+        let has_threads = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM forum_threads WHERE forum_sub_category_id = $1) AS "exists!""#,
+            forum_sub_category_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if has_threads {
+            return Err(Error::ForumSubCategoryNotEmpty);
+        }
+
+        sqlx::query!(
+            "DELETE FROM forum_sub_categories WHERE id = $1",
+            forum_sub_category_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // markers only move forward, reading an older page keeps the latest one
+    pub async fn mark_forum_thread_as_read(
+        &self,
+        forum_thread_id: i64,
+        user_id: i32,
+        last_read_post_id: i64,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO forum_thread_reads (user_id, forum_thread_id, last_read_post_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id, forum_thread_id) DO UPDATE
+            SET last_read_post_id = GREATEST(forum_thread_reads.last_read_post_id, EXCLUDED.last_read_post_id),
+                read_at = NOW()
+            "#,
+            user_id,
+            forum_thread_id,
+            last_read_post_id
+        )
+        .execute(self.borrow())
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn mark_forum_sub_category_as_read(
+        &self,
+        forum_sub_category_id: i32,
+        user_id: i32,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO forum_thread_reads (user_id, forum_thread_id, last_read_post_id)
+            SELECT $2, fp.forum_thread_id, MAX(fp.id)
+            FROM forum_posts fp
+            JOIN forum_threads ft ON ft.id = fp.forum_thread_id
+            WHERE ft.forum_sub_category_id = $1
+            GROUP BY fp.forum_thread_id
+            ON CONFLICT (user_id, forum_thread_id) DO UPDATE
+            SET last_read_post_id = GREATEST(forum_thread_reads.last_read_post_id, EXCLUDED.last_read_post_id),
+                read_at = NOW()
+            "#,
+            forum_sub_category_id,
+            user_id
+        )
+        .execute(self.borrow())
+        .await?;
+
+        Ok(())
+    }
 }
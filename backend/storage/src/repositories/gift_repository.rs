@@ -1,8 +1,12 @@
 use crate::{
     connection_pool::ConnectionPool,
-    models::gift::{Gift, UserCreatedGift},
+    models::{
+        gift::{Gift, UserCreatedGift},
+        notification::NotificationType,
+    },
 };
 use arcadia_common::error::{Error, Result};
+use serde_json::json;
 use sqlx::{PgPool, Postgres, Transaction};
 use std::borrow::Borrow;
 
@@ -37,6 +41,19 @@ impl ConnectionPool {
         .await
         .map_err(Error::CouldNotCreateGift)?;
 
+        Self::notify_users(
+            &mut tx,
+            NotificationType::GiftReceived,
+            &[gift.receiver_id],
+            Some(current_user_id),
+            gift.id,
+            json!({
+                "bonus_points": gift.bonus_points,
+                "freeleech_tokens": gift.freeleech_tokens,
+            }),
+        )
+        .await?;
+
         tx.commit().await?;
 
         Ok(gift)
@@ -0,0 +1,89 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::ip_ban::{IpBan, UserCreatedIpBan},
+};
+use arcadia_common::error::{Error, Result};
+use sqlx::types::ipnetwork::IpNetwork;
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub async fn create_ip_ban(
+        &self,
+        ban: &UserCreatedIpBan,
+        current_user_id: i32,
+    ) -> Result<IpBan> {
+        let created_ban = sqlx::query_as!(
+            IpBan,
+            r#"
+                INSERT INTO ip_bans (created_by_id, ip_range, reason)
+                VALUES ($1, $2, $3)
+                RETURNING *
+            "#,
+            current_user_id,
+            // the host bits must be zeroed for the cidr type
+            IpNetwork::new(ban.ip_range.network(), ban.ip_range.prefix()).unwrap(),
+            ban.reason
+        )
+        .fetch_one(self.borrow())
+        .await
+        .map_err(|error| match &error {
+            sqlx::Error::Database(database_error) if database_error.is_unique_violation() => {
+                Error::IpBanAlreadyExists
+            }
+            _ => Error::CouldNotCreateIpBan(error),
+        })?;
+
+        Ok(created_ban)
+    }
+
+    pub async fn find_ip_bans(&self) -> Result<Vec<IpBan>> {
+        let bans = sqlx::query_as!(
+            IpBan,
+            r#"
+                SELECT * FROM ip_bans
+                ORDER BY created_at DESC
+            "#
+        )
+        .fetch_all(self.borrow())
+        .await
+        .map_err(Error::CouldNotFindIpBans)?;
+
+        Ok(bans)
+    }
+
+    pub async fn delete_ip_ban(&self, ban_id: i32) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM ip_bans
+                WHERE id = $1
+            "#,
+            ban_id
+        )
+        .execute(self.borrow())
+        .await
+        .map_err(Error::CouldNotDeleteIpBan)?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::IpBanNotFound(ban_id));
+        }
+
+        Ok(())
+    }
+
+    pub async fn is_ip_banned(&self, ip: IpNetwork) -> Result<bool> {
+        let is_banned = sqlx::query_scalar!(
+            r#"
+                SELECT EXISTS(
+                    SELECT 1 FROM ip_bans
+                    WHERE ip_range >>= $1
+                ) AS "is_banned!"
+            "#,
+            // ipv4 clients reaching a dual stack socket show up as ipv4-mapped ipv6 addresses
+            IpNetwork::from(ip.ip().to_canonical())
+        )
+        .fetch_one(self.borrow())
+        .await?;
+
+        Ok(is_banned)
+    }
+}
@@ -3,24 +3,36 @@ pub mod auth_repository;
 pub mod collage_repository;
 pub mod conversation_repository;
 pub mod edition_group_repository;
+pub mod feed_repository;
+pub mod forum_poll_repository;
 pub mod forum_repository;
 pub mod gift_repository;
 pub mod invitation_repository;
+pub mod ip_ban_repository;
 pub mod master_group_repository;
+pub mod notification_filter_repository;
 pub mod notification_repository;
 pub mod peer_repository;
+pub mod revision_repository;
+pub mod saved_torrent_search_repository;
 pub mod series_repository;
+pub mod site_log_repository;
 pub mod staff_pm_repository;
 pub mod stats_repository;
 pub mod subscriptions_repository;
 pub mod title_group_bookmark_repository;
 pub mod title_group_comment_repository;
 pub mod title_group_repository;
+pub mod torrent_client_repository;
 pub mod torrent_report_repository;
 pub mod torrent_repository;
 pub mod torrent_request_comment_repository;
 pub mod torrent_request_repository;
 pub mod torrent_request_vote_repository;
+pub mod torrent_review_repository;
+pub mod torrent_trump_repository;
+pub mod tracker_flag_repository;
+pub mod upload_rule_repository;
 pub mod user_application_repository;
 pub mod user_repository;
 pub mod wiki_repository;
@@ -0,0 +1,129 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::notification_filter::{NotificationFilter, UserCreatedNotificationFilter},
+};
+use arcadia_common::error::{Error, Result};
+use sqlx::PgPool;
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub async fn create_notification_filter(
+        &self,
+        filter: &UserCreatedNotificationFilter,
+        user_id: i32,
+        max_filters: i64,
+    ) -> Result<NotificationFilter> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        // serializes the concurrent creations of a user, so they can't all pass the limit check
+        sqlx::query!("SELECT id FROM users WHERE id = $1 FOR UPDATE", user_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        let filters_amount = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM notification_filters
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if filters_amount >= max_filters {
+            return Err(Error::NotificationFilterLimitReached(max_filters));
+        }
+
+        // text criteria are compared case insensitively
+        let lowercase = |values: &[String]| -> Vec<String> {
+            values
+                .iter()
+                .map(|value| value.trim().to_lowercase())
+                .filter(|value| !value.is_empty())
+                .collect()
+        };
+
+        let created_filter = sqlx::query_as!(
+            NotificationFilter,
+            r#"
+            INSERT INTO notification_filters (
+                user_id, name, artist_ids, tags, content_types, containers, audio_codecs,
+                video_resolutions, release_groups, uploader_ids, min_size, include_in_feed
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING
+                id, created_at, user_id, name, artist_ids, tags,
+                content_types AS "content_types: _",
+                containers,
+                audio_codecs AS "audio_codecs: _",
+                video_resolutions AS "video_resolutions: _",
+                release_groups, uploader_ids, min_size, include_in_feed
+            "#,
+            user_id,
+            filter.name,
+            &filter.artist_ids,
+            &lowercase(&filter.tags),
+            &filter.content_types as _,
+            &lowercase(&filter.containers),
+            &filter.audio_codecs as _,
+            &filter.video_resolutions as _,
+            &lowercase(&filter.release_groups),
+            &filter.uploader_ids,
+            filter.min_size,
+            filter.include_in_feed
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(Error::CouldNotCreateNotificationFilter)?;
+
+        tx.commit().await?;
+
+        Ok(created_filter)
+    }
+
+    pub async fn find_notification_filters(&self, user_id: i32) -> Result<Vec<NotificationFilter>> {
+        let filters = sqlx::query_as!(
+            NotificationFilter,
+            r#"
+            SELECT
+                id, created_at, user_id, name, artist_ids, tags,
+                content_types AS "content_types: _",
+                containers,
+                audio_codecs AS "audio_codecs: _",
+                video_resolutions AS "video_resolutions: _",
+                release_groups, uploader_ids, min_size, include_in_feed
+            FROM notification_filters
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+            user_id
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(filters)
+    }
+
+    pub async fn delete_notification_filter(&self, filter_id: i64, user_id: i32) -> Result<()> {
+        let deleted = sqlx::query!(
+            r#"
+            DELETE FROM notification_filters
+            WHERE id = $1 AND user_id = $2
+            "#,
+            filter_id,
+            user_id
+        )
+        .execute(self.borrow())
+        .await?
+        .rows_affected();
+
+        if deleted == 0 {
+            return Err(Error::NotificationFilterNotFound(filter_id));
+        }
+
+        Ok(())
+    }
+}
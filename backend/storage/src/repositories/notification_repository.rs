@@ -1,78 +1,345 @@
-use crate::{connection_pool::ConnectionPool, models::notification::NotificationForumThreadPost};
+use crate::{
+    connection_pool::ConnectionPool,
+    models::{
+        common::PaginatedResults,
+        notification::{Notification, NotificationPreference, NotificationQuery, NotificationType},
+    },
+};
 use arcadia_common::error::{Error, Result};
-use sqlx::{Postgres, Transaction};
+use serde_json::{json, Value};
+use sqlx::{PgPool, Postgres, Transaction};
 use std::borrow::Borrow;
 
 impl ConnectionPool {
+    // the actor isn't notified of their own action,
+    // nor are the users who disabled this type of notification
+    pub(crate) async fn notify_users(
+        tx: &mut Transaction<'_, Postgres>,
+        notification_type: NotificationType,
+        user_ids: &[i32],
+        actor_id: Option<i32>,
+        target_id: i64,
+        payload: Value,
+    ) -> Result<()> {
+        let payloads = vec![payload; user_ids.len()];
+
+        Self::notify_users_with_payloads(
+            tx,
+            notification_type,
+            user_ids,
+            &payloads,
+            actor_id,
+            target_id,
+        )
+        .await
+    }
+
+    // same as notify_users, with the payload of each recipient at the same index
+    // a user listed several times is notified once, with their first payload
+    pub(crate) async fn notify_users_with_payloads(
+        tx: &mut Transaction<'_, Postgres>,
+        notification_type: NotificationType,
+        user_ids: &[i32],
+        payloads: &[Value],
+        actor_id: Option<i32>,
+        target_id: i64,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO notifications (user_id, notification_type, actor_id, target_id, payload)
+                SELECT DISTINCT ON (recipient.id)
+                    recipient.id,
+                    $2::notification_type_enum,
+                    $3::INT,
+                    $4::BIGINT,
+                    recipient.payload
+                FROM UNNEST($1::INT[], $5::JSONB[]) WITH ORDINALITY AS recipient(id, payload, position)
+                WHERE recipient.id IS DISTINCT FROM $3
+                AND NOT EXISTS (
+                    SELECT 1 FROM notification_preferences p
+                    WHERE p.user_id = recipient.id AND p.notification_type = $2 AND NOT p.enabled
+                )
+                ORDER BY recipient.id, recipient.position
+            "#,
+            user_ids,
+            notification_type as _,
+            actor_id,
+            target_id,
+            payloads
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(Error::CouldNotCreateNotification)?;
+
+        Ok(())
+    }
+
+    // notifies the followers of the title group, its artists and series,
+    // and the users whose filters match the torrent
+    pub async fn notify_users_new_torrent(
+        tx: &mut Transaction<'_, Postgres>,
+        title_group_id: i32,
+        torrent_id: i32,
+        uploader_id: i32,
+    ) -> Result<()> {
+        Self::notify_users_title_group_torrents(tx, title_group_id, torrent_id, uploader_id)
+            .await?;
+        Self::notify_users_artist_torrents(tx, title_group_id, torrent_id, uploader_id).await?;
+        Self::notify_users_series_torrents(tx, title_group_id, torrent_id, uploader_id).await?;
+        Self::notify_users_notification_filters(tx, torrent_id).await
+    }
+
+    // the uploader isn't the actor, anonymous uploads don't reveal them
     pub async fn notify_users_title_group_torrents(
         tx: &mut Transaction<'_, Postgres>,
         title_group_id: i32,
         torrent_id: i32,
         current_user_id: i32,
     ) -> Result<()> {
-        sqlx::query!(
+        let user_ids = sqlx::query_scalar!(
             r#"
-                WITH user_ids AS (
-                    SELECT user_id
-                    FROM subscriptions_title_group_torrents
-                    WHERE title_group_id = $1
-                    AND user_id != $3
+                SELECT user_id
+                FROM subscriptions_title_group_torrents
+                WHERE title_group_id = $1
+                AND user_id != $2
+            "#,
+            title_group_id,
+            current_user_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(Error::CouldNotCreateNotification)?;
+
+        Self::notify_users(
+            tx,
+            NotificationType::TitleGroupTorrent,
+            &user_ids,
+            None,
+            torrent_id as i64,
+            json!({ "title_group_id": title_group_id }),
+        )
+        .await
+    }
+
+    // a user following several artists of the title group is notified once
+    pub async fn notify_users_artist_torrents(
+        tx: &mut Transaction<'_, Postgres>,
+        title_group_id: i32,
+        torrent_id: i32,
+        current_user_id: i32,
+    ) -> Result<()> {
+        let subscriptions = sqlx::query!(
+            r#"
+                SELECT s.user_id, s.artist_id
+                FROM subscriptions_artist_torrents s
+                JOIN affiliated_artists aa ON aa.artist_id = s.artist_id
+                WHERE aa.title_group_id = $1
+                AND s.user_id != $2
+                ORDER BY s.user_id, s.artist_id
+            "#,
+            title_group_id,
+            current_user_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(Error::CouldNotCreateNotification)?;
+
+        let (user_ids, payloads): (Vec<i32>, Vec<Value>) = subscriptions
+            .into_iter()
+            .map(|s| {
+                (
+                    s.user_id,
+                    json!({ "title_group_id": title_group_id, "artist_id": s.artist_id }),
                 )
-                INSERT INTO notifications_title_group_torrents (user_id, torrent_id)
-                SELECT
-                    user_id,
-                    $2
-                FROM user_ids
+            })
+            .unzip();
+
+        Self::notify_users_with_payloads(
+            tx,
+            NotificationType::ArtistTorrent,
+            &user_ids,
+            &payloads,
+            None,
+            torrent_id as i64,
+        )
+        .await
+    }
+
+    pub async fn notify_users_series_torrents(
+        tx: &mut Transaction<'_, Postgres>,
+        title_group_id: i32,
+        torrent_id: i32,
+        current_user_id: i32,
+    ) -> Result<()> {
+        let subscriptions = sqlx::query!(
+            r#"
+                SELECT s.user_id, s.series_id
+                FROM subscriptions_series_torrents s
+                JOIN title_groups tg ON tg.series_id = s.series_id
+                WHERE tg.id = $1
+                AND s.user_id != $2
             "#,
             title_group_id,
-            torrent_id,
             current_user_id
         )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(Error::CouldNotCreateNotification)?;
+
+        let (user_ids, payloads): (Vec<i32>, Vec<Value>) = subscriptions
+            .into_iter()
+            .map(|s| {
+                (
+                    s.user_id,
+                    json!({ "title_group_id": title_group_id, "series_id": s.series_id }),
+                )
+            })
+            .unzip();
+
+        Self::notify_users_with_payloads(
+            tx,
+            NotificationType::SeriesTorrent,
+            &user_ids,
+            &payloads,
+            None,
+            torrent_id as i64,
+        )
+        .await
+    }
+
+    // a user is notified once per torrent, even if it matches several of their filters
+    pub async fn notify_users_notification_filters(
+        tx: &mut Transaction<'_, Postgres>,
+        torrent_id: i32,
+    ) -> Result<()> {
+        let matching_filters = sqlx::query!(
+            r#"
+                SELECT
+                    f.id,
+                    f.name,
+                    f.user_id,
+                    tg.id AS title_group_id
+                FROM notification_filters f
+                JOIN torrents t ON t.id = $1
+                JOIN edition_groups eg ON eg.id = t.edition_group_id
+                JOIN title_groups tg ON tg.id = eg.title_group_id
+                WHERE f.user_id != t.created_by_id
+                AND (
+                    CARDINALITY(f.artist_ids) = 0 OR
+                    EXISTS (
+                        SELECT 1 FROM affiliated_artists aa
+                        WHERE aa.title_group_id = tg.id AND aa.artist_id = ANY(f.artist_ids)
+                    )
+                )
+                AND (
+                    CARDINALITY(f.tags) = 0 OR
+                    EXISTS (SELECT 1 FROM UNNEST(tg.tags) tag WHERE LOWER(tag) = ANY(f.tags))
+                )
+                AND (CARDINALITY(f.content_types) = 0 OR tg.content_type = ANY(f.content_types))
+                AND (CARDINALITY(f.containers) = 0 OR LOWER(t.container) = ANY(f.containers))
+                AND (CARDINALITY(f.audio_codecs) = 0 OR t.audio_codec = ANY(f.audio_codecs))
+                AND (CARDINALITY(f.video_resolutions) = 0 OR t.video_resolution = ANY(f.video_resolutions))
+                AND (CARDINALITY(f.release_groups) = 0 OR LOWER(t.release_group) = ANY(f.release_groups))
+                -- anonymous uploads don't reveal their uploader
+                AND (
+                    CARDINALITY(f.uploader_ids) = 0 OR
+                    (t.created_by_id = ANY(f.uploader_ids) AND NOT t.uploaded_as_anonymous)
+                )
+                AND (f.min_size IS NULL OR t.size >= f.min_size)
+                ORDER BY f.user_id, f.id
+            "#,
+            torrent_id
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(Error::CouldNotCreateNotification)?;
+
+        // every matching filter is recorded for the feed, whether or not the user is notified
+        let filter_ids: Vec<i64> = matching_filters.iter().map(|f| f.id).collect();
+        sqlx::query!(
+            r#"
+                INSERT INTO notification_filter_torrents (notification_filter_id, torrent_id)
+                SELECT UNNEST($1::BIGINT[]), $2
+                ON CONFLICT DO NOTHING
+            "#,
+            &filter_ids,
+            torrent_id
+        )
         .execute(&mut **tx)
         .await
         .map_err(Error::CouldNotCreateNotification)?;
 
-        Ok(())
+        let (user_ids, payloads): (Vec<i32>, Vec<Value>) = matching_filters
+            .into_iter()
+            .map(|f| {
+                (
+                    f.user_id,
+                    json!({
+                        "title_group_id": f.title_group_id,
+                        "notification_filter_id": f.id,
+                        "notification_filter_name": f.name,
+                    }),
+                )
+            })
+            .unzip();
+
+        Self::notify_users_with_payloads(
+            tx,
+            NotificationType::FilteredTorrent,
+            &user_ids,
+            &payloads,
+            None,
+            torrent_id as i64,
+        )
+        .await
     }
 
+    // a subscriber isn't notified again while they haven't read the thread,
+    // nor once they can't read it anymore
     pub async fn notify_users_forum_thread_posts(
         tx: &mut Transaction<'_, Postgres>,
         thread_id: i64,
         post_id: i64,
         current_user_id: i32,
     ) -> Result<()> {
-        sqlx::query!(
+        let thread = sqlx::query!(
             r#"
-                WITH user_ids AS (
-                    SELECT user_id
-                    FROM subscriptions_forum_thread_posts
-                    WHERE forum_thread_id = $1
-                    AND user_id != $3
-                )
-                INSERT INTO notifications_forum_thread_posts (user_id, forum_post_id, forum_thread_id)
                 SELECT
-                    user_id,
-                    $2,
-                    $1
-                FROM user_ids u
-                -- don't notify the user who created the post
-                WHERE NOT EXISTS (
-                    SELECT 1
-                    FROM notifications_forum_thread_posts n
-                    WHERE n.user_id = u.user_id
-                      AND n.forum_thread_id = $1
-                      AND n.read_status = FALSE
-                )
+                    t.name,
+                    ARRAY(
+                        SELECT s.user_id
+                        FROM subscriptions_forum_thread_posts s
+                        JOIN users u ON u.id = s.user_id
+                        WHERE s.forum_thread_id = $1
+                        AND user_class_rank(u.class) >= user_class_rank(fsc.min_class_read)
+                        AND NOT EXISTS (
+                            SELECT 1
+                            FROM notifications n
+                            WHERE n.user_id = s.user_id
+                            AND n.notification_type = 'forum_thread_post'
+                            AND n.target_id = $1
+                            AND n.read_status = FALSE
+                        )
+                    ) AS "user_ids!"
+                FROM forum_threads t
+                JOIN forum_sub_categories fsc ON fsc.id = t.forum_sub_category_id
+                WHERE t.id = $1
             "#,
-            thread_id,
-            post_id,
-            current_user_id
+            thread_id
         )
-        .execute(&mut **tx)
+        .fetch_one(&mut **tx)
         .await
         .map_err(Error::CouldNotCreateNotification)?;
 
-        Ok(())
+        Self::notify_users(
+            tx,
+            NotificationType::ForumThreadPost,
+            &thread.user_ids,
+            Some(current_user_id),
+            thread_id,
+            json!({ "forum_post_id": post_id, "forum_thread_name": thread.name }),
+        )
+        .await
     }
 
     pub async fn find_unread_notifications_amount_forum_thread_posts(
@@ -81,64 +348,159 @@ impl ConnectionPool {
     ) -> Result<i64> {
         let count = sqlx::query_scalar!(
             r#"
-            SELECT COUNT(*)
-            FROM notifications_forum_thread_posts
-            WHERE user_id = $1 AND read_status = FALSE
+            SELECT COUNT(*) AS "count!"
+            FROM notifications
+            WHERE user_id = $1
+            AND notification_type = 'forum_thread_post'
+            AND read_status = FALSE
             "#,
             user_id
         )
         .fetch_one(self.borrow())
         .await
-        .map_err(Error::CouldNotGetUnreadNotifications)?
-        .unwrap_or(0);
+        .map_err(Error::CouldNotGetUnreadNotifications)?;
 
         Ok(count)
     }
 
-    pub async fn find_unread_notifications_amount_title_group_torrents(
+    pub async fn mark_notification_forum_thread_post_as_read(
         &self,
+        forum_thread_id: i64,
         user_id: i32,
-    ) -> Result<i64> {
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+                UPDATE notifications
+                SET read_status = TRUE
+                WHERE notification_type = 'forum_thread_post'
+                AND target_id = $1
+                AND user_id = $2
+                AND read_status = FALSE
+            "#,
+            forum_thread_id,
+            user_id
+        )
+        .execute(self.borrow())
+        .await
+        .map_err(Error::CouldNotFindForumThread)?;
+
+        Ok(())
+    }
+
+    pub async fn find_notifications(
+        &self,
+        user_id: i32,
+        query: &NotificationQuery,
+    ) -> Result<PaginatedResults<Notification>> {
+        let offset = (query.page.max(1) - 1) * query.page_size;
+
+        let total_items = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM notifications
+            WHERE user_id = $1
+            AND ($2::BOOLEAN = TRUE OR read_status = FALSE)
+            AND ($3::notification_type_enum IS NULL OR notification_type = $3)
+            "#,
+            user_id,
+            query.include_read,
+            query.notification_type as _
+        )
+        .fetch_one(self.borrow())
+        .await
+        .map_err(Error::CouldNotGetUnreadNotifications)?;
+
+        let results = sqlx::query_as!(
+            Notification,
+            r#"
+            SELECT
+                n.id,
+                n.notification_type AS "notification_type: _",
+                n.actor_id,
+                u.username AS "actor_username?",
+                n.target_id,
+                n.payload,
+                n.created_at,
+                n.read_status
+            FROM notifications n
+            LEFT JOIN users u ON u.id = n.actor_id
+            WHERE n.user_id = $1
+            AND ($2::BOOLEAN = TRUE OR n.read_status = FALSE)
+            AND ($3::notification_type_enum IS NULL OR n.notification_type = $3)
+            ORDER BY n.created_at DESC, n.id DESC
+            OFFSET $4
+            LIMIT $5
+            "#,
+            user_id,
+            query.include_read,
+            query.notification_type as _,
+            offset as i64,
+            query.page_size as i64
+        )
+        .fetch_all(self.borrow())
+        .await
+        .map_err(Error::CouldNotGetUnreadNotifications)?;
+
+        Ok(PaginatedResults {
+            results,
+            total_items,
+            page: query.page,
+            page_size: query.page_size,
+        })
+    }
+
+    pub async fn find_unread_notifications_amount(&self, user_id: i32) -> Result<i64> {
         let count = sqlx::query_scalar!(
             r#"
-            SELECT COUNT(*)
-            FROM notifications_title_group_torrents
+            SELECT COUNT(*) AS "count!"
+            FROM notifications
             WHERE user_id = $1 AND read_status = FALSE
             "#,
             user_id
         )
         .fetch_one(self.borrow())
         .await
-        .map_err(Error::CouldNotGetUnreadNotifications)?
-        .unwrap_or(0);
+        .map_err(Error::CouldNotGetUnreadNotifications)?;
 
         Ok(count)
     }
 
-    pub async fn find_notifications_forum_thread_posts(
+    pub async fn find_last_notification_id(&self, user_id: i32) -> Result<i64> {
+        let id = sqlx::query_scalar!(
+            r#"SELECT COALESCE(MAX(id), 0) AS "id!" FROM notifications WHERE user_id = $1"#,
+            user_id
+        )
+        .fetch_one(self.borrow())
+        .await
+        .map_err(Error::CouldNotGetUnreadNotifications)?;
+
+        Ok(id)
+    }
+
+    pub async fn find_notifications_after(
         &self,
         user_id: i32,
-        include_read: bool,
-    ) -> Result<Vec<NotificationForumThreadPost>> {
+        after_id: i64,
+    ) -> Result<Vec<Notification>> {
         let notifications = sqlx::query_as!(
-            NotificationForumThreadPost,
+            Notification,
             r#"
             SELECT
                 n.id,
-                n.forum_post_id,
-                p.forum_thread_id,
-                t.name AS forum_thread_name,
+                n.notification_type AS "notification_type: _",
+                n.actor_id,
+                u.username AS "actor_username?",
+                n.target_id,
+                n.payload,
                 n.created_at,
                 n.read_status
-            FROM notifications_forum_thread_posts n
-            JOIN forum_posts p ON p.id = n.forum_post_id
-            JOIN forum_threads t ON t.id = n.forum_thread_id
-            WHERE n.user_id = $1
-            AND ($2::bool = TRUE OR n.read_status = FALSE)
-            ORDER BY n.created_at DESC
+            FROM notifications n
+            LEFT JOIN users u ON u.id = n.actor_id
+            WHERE n.user_id = $1 AND n.id > $2
+            ORDER BY n.id
             "#,
             user_id,
-            include_read
+            after_id
         )
         .fetch_all(self.borrow())
         .await
@@ -147,24 +509,130 @@ impl ConnectionPool {
         Ok(notifications)
     }
 
-    pub async fn mark_notification_forum_thread_post_as_read(
+    pub async fn find_unread_notification_recipients(
         &self,
-        forum_thread_id: i64,
+        notification_types: &[NotificationType],
+        target_id: i64,
+    ) -> Result<Vec<i32>> {
+        let user_ids = sqlx::query_scalar!(
+            r#"
+            SELECT DISTINCT user_id
+            FROM notifications
+            WHERE notification_type = ANY($1) AND target_id = $2 AND read_status = FALSE
+            "#,
+            notification_types as _,
+            target_id
+        )
+        .fetch_all(self.borrow())
+        .await
+        .map_err(Error::CouldNotGetUnreadNotifications)?;
+
+        Ok(user_ids)
+    }
+
+    pub async fn mark_notifications_as_read(
+        &self,
+        notification_ids: &[i64],
         user_id: i32,
     ) -> Result<()> {
         sqlx::query!(
             r#"
-                UPDATE notifications_forum_thread_posts
+                UPDATE notifications
                 SET read_status = TRUE
-                WHERE forum_thread_id = $1 AND user_id = $2
+                WHERE id = ANY($1) AND user_id = $2
             "#,
-            forum_thread_id,
+            notification_ids,
             user_id
         )
         .execute(self.borrow())
-        .await
-        .map_err(Error::CouldNotFindForumThread)?;
+        .await?;
 
         Ok(())
     }
+
+    pub async fn mark_all_notifications_as_read(&self, user_id: i32) -> Result<()> {
+        sqlx::query!(
+            r#"
+                UPDATE notifications
+                SET read_status = TRUE
+                WHERE user_id = $1 AND read_status = FALSE
+            "#,
+            user_id
+        )
+        .execute(self.borrow())
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_notification(&self, notification_id: i64, user_id: i32) -> Result<()> {
+        let deleted = sqlx::query!(
+            r#"
+                DELETE FROM notifications
+                WHERE id = $1 AND user_id = $2
+            "#,
+            notification_id,
+            user_id
+        )
+        .execute(self.borrow())
+        .await?
+        .rows_affected();
+
+        if deleted == 0 {
+            return Err(Error::NotificationNotFound(notification_id));
+        }
+
+        Ok(())
+    }
+
+    pub async fn find_notification_preferences(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<NotificationPreference>> {
+        let preferences = sqlx::query_as!(
+            NotificationPreference,
+            r#"
+            SELECT
+                t.notification_type AS "notification_type!: _",
+                COALESCE(p.enabled, TRUE) AS "enabled!"
+            FROM UNNEST(ENUM_RANGE(NULL::notification_type_enum)) AS t(notification_type)
+            LEFT JOIN notification_preferences p
+                ON p.notification_type = t.notification_type AND p.user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(preferences)
+    }
+
+    pub async fn update_notification_preferences(
+        &self,
+        preferences: &[NotificationPreference],
+        user_id: i32,
+    ) -> Result<Vec<NotificationPreference>> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        for preference in preferences {
+            sqlx::query!(
+                r#"
+                    INSERT INTO notification_preferences (user_id, notification_type, enabled)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (user_id, notification_type) DO UPDATE SET enabled = EXCLUDED.enabled
+                "#,
+                user_id,
+                preference.notification_type as _,
+                preference.enabled
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        self.find_notification_preferences(user_id).await
+    }
 }
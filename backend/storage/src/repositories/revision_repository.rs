@@ -0,0 +1,172 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::revision::{Revision, RevisionResourceType},
+};
+use arcadia_common::error::{Error, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use sqlx::{Postgres, Transaction};
+use std::borrow::Borrow;
+
+fn to_json_object<T: Serialize>(value: &T) -> Result<Map<String, Value>> {
+    match serde_json::to_value(value) {
+        Ok(Value::Object(object)) => Ok(object),
+        Ok(_) => Err(Error::InvalidRevisionSnapshot(
+            "resource is not an object".to_string(),
+        )),
+        Err(e) => Err(Error::InvalidRevisionSnapshot(e.to_string())),
+    }
+}
+
+impl ConnectionPool {
+    // `previous` and `updated` are the whole resource before and after the edit,
+    // only the fields of `edited` (the form sent by the user) are kept in the revision
+    pub(crate) async fn record_revision<T: Serialize, E: Serialize>(
+        tx: &mut Transaction<'_, Postgres>,
+        resource_type: RevisionResourceType,
+        resource_id: i64,
+        edited_by_id: i32,
+        previous: &T,
+        updated: &T,
+        edited: &E,
+    ) -> Result<()> {
+        let previous = to_json_object(previous)?;
+        let updated = to_json_object(updated)?;
+        let editable_fields = to_json_object(edited)?;
+
+        let keep_editable_fields = |resource: &Map<String, Value>| -> Map<String, Value> {
+            editable_fields
+                .keys()
+                .map(|field| {
+                    (
+                        field.clone(),
+                        resource.get(field).cloned().unwrap_or(Value::Null),
+                    )
+                })
+                .collect()
+        };
+        let previous_snapshot = keep_editable_fields(&previous);
+        let snapshot = keep_editable_fields(&updated);
+
+        let diff: Map<String, Value> = snapshot
+            .iter()
+            .filter(|(field, new)| previous_snapshot.get(*field) != Some(new))
+            .map(|(field, new)| {
+                (
+                    field.clone(),
+                    json!({ "old": previous_snapshot[field], "new": new }),
+                )
+            })
+            .collect();
+
+        if diff.is_empty() {
+            return Ok(());
+        }
+
+        // the original state of the resource isn't stored anywhere else,
+        // so it is saved as the first revision, attributed to its creator
+        let created_by_id = previous
+            .get("created_by_id")
+            .and_then(Value::as_i64)
+            .map(|id| id as i32)
+            .unwrap_or(edited_by_id);
+        let created_at = previous
+            .get("created_at")
+            .and_then(Value::as_str)
+            .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+            .map(|date| date.with_timezone(&Utc));
+
+        sqlx::query!(
+            r#"
+            INSERT INTO revisions (resource_type, resource_id, edited_by_id, created_at, diff, snapshot)
+            SELECT $1, $2, $3, COALESCE($4, NOW()), '{}'::JSONB, $5
+            WHERE NOT EXISTS (
+                SELECT 1 FROM revisions WHERE resource_type = $1 AND resource_id = $2
+            )
+            "#,
+            resource_type as _,
+            resource_id,
+            created_by_id,
+            created_at,
+            Value::Object(previous_snapshot)
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(Error::CouldNotCreateRevision)?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO revisions (resource_type, resource_id, edited_by_id, diff, snapshot)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            resource_type as _,
+            resource_id,
+            edited_by_id,
+            Value::Object(diff),
+            Value::Object(snapshot)
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(Error::CouldNotCreateRevision)?;
+
+        Ok(())
+    }
+
+    pub async fn find_revisions(
+        &self,
+        resource_type: RevisionResourceType,
+        resource_id: i64,
+    ) -> Result<Vec<Revision>> {
+        let revisions = sqlx::query_as!(
+            Revision,
+            r#"
+            SELECT
+                r.id,
+                r.resource_type AS "resource_type: _",
+                r.resource_id,
+                r.edited_by_id,
+                u.username AS edited_by_username,
+                r.created_at,
+                r.diff,
+                r.snapshot
+            FROM revisions r
+            JOIN users u ON u.id = r.edited_by_id
+            WHERE r.resource_type = $1 AND r.resource_id = $2
+            ORDER BY r.created_at DESC, r.id DESC
+            "#,
+            resource_type as _,
+            resource_id
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(revisions)
+    }
+
+    pub async fn find_revision(&self, revision_id: i64) -> Result<Revision> {
+        let revision = sqlx::query_as!(
+            Revision,
+            r#"
+            SELECT
+                r.id,
+                r.resource_type AS "resource_type: _",
+                r.resource_id,
+                r.edited_by_id,
+                u.username AS edited_by_username,
+                r.created_at,
+                r.diff,
+                r.snapshot
+            FROM revisions r
+            JOIN users u ON u.id = r.edited_by_id
+            WHERE r.id = $1
+            "#,
+            revision_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::RevisionNotFound(revision_id))?;
+
+        Ok(revision)
+    }
+}
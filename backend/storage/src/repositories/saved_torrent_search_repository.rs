@@ -0,0 +1,101 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::{
+        saved_torrent_search::{SavedTorrentSearch, UserCreatedSavedTorrentSearch},
+        torrent::TorrentSearch,
+    },
+};
+use arcadia_common::error::{Error, Result};
+use sqlx::types::Json;
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub async fn create_saved_torrent_search(
+        &self,
+        saved_search: &UserCreatedSavedTorrentSearch,
+        user_id: i32,
+    ) -> Result<SavedTorrentSearch> {
+        let created_saved_search = sqlx::query_as!(
+            SavedTorrentSearch,
+            r#"
+            INSERT INTO saved_torrent_searches (user_id, name, search)
+            VALUES ($1, $2, $3)
+            RETURNING id, created_at, user_id, name, search AS "search: Json<TorrentSearch>"
+            "#,
+            user_id,
+            saved_search.name,
+            Json(&saved_search.search) as _
+        )
+        .fetch_one(self.borrow())
+        .await
+        .map_err(Error::CouldNotCreateSavedTorrentSearch)?;
+
+        Ok(created_saved_search)
+    }
+
+    pub async fn find_saved_torrent_searches(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<SavedTorrentSearch>> {
+        let saved_searches = sqlx::query_as!(
+            SavedTorrentSearch,
+            r#"
+            SELECT id, created_at, user_id, name, search AS "search: Json<TorrentSearch>"
+            FROM saved_torrent_searches
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+            user_id
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(saved_searches)
+    }
+
+    pub async fn find_saved_torrent_search(
+        &self,
+        saved_search_id: i64,
+        user_id: i32,
+    ) -> Result<SavedTorrentSearch> {
+        let saved_search = sqlx::query_as!(
+            SavedTorrentSearch,
+            r#"
+            SELECT id, created_at, user_id, name, search AS "search: Json<TorrentSearch>"
+            FROM saved_torrent_searches
+            WHERE id = $1 AND user_id = $2
+            "#,
+            saved_search_id,
+            user_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::SavedTorrentSearchNotFound(saved_search_id))?;
+
+        Ok(saved_search)
+    }
+
+    pub async fn delete_saved_torrent_search(
+        &self,
+        saved_search_id: i64,
+        user_id: i32,
+    ) -> Result<()> {
+        let deleted = sqlx::query!(
+            r#"
+            DELETE FROM saved_torrent_searches
+            WHERE id = $1 AND user_id = $2
+            "#,
+            saved_search_id,
+            user_id
+        )
+        .execute(self.borrow())
+        .await?
+        .rows_affected();
+
+        if deleted == 0 {
+            return Err(Error::SavedTorrentSearchNotFound(saved_search_id));
+        }
+
+        Ok(())
+    }
+}
@@ -1,12 +1,16 @@
 use crate::{
     connection_pool::ConnectionPool,
-    models::series::{
-        SearchSeriesQuery, Series, SeriesSearchResponse, SeriesSearchResult, UserCreatedSeries,
+    models::{
+        revision::RevisionResourceType,
+        series::{
+            EditedSeries, SearchSeriesQuery, Series, SeriesSearchResponse, SeriesSearchResult,
+            UserCreatedSeries,
+        },
     },
 };
 use arcadia_common::error::{Error, Result};
 use serde_json::Value;
-use sqlx::{query_as_unchecked, query_scalar};
+use sqlx::{query_as_unchecked, query_scalar, PgPool};
 use std::borrow::Borrow;
 
 impl ConnectionPool {
@@ -116,4 +120,74 @@ impl ConnectionPool {
             total_items,
         })
     }
+
+    pub async fn find_series_lite(&self, series_id: i64) -> Result<Series> {
+        let series = sqlx::query_as!(
+            Series,
+            r#"
+            SELECT *
+            FROM series
+            WHERE id = $1
+            "#,
+            series_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::SeriesWithIdNotFound(series_id))?;
+
+        Ok(series)
+    }
+
+    pub async fn update_series(
+        &self,
+        edited_series: &EditedSeries,
+        series_id: i64,
+        edited_by_id: i32,
+    ) -> Result<Series> {
+        let previous_series = self.find_series_lite(series_id).await?;
+
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let updated_series = sqlx::query_as!(
+            Series,
+            r#"
+            UPDATE series
+            SET
+                name = $2,
+                description = $3,
+                covers = $4,
+                banners = $5,
+                tags = $6,
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+            series_id,
+            edited_series.name,
+            edited_series.description,
+            &edited_series.covers,
+            edited_series.banners.as_deref(),
+            &edited_series.tags
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| Error::ErrorWhileUpdatingSeries(e.to_string()))?;
+
+        Self::record_revision(
+            &mut tx,
+            RevisionResourceType::Series,
+            series_id,
+            edited_by_id,
+            &previous_series,
+            &updated_series,
+            edited_series,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(updated_series)
+    }
 }
@@ -0,0 +1,79 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::{
+        common::PaginatedResults,
+        site_log::{SiteLog, SiteLogAction, SiteLogQuery},
+    },
+};
+use arcadia_common::error::Result;
+use sqlx::{Postgres, Transaction};
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub(crate) async fn create_site_log(
+        tx: &mut Transaction<'_, Postgres>,
+        action: SiteLogAction,
+        performed_by_id: i32,
+        message: &str,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO site_logs (action, performed_by_id, message)
+            VALUES ($1, $2, $3)
+            "#,
+            action as _,
+            performed_by_id,
+            message
+        )
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_site_logs(&self, query: &SiteLogQuery) -> Result<PaginatedResults<SiteLog>> {
+        let offset = (query.page.max(1) - 1) * query.page_size;
+
+        let total_items = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM site_logs
+            WHERE $1::site_log_action_enum IS NULL OR action = $1
+            "#,
+            query.action as _
+        )
+        .fetch_one(self.borrow())
+        .await?;
+
+        let results = sqlx::query_as!(
+            SiteLog,
+            r#"
+            SELECT
+                sl.id,
+                sl.created_at,
+                sl.performed_by_id,
+                u.username AS performed_by_username,
+                sl.action AS "action: _",
+                sl.message
+            FROM site_logs sl
+            JOIN users u ON u.id = sl.performed_by_id
+            WHERE $1::site_log_action_enum IS NULL OR sl.action = $1
+            ORDER BY sl.created_at DESC, sl.id DESC
+            OFFSET $2
+            LIMIT $3
+            "#,
+            query.action as _,
+            offset as i64,
+            query.page_size as i64
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(PaginatedResults {
+            results,
+            total_items,
+            page: query.page,
+            page_size: query.page_size,
+        })
+    }
+}
@@ -82,4 +82,82 @@ impl ConnectionPool {
         // TODO: check result.rows_affected()
         Ok(())
     }
+
+    pub async fn create_subscription_artist_torrents(
+        &self,
+        artist_id: i64,
+        current_user_id: i32,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO subscriptions_artist_torrents (user_id, artist_id)
+                VALUES ($1, $2)
+            "#,
+            current_user_id,
+            artist_id
+        )
+        .execute(self.borrow())
+        .await
+        .map_err(Error::CouldNotCreateSubscription)?;
+
+        Ok(())
+    }
+
+    pub async fn delete_subscription_artist_torrents(
+        &self,
+        artist_id: i64,
+        current_user_id: i32,
+    ) -> Result<()> {
+        let _ = sqlx::query!(
+            r#"
+                DELETE FROM subscriptions_artist_torrents
+                WHERE artist_id = $1 AND user_id = $2;
+            "#,
+            artist_id,
+            current_user_id
+        )
+        .execute(self.borrow())
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_subscription_series_torrents(
+        &self,
+        series_id: i64,
+        current_user_id: i32,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO subscriptions_series_torrents (user_id, series_id)
+                VALUES ($1, $2)
+            "#,
+            current_user_id,
+            series_id
+        )
+        .execute(self.borrow())
+        .await
+        .map_err(Error::CouldNotCreateSubscription)?;
+
+        Ok(())
+    }
+
+    pub async fn delete_subscription_series_torrents(
+        &self,
+        series_id: i64,
+        current_user_id: i32,
+    ) -> Result<()> {
+        let _ = sqlx::query!(
+            r#"
+                DELETE FROM subscriptions_series_torrents
+                WHERE series_id = $1 AND user_id = $2;
+            "#,
+            series_id,
+            current_user_id
+        )
+        .execute(self.borrow())
+        .await?;
+
+        Ok(())
+    }
 }
@@ -1,11 +1,16 @@
 use crate::{
     connection_pool::ConnectionPool,
-    models::title_group::{
-        ContentType, EditedTitleGroup, PublicRating, TitleGroup, UserCreatedTitleGroup,
+    models::{
+        revision::RevisionResourceType,
+        site_log::SiteLogAction,
+        title_group::{
+            ContentType, EditedTitleGroup, PublicRating, TitleGroup, UserCreatedTitleGroup,
+        },
     },
 };
 use arcadia_common::error::{Error, Result};
 use serde_json::{json, Value};
+use sqlx::PgPool;
 use std::borrow::Borrow;
 
 impl ConnectionPool {
@@ -39,13 +44,13 @@ impl ConnectionPool {
             .bind(&title_group_form.name_aliases)
             .bind(user_id)
             .bind(&title_group_form.description)
-            .bind(&title_group_form.original_language)
+            .bind(title_group_form.original_language)
             .bind(&title_group_form.country_from)
             .bind(&title_group_form.covers)
             .bind(&title_group_form.external_links)
             .bind(&title_group_form.embedded_links)
-            .bind(&title_group_form.category)
-            .bind(&title_group_form.content_type)
+            .bind(title_group_form.category)
+            .bind(title_group_form.content_type)
             .bind(title_group_form.original_release_date)
             .bind(Self::sanitize_title_group_tags(
                 title_group_form.tags.clone(),
@@ -367,7 +372,14 @@ impl ConnectionPool {
         &self,
         edited_title_group: &EditedTitleGroup,
         title_group_id: i32,
+        edited_by_id: i32,
     ) -> Result<TitleGroup> {
+        let previous_title_group = self.find_title_group(title_group_id).await?;
+
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
         let updated_title_group = sqlx::query_as!(
             TitleGroup,
             r#"
@@ -418,13 +430,196 @@ impl ConnectionPool {
             edited_title_group.tags as _,
             edited_title_group.screenshots as _
         )
-        .fetch_one(self.borrow())
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| Error::ErrorWhileUpdatingTitleGroup(e.to_string()))?;
 
+        Self::record_revision(
+            &mut tx,
+            RevisionResourceType::TitleGroup,
+            title_group_id as i64,
+            edited_by_id,
+            &previous_title_group,
+            &updated_title_group,
+            edited_title_group,
+        )
+        .await?;
+
+        tx.commit().await?;
+
         Ok(updated_title_group)
     }
 
+    pub async fn merge_title_groups(
+        &self,
+        source: &TitleGroup,
+        target: &TitleGroup,
+        performed_by_id: i32,
+    ) -> Result<()> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        // keep the links of the source title group, so that it doesn't get created again from them
+        sqlx::query!(
+            r#"
+            UPDATE title_groups
+            SET
+                external_links = external_links || ARRAY(
+                    SELECT unnest($2::TEXT[]) EXCEPT SELECT unnest(external_links)
+                ),
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+            target.id,
+            &source.external_links
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE edition_groups SET title_group_id = $2 WHERE title_group_id = $1",
+            source.id,
+            target.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE title_group_comments SET title_group_id = $2 WHERE title_group_id = $1",
+            source.id,
+            target.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE torrent_requests SET title_group_id = $2 WHERE title_group_id = $1",
+            source.id,
+            target.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // the rows that would be duplicates in the target title group are
+        // left behind and deleted along with the source title group
+        sqlx::query!(
+            r#"
+            UPDATE title_group_bookmarks SET title_group_id = $2
+            WHERE title_group_id = $1
+            AND user_id NOT IN (SELECT user_id FROM title_group_bookmarks WHERE title_group_id = $2)
+            "#,
+            source.id,
+            target.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE subscriptions_title_group_torrents SET title_group_id = $2
+            WHERE title_group_id = $1
+            AND user_id NOT IN (SELECT user_id FROM subscriptions_title_group_torrents WHERE title_group_id = $2)
+            "#,
+            source.id,
+            target.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE affiliated_artists SET title_group_id = $2
+            WHERE title_group_id = $1
+            AND artist_id NOT IN (SELECT artist_id FROM affiliated_artists WHERE title_group_id = $2)
+            "#,
+            source.id,
+            target.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE affiliated_entities SET title_group_id = $2
+            WHERE title_group_id = $1
+            AND entity_id NOT IN (SELECT entity_id FROM affiliated_entities WHERE title_group_id = $2)
+            "#,
+            source.id,
+            target.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE collage_entry SET title_group_id = $2
+            WHERE title_group_id = $1
+            AND collage_id NOT IN (
+                SELECT collage_id FROM collage_entry WHERE title_group_id = $2
+            )
+            "#,
+            source.id,
+            target.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // the source's rows are deleted in cascade, a link between the source and
+        // the target isn't kept since it would link the target to itself
+        sqlx::query!(
+            r#"
+            INSERT INTO similar_title_groups (group_1_id, group_2_id)
+            SELECT moved.group_1_id, moved.group_2_id
+            FROM similar_title_groups s
+            CROSS JOIN LATERAL (
+                SELECT
+                    CASE WHEN s.group_1_id = $1 THEN $2 ELSE s.group_1_id END AS group_1_id,
+                    CASE WHEN s.group_2_id = $1 THEN $2 ELSE s.group_2_id END AS group_2_id
+            ) moved
+            WHERE $1 IN (s.group_1_id, s.group_2_id)
+            AND moved.group_1_id <> moved.group_2_id
+            AND NOT EXISTS (
+                SELECT 1 FROM similar_title_groups existing
+                WHERE existing.group_1_id = moved.group_2_id
+                AND existing.group_2_id = moved.group_1_id
+            )
+            ON CONFLICT DO NOTHING
+            "#,
+            source.id as i64,
+            target.id as i64
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // collage entries are not deleted in cascade
+        sqlx::query!(
+            "DELETE FROM collage_entry WHERE title_group_id = $1",
+            source.id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM title_groups WHERE id = $1", source.id)
+            .execute(&mut *tx)
+            .await?;
+
+        Self::create_site_log(
+            &mut tx,
+            SiteLogAction::TitleGroupsMerged,
+            performed_by_id,
+            &format!(
+                "Title group {} ({}) was merged into title group {} ({})",
+                source.id, source.name, target.id, target.name
+            ),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
     pub async fn does_title_group_with_link_exist(
         &self,
         external_link: &str,
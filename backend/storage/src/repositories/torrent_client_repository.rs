@@ -0,0 +1,133 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::torrent_client::{
+        EditedTorrentClient, SeenTorrentClient, TorrentClient, UserCreatedTorrentClient,
+    },
+};
+use arcadia_common::error::{Error, Result};
+use arcadia_shared::tracker::models::torrent_client::TorrentClientRule;
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub async fn create_torrent_client(
+        &self,
+        client: &UserCreatedTorrentClient,
+        current_user_id: i32,
+    ) -> Result<TorrentClient> {
+        let created_client = sqlx::query_as!(
+            TorrentClient,
+            r#"
+                INSERT INTO torrent_clients (created_by_id, name, rule, peer_id_prefix, min_version,
+                                             max_version, user_agent_pattern, description)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id, created_at, updated_at, created_by_id, name,
+                          rule AS "rule: TorrentClientRule", peer_id_prefix, min_version,
+                          max_version, user_agent_pattern, description
+            "#,
+            current_user_id,
+            client.name,
+            client.rule as TorrentClientRule,
+            client.peer_id_prefix,
+            client.min_version,
+            client.max_version,
+            client.user_agent_pattern,
+            client.description
+        )
+        .fetch_one(self.borrow())
+        .await
+        .map_err(Error::CouldNotCreateTorrentClient)?;
+
+        Ok(created_client)
+    }
+
+    pub async fn find_torrent_clients(&self) -> Result<Vec<TorrentClient>> {
+        let clients = sqlx::query_as!(
+            TorrentClient,
+            r#"
+                SELECT id, created_at, updated_at, created_by_id, name,
+                       rule AS "rule: TorrentClientRule", peer_id_prefix, min_version,
+                       max_version, user_agent_pattern, description
+                FROM torrent_clients
+                ORDER BY name, peer_id_prefix
+            "#
+        )
+        .fetch_all(self.borrow())
+        .await
+        .map_err(Error::CouldNotFindTorrentClients)?;
+
+        Ok(clients)
+    }
+
+    pub async fn update_torrent_client(
+        &self,
+        client: &EditedTorrentClient,
+    ) -> Result<TorrentClient> {
+        let updated_client = sqlx::query_as!(
+            TorrentClient,
+            r#"
+                UPDATE torrent_clients
+                SET name = $2, rule = $3, peer_id_prefix = $4, min_version = $5,
+                    max_version = $6, user_agent_pattern = $7, description = $8,
+                    updated_at = NOW()
+                WHERE id = $1
+                RETURNING id, created_at, updated_at, created_by_id, name,
+                          rule AS "rule: TorrentClientRule", peer_id_prefix, min_version,
+                          max_version, user_agent_pattern, description
+            "#,
+            client.id,
+            client.name,
+            client.rule as TorrentClientRule,
+            client.peer_id_prefix,
+            client.min_version,
+            client.max_version,
+            client.user_agent_pattern,
+            client.description
+        )
+        .fetch_optional(self.borrow())
+        .await
+        .map_err(Error::CouldNotUpdateTorrentClient)?
+        .ok_or(Error::TorrentClientNotFound(client.id))?;
+
+        Ok(updated_client)
+    }
+
+    pub async fn delete_torrent_client(&self, client_id: i32) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM torrent_clients
+                WHERE id = $1
+            "#,
+            client_id
+        )
+        .execute(self.borrow())
+        .await
+        .map_err(Error::CouldNotDeleteTorrentClient)?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::TorrentClientNotFound(client_id));
+        }
+
+        Ok(())
+    }
+
+    pub async fn find_seen_torrent_clients(&self) -> Result<Vec<SeenTorrentClient>> {
+        let clients = sqlx::query_as!(
+            SeenTorrentClient,
+            r#"
+                SELECT
+                    agent,
+                    COUNT(*) AS "peers_amount!",
+                    COUNT(DISTINCT user_id) AS "users_amount!"
+                FROM peers
+                WHERE active = TRUE
+                GROUP BY agent
+                ORDER BY 2 DESC
+            "#
+        )
+        .fetch_all(self.borrow())
+        .await
+        .map_err(Error::CouldNotFindSeenTorrentClients)?;
+
+        Ok(clients)
+    }
+}
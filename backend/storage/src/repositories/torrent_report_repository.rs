@@ -1,8 +1,18 @@
 use crate::{
     connection_pool::ConnectionPool,
-    models::torrent_report::{TorrentReport, UserCreatedTorrentReport},
+    models::{
+        common::PaginatedResults,
+        notification::NotificationType,
+        torrent::{Torrent, TorrentToDelete},
+        torrent_report::{
+            TorrentReport, TorrentReportInboxEntry, TorrentReportInboxQuery, TorrentReportOutcome,
+            TorrentReportResolution, TorrentReportStatus, UserCreatedTorrentReport,
+        },
+    },
 };
 use arcadia_common::error::{Error, Result};
+use serde_json::json;
+use sqlx::PgPool;
 use std::borrow::Borrow;
 
 impl ConnectionPool {
@@ -14,13 +24,17 @@ impl ConnectionPool {
         let torrent_report = sqlx::query_as!(
             TorrentReport,
             r#"
-                INSERT INTO torrent_reports (reported_by_id, reported_torrent_id, description)
-                VALUES ($1, $2, $3)
-                RETURNING *
+                INSERT INTO torrent_reports (reported_by_id, reported_torrent_id, description, category)
+                VALUES ($1, $2, $3, $4)
+                RETURNING
+                    id, reported_at, reported_by_id, reported_torrent_id, description,
+                    category AS "category: _", claimed_by_id, claimed_at AS "claimed_at: _", resolved_by_id,
+                    resolved_at AS "resolved_at: _", outcome AS "outcome: _", resolution_comment
             "#,
             user_id,
             form.reported_torrent_id,
             form.description,
+            form.category as _,
         )
         .fetch_one(self.borrow())
         .await
@@ -28,4 +42,250 @@ impl ConnectionPool {
 
         Ok(torrent_report)
     }
+
+    pub async fn find_torrent_report(&self, report_id: i64) -> Result<TorrentReport> {
+        let torrent_report = sqlx::query_as!(
+            TorrentReport,
+            r#"
+                SELECT
+                    id, reported_at, reported_by_id, reported_torrent_id, description,
+                    category AS "category: _", claimed_by_id, claimed_at AS "claimed_at: _", resolved_by_id,
+                    resolved_at AS "resolved_at: _", outcome AS "outcome: _", resolution_comment
+                FROM torrent_reports
+                WHERE id = $1
+            "#,
+            report_id
+        )
+        .fetch_optional(self.borrow())
+        .await?
+        .ok_or(Error::TorrentReportNotFound(report_id))?;
+
+        Ok(torrent_report)
+    }
+
+    pub async fn find_torrent_report_inbox(
+        &self,
+        query: &TorrentReportInboxQuery,
+    ) -> Result<PaginatedResults<TorrentReportInboxEntry>> {
+        let offset = (query.page.max(1) - 1) * query.page_size;
+        let status = query.status.map(|status| match status {
+            TorrentReportStatus::Open => "open",
+            TorrentReportStatus::Claimed => "claimed",
+            TorrentReportStatus::Resolved => "resolved",
+        });
+
+        let total_items = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM torrent_reports tr
+            WHERE ($1::torrent_report_category_enum IS NULL OR tr.category = $1)
+            AND (
+                $2::TEXT IS NULL
+                OR ($2 = 'open' AND tr.claimed_by_id IS NULL AND tr.resolved_at IS NULL)
+                OR ($2 = 'claimed' AND tr.claimed_by_id IS NOT NULL AND tr.resolved_at IS NULL)
+                OR ($2 = 'resolved' AND tr.resolved_at IS NOT NULL)
+            )
+            AND ($3::INT IS NULL OR tr.claimed_by_id = $3)
+            "#,
+            query.category as _,
+            status,
+            query.claimed_by_id
+        )
+        .fetch_one(self.borrow())
+        .await?;
+
+        let results = sqlx::query_as!(
+            TorrentReportInboxEntry,
+            r#"
+            SELECT
+                tr.id,
+                tr.reported_at,
+                tr.reported_by_id,
+                reporter.username AS reported_by_username,
+                tr.reported_torrent_id,
+                tg.id AS title_group_id,
+                tg.name AS title_group_name,
+                tr.description,
+                tr.category AS "category: _",
+                tr.claimed_by_id,
+                claimer.username AS "claimed_by_username?",
+                tr.resolved_at AS "resolved_at: _",
+                tr.outcome AS "outcome: _"
+            FROM torrent_reports tr
+            JOIN users reporter ON reporter.id = tr.reported_by_id
+            LEFT JOIN users claimer ON claimer.id = tr.claimed_by_id
+            JOIN torrents t ON t.id = tr.reported_torrent_id
+            JOIN edition_groups eg ON eg.id = t.edition_group_id
+            JOIN title_groups tg ON tg.id = eg.title_group_id
+            WHERE ($1::torrent_report_category_enum IS NULL OR tr.category = $1)
+            AND (
+                $2::TEXT IS NULL
+                OR ($2 = 'open' AND tr.claimed_by_id IS NULL AND tr.resolved_at IS NULL)
+                OR ($2 = 'claimed' AND tr.claimed_by_id IS NOT NULL AND tr.resolved_at IS NULL)
+                OR ($2 = 'resolved' AND tr.resolved_at IS NOT NULL)
+            )
+            AND ($3::INT IS NULL OR tr.claimed_by_id = $3)
+            ORDER BY tr.reported_at ASC
+            OFFSET $4
+            LIMIT $5
+            "#,
+            query.category as _,
+            status,
+            query.claimed_by_id,
+            offset as i64,
+            query.page_size as i64
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(PaginatedResults {
+            results,
+            total_items,
+            page: query.page,
+            page_size: query.page_size,
+        })
+    }
+
+    pub async fn claim_torrent_report(
+        &self,
+        report_id: i64,
+        current_user_id: i32,
+    ) -> Result<TorrentReport> {
+        let claimed = sqlx::query_as!(
+            TorrentReport,
+            r#"
+                UPDATE torrent_reports
+                SET claimed_by_id = $2, claimed_at = NOW()
+                WHERE id = $1 AND resolved_at IS NULL
+                AND (claimed_by_id IS NULL OR claimed_by_id = $2)
+                RETURNING
+                    id, reported_at, reported_by_id, reported_torrent_id, description,
+                    category AS "category: _", claimed_by_id, claimed_at AS "claimed_at: _", resolved_by_id,
+                    resolved_at AS "resolved_at: _", outcome AS "outcome: _", resolution_comment
+            "#,
+            report_id,
+            current_user_id
+        )
+        .fetch_optional(self.borrow())
+        .await
+        .map_err(Error::CouldNotUpdateTorrentReport)?;
+
+        match claimed {
+            Some(report) => Ok(report),
+            None => {
+                let report = self.find_torrent_report(report_id).await?;
+                if report.resolved_at.is_some() {
+                    Err(Error::TorrentReportAlreadyResolved)
+                } else {
+                    Err(Error::TorrentReportClaimedByAnotherUser)
+                }
+            }
+        }
+    }
+
+    // the outcome is applied to the reported torrent in the same transaction,
+    // which is only needed when the report isn't dismissed
+    pub async fn resolve_torrent_report(
+        &self,
+        resolution: &TorrentReportResolution,
+        reported_torrent: Option<&Torrent>,
+        displayed_reason: String,
+        current_user_id: i32,
+    ) -> Result<TorrentReport> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let locked_report = sqlx::query!(
+            r#"
+                SELECT claimed_by_id, resolved_at
+                FROM torrent_reports
+                WHERE id = $1
+                FOR UPDATE
+            "#,
+            resolution.report_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(Error::TorrentReportNotFound(resolution.report_id))?;
+
+        if locked_report.resolved_at.is_some() {
+            return Err(Error::TorrentReportAlreadyResolved);
+        }
+        if locked_report
+            .claimed_by_id
+            .is_some_and(|id| id != current_user_id)
+        {
+            return Err(Error::TorrentReportClaimedByAnotherUser);
+        }
+
+        if let Some(torrent) = reported_torrent {
+            match resolution.outcome {
+                TorrentReportOutcome::Deleted => {
+                    Self::mark_torrent_deleted(
+                        &mut tx,
+                        &TorrentToDelete {
+                            id: torrent.id,
+                            reason: resolution.comment.clone(),
+                            displayed_reason: Some(displayed_reason),
+                        },
+                        current_user_id,
+                    )
+                    .await?;
+                }
+                TorrentReportOutcome::Edited => {
+                    if let Some(edited_torrent) = &resolution.edited_torrent {
+                        Self::apply_torrent_edit(&mut tx, torrent, edited_torrent, current_user_id)
+                            .await?;
+                    }
+                }
+                TorrentReportOutcome::Dismissed => {}
+            }
+        }
+
+        // resolving an unclaimed report claims it at the same time
+        let report = sqlx::query_as!(
+            TorrentReport,
+            r#"
+                UPDATE torrent_reports
+                SET
+                    claimed_by_id = $2,
+                    claimed_at = COALESCE(claimed_at, NOW()),
+                    resolved_by_id = $2,
+                    resolved_at = NOW(),
+                    outcome = $3,
+                    resolution_comment = $4
+                WHERE id = $1
+                RETURNING
+                    id, reported_at, reported_by_id, reported_torrent_id, description,
+                    category AS "category: _", claimed_by_id, claimed_at AS "claimed_at: _", resolved_by_id,
+                    resolved_at AS "resolved_at: _", outcome AS "outcome: _", resolution_comment
+            "#,
+            resolution.report_id,
+            current_user_id,
+            resolution.outcome as _,
+            resolution.comment
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(Error::CouldNotUpdateTorrentReport)?;
+
+        Self::notify_users(
+            &mut tx,
+            NotificationType::TorrentReportResolved,
+            &[report.reported_by_id],
+            Some(current_user_id),
+            report.id,
+            json!({
+                "torrent_id": report.reported_torrent_id,
+                "outcome": report.outcome,
+                "resolution_comment": report.resolution_comment,
+            }),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(report)
+    }
 }
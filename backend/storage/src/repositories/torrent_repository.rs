@@ -2,12 +2,16 @@ use crate::{
     connection_pool::ConnectionPool,
     models::{
         common::PaginatedResults,
-        edition_group::EditionGroupHierarchyLite,
+        edition_group::{EditionGroup, EditionGroupHierarchyLite, Source},
+        notification::NotificationType,
+        revision::RevisionResourceType,
+        site_log::SiteLogAction,
         title_group::TitleGroupHierarchyLite,
         torrent::{
             EditedTorrent, Features, Torrent, TorrentHierarchyLite, TorrentMinimal, TorrentSearch,
             TorrentToDelete, UploadedTorrent,
         },
+        torrent_log::{CheckedLog, TorrentLog},
     },
 };
 use arcadia_common::{
@@ -17,7 +21,7 @@ use arcadia_common::{
 use arcadia_shared::tracker::models::torrent::InfoHash;
 use bip_metainfo::{Info, InfoBuilder, Metainfo, MetainfoBuilder, PieceLength};
 use serde_json::{json, Value};
-use sqlx::{types::Json, PgPool};
+use sqlx::{types::Json, PgPool, Postgres, Transaction};
 use std::{borrow::Borrow, collections::HashMap, str::FromStr};
 
 #[derive(sqlx::FromRow)]
@@ -36,26 +40,45 @@ impl ConnectionPool {
     pub async fn create_torrent(
         &self,
         torrent_form: &UploadedTorrent,
+        logs: &[CheckedLog],
+        source: &str,
+        held_from_tracker: bool,
         user_id: i32,
     ) -> Result<Torrent> {
         let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
             .begin()
             .await?;
 
+        if !logs.is_empty() {
+            let source = sqlx::query_scalar!(
+                r#"SELECT source AS "source: Source" FROM edition_groups WHERE id = $1"#,
+                torrent_form.edition_group_id.0
+            )
+            .fetch_optional(&mut *tx)
+            .await?
+            .flatten();
+
+            if !matches!(source, Some(Source::Cd)) {
+                return Err(Error::LogsOnlyAllowedForCdSource);
+            }
+        }
+
         let create_torrent_query = r#"
             INSERT INTO torrents (
                 edition_group_id, created_by_id, release_name, release_group, description,
                 file_amount_per_type, uploaded_as_anonymous, file_list, mediainfo, trumpable,
                 staff_checked, size, duration, audio_codec, audio_bitrate, audio_bitrate_sampling,
                 audio_channels, video_codec, features, subtitle_languages, video_resolution,
-                video_resolution_other_x, video_resolution_other_y, container, languages, info_hash, info_dict, extras
+                video_resolution_other_x, video_resolution_other_y, container, languages, info_hash, info_dict, extras,
+                info_hash_v2, piece_layers, log_score, held_from_tracker
             ) VALUES (
                 $1, $2, $3, $4, $5, $6, $7,
                 $8, $9, $10, $11, $12, $13,
                 $14::audio_codec_enum, $15, $16::audio_bitrate_sampling_enum,
                 $17::audio_channels_enum, $18::video_codec_enum, $19::features_enum[],
                 $20::language_enum[], $21::video_resolution_enum, $22, $23, $24,
-                $25::language_enum[], $26::bytea, $27::bytea, $28::extras_enum[]
+                $25::language_enum[], $26::bytea, $27::bytea, $28::extras_enum[],
+                $29::bytea, $30::bytea, $31, $32
             )
             RETURNING *
         "#;
@@ -67,37 +90,76 @@ impl ConnectionPool {
 
         // We cannot trust that the uploader has set the private field properly,
         // so we need to recreate the info db with it forced, which requires a
-        // recomputation of info hash
+        // recomputation of info hash. The source is replaced by ours as well, so
+        // that the info hash differs from the one of the same torrent on other sites
         let info_normalized = InfoBuilder::new()
             .set_private_flag(Some(true))
+            .set_source(Some(source))
             .set_piece_length(PieceLength::Custom(info.piece_length() as usize))
             .build(1, info, |_| {})
             .map_err(|_| Error::TorrentFileInvalid)?;
-
-        let info_hash = bip_metainfo::InfoHash::from_bytes(&info_normalized);
+        let info_normalized =
+            Info::from_bytes(&info_normalized).map_err(|_| Error::TorrentFileInvalid)?;
+
+        // v2 clients announce with the SHA-256 info hash truncated to 20 bytes,
+        // which is also the only info hash pure v2 torrents have
+        let info_hash_v2 = info_normalized
+            .info_hash_v2()
+            .map(|hash| hash[..20].to_vec());
+        let info_hash = match (info_normalized.is_v1(), &info_hash_v2) {
+            (false, Some(info_hash_v2)) => info_hash_v2.clone(),
+            _ => info_normalized.info_hash().as_ref().to_vec(),
+        };
 
         // TODO: torrent metadata extraction should be done on the client side
-        let parent_folder = info.directory().map(|d| d.to_str().unwrap()).unwrap_or("");
-        let files = info
-            .files()
-            .map(|f| json!({"name": f.path().to_str().unwrap(), "size": f.length()}))
-            .collect::<Vec<_>>();
-
-        let file_list = json!({"parent_folder": parent_folder, "files": files});
+        let file_list = file_list(info);
 
         let file_amount_per_type = json!(info
             .files()
+            .filter(|file| !file.is_padding())
             .flat_map(|file| file.path().to_str().unwrap().split('.').next_back())
             .fold(std::collections::HashMap::new(), |mut acc, ext| {
                 *acc.entry(ext.to_string()).or_insert(0) += 1;
                 acc
             }));
 
-        // TODO: check if the torrent is trumpable: via a service ?
+        // the same content can be uploaded with a different info hash (other piece
+        // size, source tag, etc.), so identical file lists within a title group are
+        // duplicates as well
+        let duplicate_torrent_id = sqlx::query_scalar!(
+            r#"
+            SELECT t.id
+            FROM torrents t
+            JOIN edition_groups eg ON eg.id = t.edition_group_id
+            WHERE t.info_hash = ANY($1) OR t.info_hash_v2 = ANY($1)
+            OR (
+                t.deleted_at IS NULL
+                AND eg.title_group_id = (SELECT title_group_id FROM edition_groups WHERE id = $2)
+                AND t.file_list -> 'files' = $3
+            )
+            LIMIT 1
+            "#,
+            &[Some(&info_hash), info_hash_v2.as_ref()]
+                .into_iter()
+                .flatten()
+                .cloned()
+                .collect::<Vec<_>>(),
+            torrent_form.edition_group_id.0,
+            &file_list["files"]
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(duplicate_torrent_id) = duplicate_torrent_id {
+            return Err(Error::DuplicateTorrent(duplicate_torrent_id));
+        }
+
+        // flagged later on, when a better torrent is uploaded in the same edition
         let trumpable = String::from("");
         let size = metainfo
             .info()
             .files()
+            .filter(|file| !file.is_padding())
             .map(|file| file.length())
             .sum::<u64>() as i64;
 
@@ -157,8 +219,8 @@ impl ConnectionPool {
                     .map(|f| f.trim())
                     .collect::<Vec<&str>>(),
             )
-            .bind(info_hash.as_ref())
-            .bind(info.to_bytes())
+            .bind(&info_hash)
+            .bind(info_normalized.to_bytes())
             .bind(
                 torrent_form
                     .extras
@@ -167,10 +229,32 @@ impl ConnectionPool {
                     .map(|f| f.trim())
                     .collect::<Vec<&str>>(),
             )
+            .bind(&info_hash_v2)
+            .bind(metainfo.piece_layers())
+            .bind(logs.iter().map(|log| log.score).min())
+            .bind(held_from_tracker)
             .fetch_one(&mut *tx)
             .await
             .map_err(Error::CouldNotCreateTorrent)?;
 
+        for log in logs {
+            sqlx::query!(
+                r#"
+                INSERT INTO torrent_logs (torrent_id, ripper, log, score, checksum, deductions)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                "#,
+                uploaded_torrent.id,
+                log.ripper as _,
+                log.log,
+                log.score,
+                log.checksum as _,
+                Json(&log.deductions) as _
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(Error::CouldNotCreateTorrent)?;
+        }
+
         let title_group_info = sqlx::query_as!(
             TitleGroupInfoLite,
             r#"
@@ -184,13 +268,16 @@ impl ConnectionPool {
         .fetch_one(&mut *tx)
         .await?;
 
-        let _ = Self::notify_users_title_group_torrents(
-            &mut tx,
-            title_group_info.id,
-            uploaded_torrent.id,
-            user_id,
-        )
-        .await;
+        // held torrents can't be downloaded yet, users are notified once they are approved
+        if !held_from_tracker {
+            Self::notify_users_new_torrent(
+                &mut tx,
+                title_group_info.id,
+                uploaded_torrent.id,
+                user_id,
+            )
+            .await?;
+        }
 
         tx.commit().await?;
 
@@ -202,7 +289,8 @@ impl ConnectionPool {
             Torrent,
             r#"
             SELECT
-                id, info_hash as "info_hash: InfoHash", upload_factor, download_factor, seeders, leechers,
+                id, info_hash as "info_hash: InfoHash", info_hash_v2 as "info_hash_v2: InfoHash",
+                upload_factor, download_factor, seeders, leechers,
                 times_completed, snatched, edition_group_id, created_at, updated_at,
                 created_by_id,
                 deleted_at AS "deleted_at!: _",
@@ -210,7 +298,7 @@ impl ConnectionPool {
                 extras AS "extras!: _",
                 languages AS "languages!: _",
                 release_name, release_group, description, file_amount_per_type,
-                uploaded_as_anonymous, file_list, mediainfo, trumpable, staff_checked,
+                uploaded_as_anonymous, file_list, mediainfo, trumpable, staff_checked, held_from_tracker, log_score,
                 container, size, duration,
                 audio_codec AS "audio_codec: _",
                 audio_bitrate,
@@ -234,10 +322,66 @@ impl ConnectionPool {
         Ok(torrent)
     }
 
+    pub async fn find_torrent_info(&self, torrent_id: i32) -> Result<Info> {
+        let info_dict = sqlx::query_scalar!(
+            r#"
+            SELECT info_dict
+            FROM torrents
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            torrent_id
+        )
+        .fetch_one(self.borrow())
+        .await
+        .map_err(|_| Error::TorrentFileInvalid)?;
+
+        Info::from_bytes(info_dict).map_err(|_| Error::TorrentFileInvalid)
+    }
+
+    pub async fn find_torrent_logs(&self, torrent_id: i32) -> Result<Vec<TorrentLog>> {
+        let logs = sqlx::query_as!(
+            TorrentLog,
+            r#"
+            SELECT id, torrent_id, created_at, ripper AS "ripper: _", log, score,
+                   checksum AS "checksum: _", deductions AS "deductions: _"
+            FROM torrent_logs
+            WHERE torrent_id = $1
+            ORDER BY id
+            "#,
+            torrent_id
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(logs)
+    }
+
     pub async fn update_torrent(
         &self,
         edited_torrent: &EditedTorrent,
         torrent_id: i32,
+        edited_by_id: i32,
+    ) -> Result<Torrent> {
+        let previous_torrent = self.find_torrent(torrent_id).await?;
+
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        let updated_torrent =
+            Self::apply_torrent_edit(&mut tx, &previous_torrent, edited_torrent, edited_by_id)
+                .await?;
+
+        tx.commit().await?;
+
+        Ok(updated_torrent)
+    }
+
+    pub(crate) async fn apply_torrent_edit(
+        tx: &mut Transaction<'_, Postgres>,
+        previous_torrent: &Torrent,
+        edited_torrent: &EditedTorrent,
+        edited_by_id: i32,
     ) -> Result<Torrent> {
         let updated_torrent = sqlx::query_as!(
             Torrent,
@@ -266,7 +410,8 @@ impl ConnectionPool {
                 updated_at = NOW()
             WHERE id = $1 AND deleted_at IS NULL
             RETURNING
-                id, info_hash as "info_hash: InfoHash", upload_factor, download_factor, seeders, leechers,
+                id, info_hash as "info_hash: InfoHash", info_hash_v2 as "info_hash_v2: InfoHash",
+                upload_factor, download_factor, seeders, leechers,
                 times_completed, snatched, edition_group_id, created_at, updated_at,
                 created_by_id,
                 deleted_at AS "deleted_at!: _",
@@ -274,7 +419,7 @@ impl ConnectionPool {
                 extras AS "extras!: _",
                 languages AS "languages!: _",
                 release_name, release_group, description, file_amount_per_type,
-                uploaded_as_anonymous, file_list, mediainfo, trumpable, staff_checked,
+                uploaded_as_anonymous, file_list, mediainfo, trumpable, staff_checked, held_from_tracker, log_score,
                 container, size, duration,
                 audio_codec AS "audio_codec: _",
                 audio_bitrate,
@@ -287,7 +432,7 @@ impl ConnectionPool {
                 video_resolution_other_x,
                 video_resolution_other_y
             "#,
-            torrent_id,
+            previous_torrent.id,
             edited_torrent.release_name,
             edited_torrent.release_group,
             edited_torrent.description,
@@ -308,10 +453,21 @@ impl ConnectionPool {
             edited_torrent.languages as _,
             edited_torrent.extras as _
         )
-        .fetch_one(self.borrow())
+        .fetch_one(&mut **tx)
         .await
         .map_err(|e| Error::ErrorWhileUpdatingTorrent(e.to_string()))?;
 
+        Self::record_revision(
+            tx,
+            RevisionResourceType::Torrent,
+            previous_torrent.id as i64,
+            edited_by_id,
+            previous_torrent,
+            &updated_torrent,
+            edited_torrent,
+        )
+        .await?;
+
         Ok(updated_torrent)
     }
 
@@ -334,6 +490,7 @@ impl ConnectionPool {
             WHERE id = $1 AND deleted_at IS NULL
             RETURNING
                 info_dict,
+                piece_layers,
                 EXTRACT(EPOCH FROM created_at)::BIGINT AS "created_at_secs!",
                 release_name;
             "#,
@@ -357,6 +514,8 @@ impl ConnectionPool {
             .set_created_by(Some(tracker_name))
             .set_piece_length(PieceLength::Custom(info.piece_length() as usize))
             .set_private_flag(Some(true))
+            .set_source(info.source())
+            .set_piece_layers(torrent.piece_layers.as_deref())
             .build(1, &info, |_| {})
             .map_err(|_| Error::TorrentFileInvalid)?;
 
@@ -437,6 +596,7 @@ impl ConnectionPool {
                    NOT tgh.torrent_uploaded_as_anonymous)
                 )
             )
+            AND ($10::SMALLINT IS NULL OR tgh.torrent_log_score >= $10)
             AND (
                 $9::BIGINT IS NULL OR
                 EXISTS (SELECT 1 FROM affiliated_artists aa WHERE aa.title_group_id = tgh.title_group_id AND aa.artist_id = $9)
@@ -464,7 +624,8 @@ impl ConnectionPool {
             form.order_by_direction.to_string(),
             form.torrent_created_by_id,
             requesting_user_id,
-            form.artist_id
+            form.artist_id,
+            form.torrent_log_score_min
         )
         .fetch_all(self.borrow())
         .await
@@ -477,6 +638,7 @@ impl ConnectionPool {
             FROM title_group_hierarchy_lite tgh
             WHERE ($1::BOOLEAN IS NULL OR tgh.torrent_staff_checked = $1)
               AND ($2::BOOLEAN IS NULL OR tgh.torrent_reported = $2)
+              AND ($5::SMALLINT IS NULL OR tgh.torrent_log_score >= $5)
               AND (
                  $3::INT IS NULL OR
                  -- don't return torrents created as anonymous
@@ -490,7 +652,8 @@ impl ConnectionPool {
             form.torrent_staff_checked,
             form.torrent_reported,
             form.torrent_created_by_id,
-            requesting_user_id
+            requesting_user_id,
+            form.torrent_log_score_min
         )
         .fetch_one(self.borrow())
         .await
@@ -566,6 +729,7 @@ impl ConnectionPool {
                 release_group,
                 trumpable AS "trumpable!",
                 staff_checked AS "staff_checked!",
+                log_score,
                 COALESCE(languages, '{}') AS "languages!: _",
                 container AS "container!",
                 size AS "size!",
@@ -587,6 +751,7 @@ impl ConnectionPool {
 
             AND ($3::BOOLEAN IS NULL OR tar.staff_checked = $3)
             AND ($4::BOOLEAN IS NULL OR tar.reported = $4)
+            AND ($6::SMALLINT IS NULL OR tar.log_score >= $6)
             AND (
                $2::INT IS NULL OR
                -- don't return torrents created as anonymous
@@ -604,6 +769,7 @@ impl ConnectionPool {
             form.torrent_staff_checked,
             form.torrent_reported,
             requesting_user_id,
+            form.torrent_log_score_min,
         )
         .fetch_all(self.borrow())
         .await?;
@@ -682,6 +848,49 @@ impl ConnectionPool {
         Ok(serde_json::json!({"title_groups": search_results.title_groups}))
     }
 
+    pub async fn move_torrent(
+        &self,
+        torrent: &Torrent,
+        edition_group: &EditionGroup,
+        performed_by_id: i32,
+    ) -> Result<()> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE torrents
+            SET edition_group_id = $2, updated_at = NOW()
+            WHERE id = $1
+            "#,
+            torrent.id,
+            edition_group.id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Error::ErrorWhileUpdatingTorrent(e.to_string()))?;
+
+        Self::create_site_log(
+            &mut tx,
+            SiteLogAction::TorrentMoved,
+            performed_by_id,
+            &format!(
+                "Torrent {} ({}) was moved from edition group {} to edition group {} of title group {}",
+                torrent.id,
+                torrent.release_name.as_deref().unwrap_or_default(),
+                torrent.edition_group_id,
+                edition_group.id,
+                edition_group.title_group_id
+            ),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
     pub async fn remove_torrent(
         &self,
         torrent_to_delete: &TorrentToDelete,
@@ -691,8 +900,18 @@ impl ConnectionPool {
             .begin()
             .await?;
 
-        // TODO: Notify users about the deletion of the torrent
+        Self::mark_torrent_deleted(&mut tx, torrent_to_delete, current_user_id).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
 
+    pub(crate) async fn mark_torrent_deleted(
+        tx: &mut Transaction<'_, Postgres>,
+        torrent_to_delete: &TorrentToDelete,
+        current_user_id: i32,
+    ) -> Result<()> {
         sqlx::query!(
             r#"
             UPDATE torrents SET deleted_at = NOW(), deleted_by_id = $1 WHERE id = $2;
@@ -700,11 +919,31 @@ impl ConnectionPool {
             current_user_id,
             torrent_to_delete.id
         )
-        .execute(&mut *tx)
+        .execute(&mut **tx)
         .await
         .map_err(|error| Error::ErrorDeletingTorrent(error.to_string()))?;
 
-        tx.commit().await?;
+        // the uploader and everyone who downloaded or seeded it
+        let user_ids = sqlx::query_scalar!(
+            r#"
+            SELECT created_by_id AS "id!" FROM torrents WHERE id = $1
+            UNION
+            SELECT user_id FROM torrent_activities WHERE torrent_id = $1
+            "#,
+            torrent_to_delete.id
+        )
+        .fetch_all(&mut **tx)
+        .await?;
+
+        Self::notify_users(
+            tx,
+            NotificationType::TorrentDeleted,
+            &user_ids,
+            Some(current_user_id),
+            torrent_to_delete.id as i64,
+            json!({ "displayed_reason": torrent_to_delete.displayed_reason }),
+        )
+        .await?;
 
         Ok(())
     }
@@ -762,7 +1001,8 @@ impl ConnectionPool {
         let torrents = sqlx::query_as!(
             TorrentMinimal,
             r#"
-            SELECT id, created_at, ENCODE(info_hash, 'hex') as info_hash FROM torrents WHERE deleted_at IS NULL;
+            SELECT id, created_at, ENCODE(info_hash, 'hex') as info_hash FROM torrents
+            WHERE deleted_at IS NULL AND NOT held_from_tracker;
             "#
         )
         .fetch_all(self.borrow())
@@ -770,4 +1010,37 @@ impl ConnectionPool {
 
         Ok(torrents)
     }
+
+    /// Live torrent with exactly the same files (and parent folder) as the given
+    /// info dictionary, which can be seeded from the same data on disk
+    pub async fn find_cross_seedable_torrent(&self, info: &Info) -> Result<Option<i32>> {
+        let torrent_id = sqlx::query_scalar!(
+            r#"
+            SELECT id
+            FROM torrents
+            WHERE file_list_hash = decode(md5($1::JSONB::TEXT), 'hex')
+            AND file_list = $1
+            AND deleted_at IS NULL AND NOT held_from_tracker
+            ORDER BY id
+            LIMIT 1
+            "#,
+            file_list(info)
+        )
+        .fetch_optional(self.borrow())
+        .await?;
+
+        Ok(torrent_id)
+    }
+}
+
+// BEP 47 padding files only align the pieces, they are not part of the content
+fn file_list(info: &Info) -> Value {
+    let parent_folder = info.directory().map(|d| d.to_str().unwrap()).unwrap_or("");
+    let files = info
+        .files()
+        .filter(|f| !f.is_padding())
+        .map(|f| json!({"name": f.path().to_str().unwrap(), "size": f.length()}))
+        .collect::<Vec<_>>();
+
+    json!({"parent_folder": parent_folder, "files": files})
 }
@@ -1,9 +1,12 @@
 use crate::{
     connection_pool::ConnectionPool,
-    models::torrent_request::{EditedTorrentRequest, TorrentRequest, UserCreatedTorrentRequest},
+    models::{
+        notification::NotificationType,
+        torrent_request::{EditedTorrentRequest, TorrentRequest, UserCreatedTorrentRequest},
+    },
 };
 use arcadia_common::error::{Error, Result};
-use serde_json::Value;
+use serde_json::{json, Value};
 use sqlx::{query_as, query_scalar, PgPool};
 use std::borrow::Borrow;
 
@@ -182,6 +185,28 @@ impl ConnectionPool {
         .execute(&mut *tx)
         .await?;
 
+        // the request creator and everyone who put a bounty on it
+        let user_ids = query_scalar!(
+            r#"
+            SELECT created_by_id AS "id!" FROM torrent_requests WHERE id = $1
+            UNION
+            SELECT created_by_id FROM torrent_request_votes WHERE torrent_request_id = $1
+            "#,
+            torrent_request_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        Self::notify_users(
+            &mut tx,
+            NotificationType::TorrentRequestFilled,
+            &user_ids,
+            Some(current_user_id),
+            torrent_request_id,
+            json!({ "torrent_id": torrent_id }),
+        )
+        .await?;
+
         tx.commit().await?;
 
         Ok(())
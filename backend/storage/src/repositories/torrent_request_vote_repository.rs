@@ -1,8 +1,13 @@
 use crate::{
     connection_pool::ConnectionPool,
-    models::torrent_request_vote::{TorrentRequestVote, UserCreatedTorrentRequestVote},
+    models::{
+        notification::NotificationType,
+        torrent_request_vote::{TorrentRequestVote, UserCreatedTorrentRequestVote},
+    },
 };
 use arcadia_common::error::{Error, Result};
+use serde_json::json;
+use sqlx::PgPool;
 use std::borrow::Borrow;
 
 impl ConnectionPool {
@@ -21,6 +26,10 @@ impl ConnectionPool {
         // TODO config: check if the bounty is above the minimum set in the config
         // TODO config: check if the user's ratio stays above the minimum ratio set in the config (after the uploaded amount changes)
 
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
         let created_torrent_request_vote = sqlx::query_as!(
             TorrentRequestVote,
             r#"
@@ -46,10 +55,32 @@ impl ConnectionPool {
             torrent_request_vote.bounty_upload,
             torrent_request_vote.bounty_bonus_points
         )
-        .fetch_one(self.borrow())
+        .fetch_one(&mut *tx)
         .await
         .map_err(Error::CouldNotCreateTorrentRequestVote)?;
 
+        let request_creator_id = sqlx::query_scalar!(
+            "SELECT created_by_id FROM torrent_requests WHERE id = $1",
+            created_torrent_request_vote.torrent_request_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        Self::notify_users(
+            &mut tx,
+            NotificationType::TorrentRequestVoted,
+            &[request_creator_id],
+            Some(current_user.id),
+            created_torrent_request_vote.torrent_request_id,
+            json!({
+                "bounty_upload": created_torrent_request_vote.bounty_upload,
+                "bounty_bonus_points": created_torrent_request_vote.bounty_bonus_points,
+            }),
+        )
+        .await?;
+
+        tx.commit().await?;
+
         Ok(created_torrent_request_vote)
     }
 }
@@ -0,0 +1,186 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::{
+        common::PaginatedResults,
+        notification::NotificationType,
+        torrent_review::{
+            TorrentReview, TorrentReviewQueueQuery, TorrentReviewerStats, TorrentToReview,
+            UserCreatedTorrentReview,
+        },
+    },
+};
+use arcadia_common::error::{Error, Result};
+use serde_json::json;
+use sqlx::PgPool;
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub async fn find_torrent_review_queue(
+        &self,
+        query: &TorrentReviewQueueQuery,
+    ) -> Result<PaginatedResults<TorrentToReview>> {
+        let offset = (query.page.max(1) - 1) * query.page_size;
+
+        let total_items = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM torrents
+            WHERE deleted_at IS NULL AND NOT staff_checked
+            AND ($1::BOOLEAN IS NOT TRUE OR held_from_tracker)
+            "#,
+            query.held_only
+        )
+        .fetch_one(self.borrow())
+        .await?;
+
+        // oldest uploads first, so that held ones don't wait forever
+        let results = sqlx::query_as!(
+            TorrentToReview,
+            r#"
+            SELECT
+                t.id,
+                tg.id AS title_group_id,
+                tg.name AS title_group_name,
+                t.release_name,
+                t.created_by_id,
+                u.username AS "created_by_username?",
+                t.created_at,
+                t.held_from_tracker,
+                last_review.decision AS "last_decision?: _",
+                last_review.reason AS "last_reason?"
+            FROM torrents t
+            JOIN edition_groups eg ON eg.id = t.edition_group_id
+            JOIN title_groups tg ON tg.id = eg.title_group_id
+            LEFT JOIN users u ON u.id = t.created_by_id
+            LEFT JOIN LATERAL (
+                SELECT decision, reason
+                FROM torrent_reviews
+                WHERE torrent_id = t.id
+                ORDER BY created_at DESC
+                LIMIT 1
+            ) last_review ON TRUE
+            WHERE t.deleted_at IS NULL AND NOT t.staff_checked
+            AND ($1::BOOLEAN IS NOT TRUE OR t.held_from_tracker)
+            ORDER BY t.created_at ASC
+            OFFSET $2
+            LIMIT $3
+            "#,
+            query.held_only,
+            offset as i64,
+            query.page_size as i64
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(PaginatedResults {
+            results,
+            total_items,
+            page: query.page,
+            page_size: query.page_size,
+        })
+    }
+
+    pub async fn review_torrent(
+        &self,
+        review: &UserCreatedTorrentReview,
+        current_user_id: i32,
+    ) -> Result<TorrentReview> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        // approving releases a held torrent, rejecting deletes it
+        let reviewed_torrent = sqlx::query!(
+            r#"
+            UPDATE torrents t
+            SET
+                staff_checked = ($2::torrent_review_decision_enum = 'approved'),
+                held_from_tracker = t.held_from_tracker AND $2 != 'approved',
+                deleted_at = CASE WHEN $2 = 'rejected' THEN NOW() ELSE t.deleted_at END,
+                deleted_by_id = CASE WHEN $2 = 'rejected' THEN $3 ELSE t.deleted_by_id END
+            FROM (SELECT held_from_tracker FROM torrents WHERE id = $1) previous, edition_groups eg
+            WHERE t.id = $1 AND t.deleted_at IS NULL AND eg.id = t.edition_group_id
+            RETURNING
+                t.created_by_id,
+                t.held_from_tracker,
+                previous.held_from_tracker AS was_held_from_tracker,
+                eg.title_group_id
+            "#,
+            review.torrent_id,
+            review.decision as _,
+            current_user_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(Error::CouldNotCreateTorrentReview)?
+        .ok_or(Error::TorrentNotFound)?;
+
+        // the users weren't notified of the held upload
+        if reviewed_torrent.was_held_from_tracker && !reviewed_torrent.held_from_tracker {
+            Self::notify_users_new_torrent(
+                &mut tx,
+                reviewed_torrent.title_group_id,
+                review.torrent_id,
+                reviewed_torrent.created_by_id,
+            )
+            .await?;
+        }
+
+        let torrent_review = sqlx::query_as!(
+            TorrentReview,
+            r#"
+            INSERT INTO torrent_reviews (torrent_id, reviewed_by_id, decision, reason)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, torrent_id, reviewed_by_id, created_at, decision AS "decision: _", reason
+            "#,
+            review.torrent_id,
+            current_user_id,
+            review.decision as _,
+            review.reason
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(Error::CouldNotCreateTorrentReview)?;
+
+        Self::notify_users(
+            &mut tx,
+            NotificationType::TorrentReviewed,
+            &[reviewed_torrent.created_by_id],
+            Some(current_user_id),
+            review.torrent_id as i64,
+            json!({
+                "torrent_review_id": torrent_review.id,
+                "decision": torrent_review.decision,
+                "reason": torrent_review.reason,
+            }),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(torrent_review)
+    }
+
+    pub async fn find_torrent_reviewer_stats(&self) -> Result<Vec<TorrentReviewerStats>> {
+        let stats = sqlx::query_as!(
+            TorrentReviewerStats,
+            r#"
+            SELECT
+                r.reviewed_by_id AS reviewer_id,
+                u.username AS reviewer_username,
+                COUNT(*) FILTER (WHERE r.decision = 'approved') AS "approved!",
+                COUNT(*) FILTER (WHERE r.decision = 'changes_requested') AS "changes_requested!",
+                COUNT(*) FILTER (WHERE r.decision = 'rejected') AS "rejected!",
+                MAX(r.created_at) AS "last_reviewed_at!"
+            FROM torrent_reviews r
+            JOIN users u ON u.id = r.reviewed_by_id
+            GROUP BY r.reviewed_by_id, u.username
+            ORDER BY COUNT(*) DESC
+            "#
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(stats)
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::{
+        notification::NotificationType,
+        torrent_trump::{TorrentQuality, TorrentToTrump, TrumpRule},
+    },
+};
+use arcadia_common::error::{Error, Result};
+use serde_json::json;
+use sqlx::PgPool;
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub async fn find_trump_rules(&self) -> Result<Vec<TrumpRule>> {
+        let rules = sqlx::query_as!(
+            TrumpRule,
+            r#"
+            SELECT
+                content_type AS "content_type: _",
+                audio_bitrate_sampling_ranking AS "audio_bitrate_sampling_ranking: _",
+                audio_codec_ranking AS "audio_codec_ranking: _",
+                video_resolution_ranking AS "video_resolution_ranking: _",
+                video_codec_ranking AS "video_codec_ranking: _",
+                compare_log_score
+            FROM torrent_trump_rules
+            ORDER BY content_type
+            "#
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(rules)
+    }
+
+    pub async fn find_trump_rule_for_edition_group(
+        &self,
+        edition_group_id: i32,
+    ) -> Result<Option<TrumpRule>> {
+        let rule = sqlx::query_as!(
+            TrumpRule,
+            r#"
+            SELECT
+                r.content_type AS "content_type: _",
+                r.audio_bitrate_sampling_ranking AS "audio_bitrate_sampling_ranking: _",
+                r.audio_codec_ranking AS "audio_codec_ranking: _",
+                r.video_resolution_ranking AS "video_resolution_ranking: _",
+                r.video_codec_ranking AS "video_codec_ranking: _",
+                r.compare_log_score
+            FROM torrent_trump_rules r
+            JOIN title_groups tg ON tg.content_type = r.content_type
+            JOIN edition_groups eg ON eg.title_group_id = tg.id
+            WHERE eg.id = $1
+            "#,
+            edition_group_id
+        )
+        .fetch_optional(self.borrow())
+        .await?;
+
+        Ok(rule)
+    }
+
+    pub async fn upsert_trump_rule(&self, rule: &TrumpRule) -> Result<TrumpRule> {
+        let rule = sqlx::query_as!(
+            TrumpRule,
+            r#"
+            INSERT INTO torrent_trump_rules (
+                content_type, audio_bitrate_sampling_ranking, audio_codec_ranking,
+                video_resolution_ranking, video_codec_ranking, compare_log_score
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (content_type) DO UPDATE SET
+                audio_bitrate_sampling_ranking = EXCLUDED.audio_bitrate_sampling_ranking,
+                audio_codec_ranking = EXCLUDED.audio_codec_ranking,
+                video_resolution_ranking = EXCLUDED.video_resolution_ranking,
+                video_codec_ranking = EXCLUDED.video_codec_ranking,
+                compare_log_score = EXCLUDED.compare_log_score
+            RETURNING
+                content_type AS "content_type: _",
+                audio_bitrate_sampling_ranking AS "audio_bitrate_sampling_ranking: _",
+                audio_codec_ranking AS "audio_codec_ranking: _",
+                video_resolution_ranking AS "video_resolution_ranking: _",
+                video_codec_ranking AS "video_codec_ranking: _",
+                compare_log_score
+            "#,
+            rule.content_type as _,
+            rule.audio_bitrate_sampling_ranking as _,
+            rule.audio_codec_ranking as _,
+            rule.video_resolution_ranking as _,
+            rule.video_codec_ranking as _,
+            rule.compare_log_score
+        )
+        .fetch_one(self.borrow())
+        .await
+        .map_err(Error::CouldNotUpdateTrumpRule)?;
+
+        Ok(rule)
+    }
+
+    pub async fn find_edition_group_torrents_quality(
+        &self,
+        edition_group_id: i32,
+    ) -> Result<Vec<TorrentQuality>> {
+        let torrents = sqlx::query_as!(
+            TorrentQuality,
+            r#"
+            SELECT
+                id,
+                audio_bitrate_sampling AS "audio_bitrate_sampling: _",
+                audio_codec AS "audio_codec: _",
+                video_resolution AS "video_resolution: _",
+                video_codec AS "video_codec: _",
+                log_score
+            FROM torrents
+            WHERE edition_group_id = $1 AND deleted_at IS NULL
+            "#,
+            edition_group_id
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(torrents)
+    }
+
+    pub async fn flag_likely_trumped_torrents(
+        &self,
+        torrent_ids: &[i32],
+        trumping_torrent_id: i32,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE torrents
+            SET trumpable = 'likely trumped by torrent ' || $2
+            WHERE id = ANY($1)
+            "#,
+            torrent_ids,
+            trumping_torrent_id.to_string()
+        )
+        .execute(self.borrow())
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn trump_torrent(&self, form: &TorrentToTrump, current_user_id: i32) -> Result<()> {
+        let mut tx = <ConnectionPool as Borrow<PgPool>>::borrow(self)
+            .begin()
+            .await?;
+
+        // the trumped torrent is deleted, the trumping one must be live and about the same title
+        let trumped = sqlx::query_scalar!(
+            r#"
+            UPDATE torrents t
+            SET deleted_at = NOW(), deleted_by_id = $3, trumped_by_id = $2
+            WHERE t.id = $1 AND t.id != $2 AND t.deleted_at IS NULL
+            AND EXISTS (
+                SELECT 1
+                FROM torrents trumping
+                JOIN edition_groups trumping_eg ON trumping_eg.id = trumping.edition_group_id
+                JOIN edition_groups trumped_eg ON trumped_eg.id = t.edition_group_id
+                WHERE trumping.id = $2 AND trumping.deleted_at IS NULL
+                AND trumping_eg.title_group_id = trumped_eg.title_group_id
+            )
+            RETURNING t.id
+            "#,
+            form.trumped_torrent_id,
+            form.trumping_torrent_id,
+            current_user_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(Error::CouldNotTrumpTorrent)?;
+
+        if trumped.is_none() {
+            return Err(Error::InvalidTorrentTrump);
+        }
+
+        // every user who downloaded the .torrent file has an activity on it
+        let user_ids = sqlx::query_scalar!(
+            "SELECT user_id FROM torrent_activities WHERE torrent_id = $1",
+            form.trumped_torrent_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        Self::notify_users(
+            &mut tx,
+            NotificationType::TorrentTrumped,
+            &user_ids,
+            Some(current_user_id),
+            form.trumped_torrent_id as i64,
+            json!({
+                "trumping_torrent_id": form.trumping_torrent_id,
+                "reason": form.reason,
+            }),
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{connection_pool::ConnectionPool, models::tracker_flag::TrackerFlag};
+use arcadia_common::error::{Error, Result};
+use arcadia_shared::tracker::models::tracker_flag_update::TrackerFlagKind;
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub async fn find_tracker_flags(
+        &self,
+        user_id: Option<i32>,
+        include_resolved: bool,
+        limit: i64,
+        page: i64,
+    ) -> Result<Vec<TrackerFlag>> {
+        let flags = sqlx::query_as!(
+            TrackerFlag,
+            r#"
+                SELECT id, created_at, kind AS "kind: TrackerFlagKind", user_id, torrent_id,
+                       encode(peer_id, 'hex') AS "peer_id!", ip, agent, uploaded_delta,
+                       downloaded_delta, announce_interval, occurrences, credit_withheld,
+                       resolved_by_id, resolved_at
+                FROM tracker_flags
+                WHERE ($1::INT IS NULL OR user_id = $1)
+                  AND ($2 OR resolved_at IS NULL)
+                ORDER BY created_at DESC
+                LIMIT $3 OFFSET $4
+            "#,
+            user_id,
+            include_resolved,
+            limit,
+            (page - 1) * limit
+        )
+        .fetch_all(self.borrow())
+        .await
+        .map_err(Error::CouldNotFindTrackerFlags)?;
+
+        Ok(flags)
+    }
+
+    pub async fn resolve_tracker_flag(
+        &self,
+        flag_id: i64,
+        current_user_id: i32,
+    ) -> Result<TrackerFlag> {
+        let flag = sqlx::query_as!(
+            TrackerFlag,
+            r#"
+                UPDATE tracker_flags
+                SET resolved_by_id = $2, resolved_at = NOW()
+                WHERE id = $1
+                RETURNING id, created_at, kind AS "kind: TrackerFlagKind", user_id, torrent_id,
+                          encode(peer_id, 'hex') AS "peer_id!", ip, agent, uploaded_delta,
+                          downloaded_delta, announce_interval, occurrences, credit_withheld,
+                          resolved_by_id, resolved_at
+            "#,
+            flag_id,
+            current_user_id
+        )
+        .fetch_optional(self.borrow())
+        .await
+        .map_err(Error::CouldNotResolveTrackerFlag)?
+        .ok_or(Error::TrackerFlagNotFound(flag_id))?;
+
+        Ok(flag)
+    }
+}
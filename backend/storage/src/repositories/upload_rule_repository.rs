@@ -0,0 +1,121 @@
+use crate::{
+    connection_pool::ConnectionPool,
+    models::upload_rule::{UploadRule, UserCreatedUploadRule},
+};
+use arcadia_common::error::{Error, Result};
+use std::borrow::Borrow;
+
+impl ConnectionPool {
+    pub async fn upsert_upload_rule(&self, rule: &UserCreatedUploadRule) -> Result<UploadRule> {
+        let rule = sqlx::query_as!(
+            UploadRule,
+            r#"
+                INSERT INTO upload_rules (
+                    content_type, category, allowed_file_extensions, allowed_containers,
+                    required_fields, min_piece_length, max_piece_length, release_name_pattern
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (content_type, category) DO UPDATE SET
+                    allowed_file_extensions = EXCLUDED.allowed_file_extensions,
+                    allowed_containers = EXCLUDED.allowed_containers,
+                    required_fields = EXCLUDED.required_fields,
+                    min_piece_length = EXCLUDED.min_piece_length,
+                    max_piece_length = EXCLUDED.max_piece_length,
+                    release_name_pattern = EXCLUDED.release_name_pattern,
+                    updated_at = NOW()
+                RETURNING
+                    id, content_type AS "content_type: _", category AS "category: _",
+                    allowed_file_extensions, allowed_containers, required_fields,
+                    min_piece_length, max_piece_length, release_name_pattern,
+                    created_at, updated_at
+            "#,
+            rule.content_type as _,
+            rule.category as _,
+            &rule
+                .allowed_file_extensions
+                .iter()
+                .map(|ext| ext.trim_start_matches('.').to_lowercase())
+                .collect::<Vec<_>>(),
+            &rule
+                .allowed_containers
+                .iter()
+                .map(|container| container.to_lowercase())
+                .collect::<Vec<_>>(),
+            &rule.required_fields,
+            rule.min_piece_length,
+            rule.max_piece_length,
+            rule.release_name_pattern
+        )
+        .fetch_one(self.borrow())
+        .await
+        .map_err(Error::CouldNotCreateUploadRule)?;
+
+        Ok(rule)
+    }
+
+    pub async fn find_upload_rules(&self) -> Result<Vec<UploadRule>> {
+        let rules = sqlx::query_as!(
+            UploadRule,
+            r#"
+                SELECT
+                    id, content_type AS "content_type: _", category AS "category: _",
+                    allowed_file_extensions, allowed_containers, required_fields,
+                    min_piece_length, max_piece_length, release_name_pattern,
+                    created_at, updated_at
+                FROM upload_rules
+                ORDER BY content_type, category NULLS FIRST
+            "#
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(rules)
+    }
+
+    // the rules of the content type without category, and those of the exact category
+    pub async fn find_upload_rules_for_edition_group(
+        &self,
+        edition_group_id: i32,
+    ) -> Result<Vec<UploadRule>> {
+        let rules = sqlx::query_as!(
+            UploadRule,
+            r#"
+                SELECT
+                    r.id, r.content_type AS "content_type: _", r.category AS "category: _",
+                    r.allowed_file_extensions, r.allowed_containers, r.required_fields,
+                    r.min_piece_length, r.max_piece_length, r.release_name_pattern,
+                    r.created_at, r.updated_at
+                FROM upload_rules r
+                JOIN title_groups tg ON tg.content_type = r.content_type
+                JOIN edition_groups eg ON eg.title_group_id = tg.id
+                WHERE eg.id = $1
+                AND (r.category IS NULL OR r.category = tg.category)
+                ORDER BY r.category NULLS FIRST
+            "#,
+            edition_group_id
+        )
+        .fetch_all(self.borrow())
+        .await?;
+
+        Ok(rules)
+    }
+
+    pub async fn delete_upload_rule(&self, rule_id: i32) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM upload_rules
+                WHERE id = $1
+            "#,
+            rule_id
+        )
+        .execute(self.borrow())
+        .await
+        .map_err(Error::CouldNotDeleteUploadRule)?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::UploadRuleNotFound(rule_id));
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,14 @@
 use crate::{
     connection_pool::ConnectionPool,
-    models::user::{
-        EditedUser, PublicUser, UserClass, UserCreatedUserWarning, UserMinimal, UserWarning,
+    models::{
+        notification::NotificationType,
+        user::{
+            EditedUser, PublicUser, UserClass, UserCreatedUserWarning, UserMinimal, UserWarning,
+        },
     },
 };
 use arcadia_common::error::{Error, Result};
+use serde_json::json;
 use sqlx::PgPool;
 use std::borrow::Borrow;
 
@@ -130,6 +134,20 @@ impl ConnectionPool {
         .await
         .map_err(Error::CouldNotCreateGift)?;
 
+        Self::notify_users(
+            &mut tx,
+            NotificationType::UserWarning,
+            &[user_warning.user_id],
+            Some(current_user_id),
+            user_warning.id,
+            json!({
+                "reason": user_warning.reason,
+                "ban": user_warning.ban,
+                "expires_at": user_warning.expires_at,
+            }),
+        )
+        .await?;
+
         tx.commit().await?;
 
         Ok(user_warning)
@@ -388,16 +388,80 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/api/notifications/forum-thread-posts": {
+    "/api/notifications": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
+        get: operations["Get notifications"];
         put?: never;
-        post: operations["Get notifications for forum thread posts"];
+        post?: never;
+        delete: operations["Remove notification"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/notifications/stream": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get: operations["Stream notifications"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/notifications/read": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put: operations["Mark notifications as read"];
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/notifications/read-all": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put: operations["Mark all notifications as read"];
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/notifications/preferences": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get: operations["Get notification preferences"];
+        put: operations["Edit notification preferences"];
+        post?: never;
         delete?: never;
         options?: never;
         head?: never;
@@ -1579,17 +1643,36 @@ export interface components {
             id: number;
             name?: string | null;
         };
-        NotificationForumThreadPost: {
+        Notification: {
+            /** Format: int32 */
+            actor_id?: number | null;
+            actor_username?: string | null;
             /** Format: date-time */
             created_at: string;
             /** Format: int64 */
-            forum_post_id: number;
-            /** Format: int64 */
-            forum_thread_id: number;
-            forum_thread_name: string;
-            /** Format: int64 */
             id: number;
+            notification_type: components["schemas"]["NotificationType"];
+            payload: Record<string, never>;
             read_status: boolean;
+            /** Format: int64 */
+            target_id: number;
+        };
+        NotificationPreference: {
+            enabled: boolean;
+            notification_type: components["schemas"]["NotificationType"];
+        };
+        NotificationQuery: {
+            include_read: boolean;
+            notification_type?: null | components["schemas"]["NotificationType"];
+            /** Format: int32 */
+            page: number;
+            /** Format: int32 */
+            page_size: number;
+        };
+        /** @enum {string} */
+        NotificationType: "forum_post_mention" | "forum_post_quote" | "torrent_request_filled" | "torrent_request_voted" | "torrent_deleted" | "torrent_trumped" | "torrent_report_resolved" | "conversation_message" | "user_warning" | "gift_received" | "forum_thread_post" | "title_group_torrent" | "artist_torrent" | "series_torrent" | "filtered_torrent" | "torrent_reviewed";
+        NotificationsToMarkAsRead: {
+            notification_ids: number[];
         };
         PaginatedResults_CollageSearchResult: {
             /** Format: int32 */
@@ -1666,6 +1749,28 @@ export interface components {
             /** Format: int64 */
             total_items: number;
         };
+        PaginatedResults_Notification: {
+            /** Format: int32 */
+            page: number;
+            /** Format: int32 */
+            page_size: number;
+            results: {
+                /** Format: int32 */
+                actor_id?: number | null;
+                actor_username?: string | null;
+                /** Format: date-time */
+                created_at: string;
+                /** Format: int64 */
+                id: number;
+                notification_type: components["schemas"]["NotificationType"];
+                payload: Record<string, never>;
+                read_status: boolean;
+                /** Format: int64 */
+                target_id: number;
+            }[];
+            /** Format: int64 */
+            total_items: number;
+        };
         PaginatedResults_TitleGroupHierarchyLite: {
             /** Format: int32 */
             page: number;
@@ -3455,10 +3560,13 @@ export interface operations {
             };
         };
     };
-    "Get notifications for forum thread posts": {
+    "Get notifications": {
         parameters: {
             query: {
                 include_read: boolean;
+                notification_type?: null | components["schemas"]["NotificationType"];
+                page: number;
+                page_size: number;
             };
             header?: never;
             path?: never;
@@ -3472,7 +3580,131 @@ export interface operations {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["NotificationForumThreadPost"][];
+                    "application/json": components["schemas"]["PaginatedResults_Notification"];
+                };
+            };
+        };
+    };
+    "Remove notification": {
+        parameters: {
+            query: {
+                id: number;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Successfully removed the notification */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+        };
+    };
+    "Stream notifications": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Server-sent events: notification, conversation_message, staff_pm_message and unread_amounts. The stream ends when the token it was opened with expires or gets invalidated, or when the user is banned. Browsers' EventSource cannot send the Authorization header, clients are expected to read the stream with fetch (e.g. @microsoft/fetch-event-source) and to reconnect with a refreshed token */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "text/event-stream": unknown;
+                };
+            };
+        };
+    };
+    "Mark notifications as read": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["NotificationsToMarkAsRead"];
+            };
+        };
+        responses: {
+            /** @description Successfully marked the notifications as read */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+        };
+    };
+    "Mark all notifications as read": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Successfully marked all the notifications as read */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content?: never;
+            };
+        };
+    };
+    "Get notification preferences": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Successfully got the notification preferences */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["NotificationPreference"][];
+                };
+            };
+        };
+    };
+    "Edit notification preferences": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["NotificationPreference"][];
+            };
+        };
+        responses: {
+            /** @description Successfully edited the notification preferences */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["NotificationPreference"][];
                 };
             };
         };
@@ -1,8 +1,12 @@
-import type { components } from '@/api-schema/schema.js'
+import type { components } from '@/api-schema/schema'
 import api from './api.ts'
 
-export type NotificationForumThreadPost = components['schemas']['NotificationForumThreadPost']
+export type Notification = components['schemas']['Notification']
 
-export const getNotificationsForumThreadPosts = async (includeRead: boolean): Promise<NotificationForumThreadPost[]> => {
-  return (await api.get(`notifications/forum-thread-posts?include_read=${includeRead}`)).data
+export type NotificationQuery = components['schemas']['NotificationQuery']
+
+export type PaginatedResults_Notification = components['schemas']['PaginatedResults_Notification']
+
+export const getNotifications = async (query: NotificationQuery): Promise<PaginatedResults_Notification> => {
+  return (await api.get<PaginatedResults_Notification>('/notifications', { params: query })).data
 }
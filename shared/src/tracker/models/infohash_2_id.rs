@@ -24,6 +24,7 @@ impl DerefMut for Map {
 pub struct DBImportInfohash2Id {
     pub id: i32,
     pub info_hash: InfoHash,
+    pub info_hash_v2: Option<InfoHash>,
 }
 
 impl Map {
@@ -33,7 +34,8 @@ impl Map {
             r#"
                     SELECT
                         id,
-                        info_hash as "info_hash: InfoHash"
+                        info_hash as "info_hash: InfoHash",
+                        info_hash_v2 as "info_hash_v2: InfoHash"
                     FROM torrents
                 "#
         )
@@ -44,6 +46,10 @@ impl Map {
         let mut map: Map = Map(IndexMap::with_capacity(rows.len()));
         for r in rows {
             map.insert(r.info_hash, r.id as u32);
+            // hybrid torrents are announced with either info hash
+            if let Some(info_hash_v2) = r.info_hash_v2 {
+                map.insert(info_hash_v2, r.id as u32);
+            }
         }
 
         map
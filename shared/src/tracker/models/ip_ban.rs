@@ -0,0 +1,84 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use sqlx::{types::ipnetwork::IpNetwork, PgPool};
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+};
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct APIInsertIpBan {
+    pub id: u32,
+    pub ip_range: IpNetwork,
+}
+
+/// Banned ip ranges, indexed by prefix length so that a lookup only costs
+/// one hash set access per distinct prefix length in use
+#[derive(Debug, Default)]
+pub struct Map {
+    bans: IndexMap<u32, IpNetwork>,
+    // (is_ipv4, prefix length) -> network addresses
+    networks: HashMap<(bool, u8), HashSet<IpAddr>>,
+}
+
+impl Map {
+    pub fn insert(&mut self, id: u32, ip_range: IpNetwork) {
+        self.bans.insert(id, ip_range);
+        self.rebuild_index();
+    }
+
+    pub fn remove(&mut self, id: u32) {
+        self.bans.shift_remove(&id);
+        self.rebuild_index();
+    }
+
+    pub fn len(&self) -> usize {
+        self.bans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bans.is_empty()
+    }
+
+    pub fn is_banned(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        self.networks
+            .iter()
+            .filter(|((is_ipv4, _), _)| *is_ipv4 == ip.is_ipv4())
+            .any(|((_, prefix), networks)| {
+                IpNetwork::new(ip, *prefix)
+                    .is_ok_and(|network| networks.contains(&network.network()))
+            })
+    }
+
+    // bans are rarely edited, rebuilding keeps the lookup structure simple
+    fn rebuild_index(&mut self) {
+        self.networks.clear();
+        for ip_range in self.bans.values() {
+            self.networks
+                .entry((ip_range.is_ipv4(), ip_range.prefix()))
+                .or_default()
+                .insert(ip_range.network());
+        }
+    }
+
+    pub async fn from_database(db: &PgPool) -> Self {
+        let rows = sqlx::query!(
+            r#"
+                SELECT id, ip_range
+                FROM ip_bans
+            "#
+        )
+        .fetch_all(db)
+        .await
+        .expect("could not get ip bans");
+
+        let mut map = Map::default();
+        for r in rows {
+            map.bans.insert(r.id as u32, r.ip_range);
+        }
+        map.rebuild_index();
+
+        map
+    }
+}
@@ -5,13 +5,16 @@ use std::hash::Hash;
 
 pub mod env;
 pub mod infohash_2_id;
+pub mod ip_ban;
 pub mod passkey_2_id;
 pub mod peer;
 pub mod peer_id;
 pub mod peer_update;
 pub mod torrent;
 pub mod torrent_activity_update;
+pub mod torrent_client;
 pub mod torrent_update;
+pub mod tracker_flag_update;
 pub mod user;
 pub mod user_update;
 
@@ -30,6 +30,9 @@ pub struct Peer {
     pub updated_at: DateTime<Utc>,
     pub uploaded: u64,
     pub downloaded: u64,
+    /// What the peer reported downloading between its last two announces,
+    /// the last one being at `updated_at`
+    pub downloaded_delta: u64,
 }
 
 impl Peer {
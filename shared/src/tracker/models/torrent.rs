@@ -31,6 +31,8 @@ pub struct Torrent {
 pub struct APIInsertTorrent {
     pub id: u32,
     pub info_hash: InfoHash,
+    /// truncated v2 info hash of v2 and hybrid torrents
+    pub info_hash_v2: Option<InfoHash>,
     pub is_deleted: bool,
     pub seeders: u32,
     pub leechers: u32,
@@ -65,8 +67,9 @@ impl Map {
                         seeders,
                         leechers,
                         times_completed,
+                        -- torrents held until approved by staff cannot be announced either
                         CASE
-                            WHEN deleted_at IS NOT NULL THEN TRUE
+                            WHEN deleted_at IS NOT NULL OR held_from_tracker THEN TRUE
                             ELSE FALSE
                         END AS "is_deleted!"
                     FROM torrents
@@ -138,6 +141,7 @@ impl Map {
                             .expect("Peer with null updated_at found in database."),
                         uploaded: peer.uploaded as u64,
                         downloaded: peer.downloaded as u64,
+                        downloaded_delta: 0,
                     },
                 );
             });
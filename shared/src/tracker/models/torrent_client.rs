@@ -0,0 +1,207 @@
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::ops::{Deref, DerefMut};
+use utoipa::ToSchema;
+
+use crate::tracker::models::peer_id::PeerId;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "torrent_client_rule_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TorrentClientRule {
+    Allow,
+    Deny,
+}
+
+#[derive(Debug, Clone)]
+pub struct TorrentClient {
+    pub rule: TorrentClientRule,
+    pub peer_id_prefix: Vec<u8>,
+    pub min_version: Option<Vec<u8>>,
+    pub max_version: Option<Vec<u8>>,
+    pub user_agent_pattern: Option<Regex>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct APIInsertTorrentClient {
+    pub id: u32,
+    pub rule: TorrentClientRule,
+    pub peer_id_prefix: String,
+    pub min_version: Option<String>,
+    pub max_version: Option<String>,
+    pub user_agent_pattern: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Map(pub IndexMap<u32, TorrentClient>);
+
+impl TorrentClient {
+    /// Determines if the rule applies to the client announcing with this
+    /// peer_id and user agent
+    pub fn matches(&self, peer_id: &PeerId, user_agent: &str) -> bool {
+        let peer_id_without_hyphen = &peer_id.0[1..];
+        if !peer_id_without_hyphen.starts_with(&self.peer_id_prefix) {
+            return false;
+        }
+
+        if self.min_version.is_some() || self.max_version.is_some() {
+            // only azureus-style peer ids (-qB4620-) carry a version we can compare
+            if peer_id.0[0] != b'-' || peer_id.0[7] != b'-' {
+                return false;
+            }
+            let version = &peer_id.0[3..7];
+            if self.min_version.as_deref().is_some_and(|min| version < min)
+                || self.max_version.as_deref().is_some_and(|max| version > max)
+            {
+                return false;
+            }
+        }
+
+        match &self.user_agent_pattern {
+            Some(pattern) => pattern.is_match(user_agent),
+            None => true,
+        }
+    }
+}
+
+impl TryFrom<APIInsertTorrentClient> for TorrentClient {
+    type Error = regex::Error;
+
+    fn try_from(client: APIInsertTorrentClient) -> Result<Self, Self::Error> {
+        Ok(TorrentClient {
+            rule: client.rule,
+            peer_id_prefix: client.peer_id_prefix.into_bytes(),
+            min_version: client.min_version.map(String::into_bytes),
+            max_version: client.max_version.map(String::into_bytes),
+            user_agent_pattern: client
+                .user_agent_pattern
+                .as_deref()
+                .map(Regex::new)
+                .transpose()?,
+        })
+    }
+}
+
+impl Map {
+    /// A client is allowed if it matches at least one `allow` rule and no `deny` rule
+    pub fn is_allowed(&self, peer_id: &PeerId, user_agent: &str) -> bool {
+        let mut allowed = false;
+
+        for client in self.values() {
+            if client.matches(peer_id, user_agent) {
+                match client.rule {
+                    TorrentClientRule::Deny => return false,
+                    TorrentClientRule::Allow => allowed = true,
+                }
+            }
+        }
+
+        allowed
+    }
+
+    pub async fn from_database(db: &PgPool) -> Self {
+        let rows = sqlx::query!(
+            r#"
+                SELECT
+                    id,
+                    rule AS "rule: TorrentClientRule",
+                    peer_id_prefix,
+                    min_version,
+                    max_version,
+                    user_agent_pattern
+                FROM torrent_clients
+            "#
+        )
+        .fetch_all(db)
+        .await
+        .expect("could not get torrent clients");
+
+        let mut map: Map = Map(IndexMap::with_capacity(rows.len()));
+        for r in rows {
+            let id = r.id as u32;
+            let client = APIInsertTorrentClient {
+                id,
+                rule: r.rule,
+                peer_id_prefix: r.peer_id_prefix,
+                min_version: r.min_version,
+                max_version: r.max_version,
+                user_agent_pattern: r.user_agent_pattern,
+            };
+            match TorrentClient::try_from(client) {
+                Ok(client) => {
+                    map.insert(id, client);
+                }
+                Err(e) => log::error!("ignoring torrent client rule {id}: {e}"),
+            }
+        }
+
+        map
+    }
+}
+
+impl Deref for Map {
+    type Target = IndexMap<u32, TorrentClient>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Map {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(min_version: Option<&str>, max_version: Option<&str>) -> TorrentClient {
+        TorrentClient {
+            rule: TorrentClientRule::Allow,
+            peer_id_prefix: b"qB".to_vec(),
+            min_version: min_version.map(|version| version.as_bytes().to_vec()),
+            max_version: max_version.map(|version| version.as_bytes().to_vec()),
+            user_agent_pattern: None,
+        }
+    }
+
+    fn peer_id(prefix: &[u8; 8]) -> PeerId {
+        let mut peer_id = [b'1'; 20];
+        peer_id[..8].copy_from_slice(prefix);
+        PeerId(peer_id)
+    }
+
+    #[test]
+    fn test_matches_version_range() {
+        let client = client(Some("4500"), Some("4620"));
+        assert!(!client.matches(&peer_id(b"-qB4499-"), "qBittorrent/4.4.9"));
+        assert!(client.matches(&peer_id(b"-qB4500-"), "qBittorrent/4.5.0"));
+        assert!(client.matches(&peer_id(b"-qB4620-"), "qBittorrent/4.6.2"));
+        assert!(!client.matches(&peer_id(b"-qB4630-"), "qBittorrent/4.6.3"));
+        assert!(!client.matches(&peer_id(b"-TR4620-"), "Transmission/4.0.6"));
+    }
+
+    #[test]
+    fn test_matches_open_version_range() {
+        let from = client(Some("4500"), None);
+        assert!(!from.matches(&peer_id(b"-qB4499-"), "qBittorrent/4.4.9"));
+        assert!(from.matches(&peer_id(b"-qB5000-"), "qBittorrent/5.0.0"));
+
+        let until = client(None, Some("4620"));
+        assert!(until.matches(&peer_id(b"-qB3000-"), "qBittorrent/3.0.0"));
+        assert!(!until.matches(&peer_id(b"-qB4630-"), "qBittorrent/4.6.3"));
+    }
+
+    #[test]
+    fn test_matches_version_range_needs_azureus_style_peer_id() {
+        let client = client(Some("4500"), None);
+        let mut peer_id = peer_id(b"-qB4620-");
+        peer_id.0[7] = b'1';
+        assert!(!client.matches(&peer_id, "qBittorrent/4.6.2"));
+        assert!(client.matches(&PeerId(*b"-qB4620-000000000000"), "qBittorrent/4.6.2"));
+    }
+}
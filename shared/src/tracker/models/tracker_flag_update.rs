@@ -0,0 +1,166 @@
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use sqlx::{types::ipnetwork::IpNetwork, PgPool};
+use utoipa::ToSchema;
+
+use crate::{
+    error::Error,
+    tracker::models::{peer_id::PeerId, Flushable, Mergeable, Queue},
+};
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Eq,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    sqlx::Type,
+    ToSchema,
+)]
+#[sqlx(type_name = "tracker_flag_kind_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TrackerFlagKind {
+    ExcessiveUploadSpeed,
+    UploadWithoutLeechers,
+    UploadExceedingSwarmDownload,
+    PeerIdUserAgentMismatch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Index {
+    pub user_id: u32,
+    pub torrent_id: u32,
+    pub peer_id: PeerId,
+    pub kind: TrackerFlagKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackerFlagUpdate {
+    pub ip: std::net::IpAddr,
+    pub agent: String,
+    pub uploaded_delta: u64,
+    pub downloaded_delta: u64,
+    pub announce_interval: Option<u64>,
+    pub occurrences: u32,
+    pub credit_withheld: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Mergeable for TrackerFlagUpdate {
+    fn merge(&mut self, new: &Self) {
+        if new.created_at > self.created_at {
+            self.ip = new.ip;
+            self.agent = new.agent.clone();
+            self.announce_interval = new.announce_interval;
+            self.created_at = new.created_at;
+        }
+
+        self.uploaded_delta = self.uploaded_delta.saturating_add(new.uploaded_delta);
+        self.downloaded_delta = self.downloaded_delta.saturating_add(new.downloaded_delta);
+        self.occurrences = self.occurrences.saturating_add(new.occurrences);
+        self.credit_withheld = self.credit_withheld || new.credit_withheld;
+    }
+}
+
+impl Flushable<TrackerFlagUpdate> for Mutex<Queue<Index, TrackerFlagUpdate>> {
+    async fn flush_to_database(&self, db: &PgPool) {
+        let amount_of_updates = self.lock().records.len();
+        let updates = self
+            .lock()
+            .records
+            .drain(0..amount_of_updates)
+            .collect::<Vec<(Index, TrackerFlagUpdate)>>();
+        if updates.is_empty() {
+            return;
+        }
+
+        let mut kinds: Vec<TrackerFlagKind> = Vec::with_capacity(updates.len());
+        let mut user_ids: Vec<i32> = Vec::with_capacity(updates.len());
+        let mut torrent_ids: Vec<i32> = Vec::with_capacity(updates.len());
+        let mut peer_ids: Vec<Vec<u8>> = Vec::with_capacity(updates.len());
+        let mut ips: Vec<IpNetwork> = Vec::with_capacity(updates.len());
+        let mut agents: Vec<String> = Vec::with_capacity(updates.len());
+        let mut uploaded_deltas: Vec<i64> = Vec::with_capacity(updates.len());
+        let mut downloaded_deltas: Vec<i64> = Vec::with_capacity(updates.len());
+        let mut announce_intervals: Vec<Option<i64>> = Vec::with_capacity(updates.len());
+        let mut occurrences: Vec<i32> = Vec::with_capacity(updates.len());
+        let mut credits_withheld: Vec<bool> = Vec::with_capacity(updates.len());
+        let mut created_ats: Vec<DateTime<Utc>> = Vec::with_capacity(updates.len());
+
+        for (index, update) in updates {
+            kinds.push(index.kind);
+            user_ids.push(index.user_id as i32);
+            torrent_ids.push(index.torrent_id as i32);
+            peer_ids.push(index.peer_id.to_vec());
+            ips.push(IpNetwork::from(update.ip));
+            agents.push(update.agent);
+            uploaded_deltas.push(update.uploaded_delta as i64);
+            downloaded_deltas.push(update.downloaded_delta as i64);
+            announce_intervals.push(update.announce_interval.map(|i| i as i64));
+            occurrences.push(update.occurrences as i32);
+            credits_withheld.push(update.credit_withheld);
+            created_ats.push(update.created_at);
+        }
+
+        let result = sqlx::query!(
+            r#"
+                INSERT INTO tracker_flags (
+                    kind,
+                    user_id,
+                    torrent_id,
+                    peer_id,
+                    ip,
+                    agent,
+                    uploaded_delta,
+                    downloaded_delta,
+                    announce_interval,
+                    occurrences,
+                    credit_withheld,
+                    created_at
+                )
+                SELECT * FROM unnest(
+                    $1::tracker_flag_kind_enum[],
+                    $2::int[],
+                    $3::int[],
+                    $4::bytea[],
+                    $5::inet[],
+                    $6::varchar[],
+                    $7::bigint[],
+                    $8::bigint[],
+                    $9::bigint[],
+                    $10::int[],
+                    $11::boolean[],
+                    $12::timestamptz[]
+                )
+            "#,
+            &kinds as &[TrackerFlagKind],
+            &user_ids,
+            &torrent_ids,
+            &peer_ids,
+            &ips,
+            &agents,
+            &uploaded_deltas,
+            &downloaded_deltas,
+            &announce_intervals as &[Option<i64>],
+            &occurrences,
+            &credits_withheld,
+            &created_ats
+        )
+        .execute(db)
+        .await
+        .map_err(|e| Error::DatabseError(e.to_string()));
+
+        if result.is_err() {
+            // TODO: reinsert the updates that failed and retry
+            panic!("Failed to insert tracker flags: {}", result.err().unwrap());
+        } else {
+            log::info!("Inserted {amount_of_updates} tracker flags");
+        }
+    }
+}
@@ -4,7 +4,8 @@ use crate::announce::HttpResponseBuilderExt;
 
 pub type Result<T> = std::result::Result<T, AnnounceError>;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
 pub enum AnnounceError {
     #[error("Internal tracker error.")]
     InternalTrackerError,
@@ -20,6 +21,8 @@ pub enum AnnounceError {
     InvalidUserIdOrTorrentId,
     #[error("torrent client not in whitelist")]
     TorrentClientNotInWhitelist,
+    #[error("your ip address is banned")]
+    IpBanned,
     #[error("missing info_hash")]
     MissingInfoHash,
     #[error("missing peer_id")]
@@ -7,9 +7,15 @@ use std::{
 use crate::{
     announce::{
         error::{AnnounceError, Result},
-        models::announce::{Announce, AnnounceEvent},
+        models::{
+            announce::{Announce, AnnounceEvent},
+            announce_response::AnnounceResponse,
+        },
+    },
+    services::{
+        announce_service::is_torrent_client_allowed,
+        cheat_detection_service::{detect_cheating, inspect_swarm, AnnounceStats},
     },
-    services::announce_service::is_torrent_client_allowed,
     Tracker,
 };
 use actix_web::{
@@ -21,6 +27,7 @@ use arcadia_shared::tracker::models::{
     peer::{self, Peer},
     peer_update::{self, PeerUpdate},
     torrent_update::{self, TorrentUpdate},
+    tracker_flag_update::{self, TrackerFlagUpdate},
     user::Passkey,
     user_update::{self, UserUpdate},
 };
@@ -90,6 +97,14 @@ impl FromRequest for ClientIp {
                 .ok_or(AnnounceError::InternalTrackerError)
         };
 
+        let ip_result = ip_result.and_then(|ClientIp(ip)| {
+            if tracker.ip_bans.read().is_banned(ip) {
+                Err(AnnounceError::IpBanned)
+            } else {
+                Ok(ClientIp(ip))
+            }
+        });
+
         future::ready(ip_result)
     }
 }
@@ -123,7 +138,7 @@ pub async fn exec(
     //     return Err(AnnounceError::AbnormalAccess);
     // }
 
-    if !is_torrent_client_allowed(&ann.peer_id, &arc.env.allowed_torrent_clients.clients) {
+    if !is_torrent_client_allowed(&ann.peer_id, &user_agent, &arc.torrent_clients.read()) {
         return Err(AnnounceError::TorrentClientNotInWhitelist);
     }
 
@@ -185,6 +200,8 @@ pub async fn exec(
         user_id,
         has_requested_seed_list,
         has_requested_leech_list,
+        flags,
+        announce_interval,
         response,
     ) = {
         let mut torrent_guard = arc.torrents.lock();
@@ -202,6 +219,9 @@ pub async fn exec(
         let seeder_delta;
         let leecher_delta;
         let times_completed_delta;
+        let mut previous_announce_at = None;
+        let mut is_new_peer = false;
+        let mut warning_message = None;
         // let is_visible;
         // let mut is_active_after_stop = false;
 
@@ -213,10 +233,13 @@ pub async fn exec(
             });
             // Check if peer was removed
             if let Some(peer) = removed_peer {
+                arc.metrics.peers.dec();
+
                 // Calculate change in upload and download compared to previous
                 // announce
                 uploaded_delta = ann.uploaded.saturating_sub(peer.uploaded);
                 downloaded_delta = ann.downloaded.saturating_sub(peer.downloaded);
+                previous_announce_at = Some(peer.updated_at);
 
                 leecher_delta = 0 - peer.is_included_in_leech_list() as i32;
                 seeder_delta = 0 - peer.is_included_in_seed_list() as i32;
@@ -239,6 +262,7 @@ pub async fn exec(
                 // of sending `stopped` events. To prevent this, we need to
                 // send a warning (i.e. succcessful announce) instead, so that
                 // the client can successfully restart its session.
+                warning_message = Some("peer not found, it may have expired");
                 leecher_delta = 0;
                 seeder_delta = 0;
                 uploaded_delta = 0;
@@ -280,6 +304,7 @@ pub async fn exec(
                     updated_at: now,
                     uploaded: ann.uploaded,
                     downloaded: ann.downloaded,
+                    downloaded_delta: 0,
                 });
 
             // is_visible = new_peer.is_visible;
@@ -288,6 +313,7 @@ pub async fn exec(
             // in-memory db
             match old_peer {
                 Some(old_peer) => {
+                    previous_announce_at = Some(old_peer.updated_at);
                     leecher_delta = new_peer.is_included_in_leech_list() as i32
                         - old_peer.is_included_in_leech_list() as i32;
                     seeder_delta = new_peer.is_included_in_seed_list() as i32
@@ -307,6 +333,15 @@ pub async fn exec(
                         downloaded_delta = ann.downloaded - old_peer.downloaded;
                     }
 
+                    // Kept on the peer, the swarm's download delta is compared
+                    // against the upload delta of the other peers' announces
+                    if let Some(peer) = torrent.peers.get_mut(&peer::Index {
+                        user_id,
+                        peer_id: ann.peer_id,
+                    }) {
+                        peer.downloaded_delta = downloaded_delta;
+                    }
+
                     // Warn user if peer last announced less than
                     // announce_min_enforced seconds ago and it's
                     // not their first completed event
@@ -321,6 +356,7 @@ pub async fn exec(
                 }
                 None => {
                     // new peer is inserted
+                    is_new_peer = true;
 
                     // Make sure user is only allowed N peers per torrent.
                     let mut peer_count = 0;
@@ -342,6 +378,8 @@ pub async fn exec(
                         }
                     }
 
+                    arc.metrics.peers.inc();
+
                     leecher_delta = new_peer.is_included_in_leech_list() as i32;
                     seeder_delta = new_peer.is_included_in_seed_list() as i32;
                     times_completed_delta = 0;
@@ -361,6 +399,31 @@ pub async fn exec(
             .times_completed
             .saturating_add(times_completed_delta);
 
+        let announce_interval = previous_announce_at
+            .map(|previous| now.signed_duration_since(previous).num_seconds().max(0) as u64);
+
+        // The swarm is only inspected when the upload is large enough to be flagged,
+        // which can only happen on a peer that announced before
+        let (other_leechers, swarm_downloaded_delta) = match previous_announce_at {
+            Some(previous_announce_at) if uploaded_delta >= arc.env.min_flagged_upload => {
+                inspect_swarm(&torrent.peers, user_id, previous_announce_at)
+            }
+            _ => (0, 0),
+        };
+
+        let flags = detect_cheating(
+            &arc.env,
+            &ann.peer_id,
+            &user_agent,
+            &AnnounceStats {
+                uploaded_delta,
+                announce_interval,
+                other_leechers,
+                swarm_downloaded_delta,
+                is_new_peer,
+            },
+        );
+
         // Generate peer lists to return to client
         let mut peers_ipv4: Vec<u8> = Vec::new();
         let mut peers_ipv6: Vec<u8> = Vec::new();
@@ -419,44 +482,24 @@ pub async fn exec(
             }
         }
 
-        // Write out bencoded response (keys must be sorted to be within spec)
-        let mut response: Vec<u8> = Vec::with_capacity(
-            82 // literal characters
-            + 5 * 5 // numbers with estimated digit quantity for each
-            + peers_ipv4.len() * 6 + 5 // bytes per ipv4 plus estimated length prefix
-            + peers_ipv6.len() * 18 + 5, // bytes per ipv6 plus estimated length prefix
-        );
-
-        response.extend(b"d8:completei");
-        response.extend(torrent.seeders.to_string().as_bytes());
-        response.extend(b"e10:downloadedi");
-        response.extend(torrent.times_completed.to_string().as_bytes());
-        response.extend(b"e10:incompletei");
-        response.extend(torrent.leechers.to_string().as_bytes());
-
-        response.extend(b"e8:intervali");
-        let interval = rng().random_range(arc.env.announce_min..=arc.env.announce_max);
-        response.extend(interval.to_string().as_bytes());
-        response.extend(b"e12:min intervali");
-        response.extend(arc.env.announce_min.to_string().as_bytes());
-        response.extend(b"e5:peers");
-
-        if peers_ipv4.is_empty() {
-            response.extend(b"0:")
-        } else {
-            response.extend(peers_ipv4.len().to_string().as_bytes());
-            response.extend(b":");
-            response.extend(&peers_ipv4);
+        let mut response = AnnounceResponse {
+            complete: torrent.seeders,
+            downloaded: torrent.times_completed,
+            incomplete: torrent.leechers,
+            interval: rng().random_range(arc.env.announce_min..=arc.env.announce_max),
+            min_interval: arc.env.announce_min,
+            peers: &peers_ipv4,
+            peers6: &peers_ipv6,
+            ..Default::default()
         }
-
-        if !peers_ipv6.is_empty() {
-            response.extend(b"6:peers6");
-            response.extend(peers_ipv6.len().to_string().as_bytes());
-            response.extend(b":");
-            response.extend(peers_ipv6);
+        .external_ip(client_ip);
+        if let Some(message) = warning_message {
+            response = response.warning_message(message);
         }
-
-        response.extend(b"e");
+        if let Some(tracker_id) = &arc.env.tracker_id {
+            response = response.tracker_id(tracker_id);
+        }
+        let response = response.to_bencode();
 
         let upload_factor = std::cmp::max(arc.env.global_upload_factor, torrent.upload_factor);
         let download_factor =
@@ -488,11 +531,19 @@ pub async fn exec(
             user_id,
             has_requested_seed_list,
             has_requested_leech_list,
+            flags,
+            announce_interval,
             response,
         )
     };
 
-    let credited_uploaded_delta = upload_factor as u64 * uploaded_delta / 100;
+    let credit_withheld = !flags.is_empty() && arc.env.withhold_flagged_upload_credit;
+
+    let credited_uploaded_delta = if credit_withheld {
+        0
+    } else {
+        upload_factor as u64 * uploaded_delta / 100
+    };
     let credited_downloaded_delta = download_factor as u64 * downloaded_delta / 100;
 
     // let completed_at = if ann.event == AnnounceEvent::Completed {
@@ -522,6 +573,30 @@ pub async fn exec(
         });
     }
 
+    if !flags.is_empty() {
+        let mut tracker_flag_updates = arc.tracker_flag_updates.lock();
+        for kind in flags {
+            tracker_flag_updates.upsert(
+                tracker_flag_update::Index {
+                    user_id,
+                    torrent_id,
+                    peer_id: ann.peer_id,
+                    kind,
+                },
+                TrackerFlagUpdate {
+                    ip: client_ip,
+                    agent: user_agent.0.clone(),
+                    uploaded_delta,
+                    downloaded_delta,
+                    announce_interval,
+                    occurrences: 1,
+                    credit_withheld,
+                    created_at: now,
+                },
+            );
+        }
+    }
+
     arc.peer_updates.lock().upsert(
         peer_update::Index {
             peer_id: ann.peer_id,
@@ -1,7 +1,16 @@
 pub mod handle_announce;
 
-use actix_web::web::{get, resource, ServiceConfig};
+use actix_web::{
+    middleware::from_fn,
+    web::{get, resource, ServiceConfig},
+};
+
+use crate::metrics::record_announce;
 
 pub fn config(cfg: &mut ServiceConfig) {
-    cfg.service(resource("/announce").route(get().to(self::handle_announce::exec)));
+    cfg.service(
+        resource("/announce")
+            .wrap(from_fn(record_announce))
+            .route(get().to(self::handle_announce::exec)),
+    );
 }
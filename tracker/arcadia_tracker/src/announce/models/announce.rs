@@ -45,7 +45,8 @@ impl FromRequest for Announce {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
 pub enum AnnounceEvent {
     Started,
     Stopped,
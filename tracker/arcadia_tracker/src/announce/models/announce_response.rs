@@ -0,0 +1,166 @@
+use std::net::IpAddr;
+
+/// Bencoded body of a successful announce
+///
+/// Keys are written in the sorted order required by the bencode spec, the
+/// optional ones are only written when set.
+#[derive(Debug, Default)]
+pub struct AnnounceResponse<'a> {
+    pub complete: u32,
+    pub downloaded: u32,
+    pub incomplete: u32,
+    pub interval: u32,
+    pub min_interval: u32,
+    /// Compact ipv4 peer list, 6 bytes per peer
+    pub peers: &'a [u8],
+    /// Compact ipv6 peer list, 18 bytes per peer
+    pub peers6: &'a [u8],
+    pub warning_message: Option<&'a str>,
+    pub external_ip: Option<IpAddr>,
+    pub tracker_id: Option<&'a str>,
+}
+
+impl<'a> AnnounceResponse<'a> {
+    pub fn warning_message(mut self, message: &'a str) -> Self {
+        self.warning_message = Some(message);
+        self
+    }
+
+    /// See BEP 24
+    pub fn external_ip(mut self, ip: IpAddr) -> Self {
+        self.external_ip = Some(ip);
+        self
+    }
+
+    pub fn tracker_id(mut self, tracker_id: &'a str) -> Self {
+        self.tracker_id = Some(tracker_id);
+        self
+    }
+
+    pub fn to_bencode(&self) -> Vec<u8> {
+        let mut response: Vec<u8> = Vec::with_capacity(
+            128 // literal characters and numbers
+            + self.peers.len() + 5 // bytes of the ipv4 peers plus estimated length prefix
+            + self.peers6.len() + 5 // bytes of the ipv6 peers plus estimated length prefix
+            + self.warning_message.map_or(0, str::len)
+            + self.tracker_id.map_or(0, str::len),
+        );
+
+        response.push(b'd');
+        write_int(&mut response, "complete", self.complete);
+        write_int(&mut response, "downloaded", self.downloaded);
+        if let Some(ip) = self.external_ip {
+            match ip {
+                IpAddr::V4(ip) => write_bytes(&mut response, "external ip", &ip.octets()),
+                IpAddr::V6(ip) => write_bytes(&mut response, "external ip", &ip.octets()),
+            }
+        }
+        write_int(&mut response, "incomplete", self.incomplete);
+        write_int(&mut response, "interval", self.interval);
+        write_int(&mut response, "min interval", self.min_interval);
+        write_bytes(&mut response, "peers", self.peers);
+        if !self.peers6.is_empty() {
+            write_bytes(&mut response, "peers6", self.peers6);
+        }
+        if let Some(tracker_id) = self.tracker_id {
+            write_bytes(&mut response, "tracker id", tracker_id.as_bytes());
+        }
+        if let Some(message) = self.warning_message {
+            write_bytes(&mut response, "warning message", message.as_bytes());
+        }
+        response.push(b'e');
+
+        response
+    }
+}
+
+fn write_key(response: &mut Vec<u8>, key: &str) {
+    response.extend(key.len().to_string().as_bytes());
+    response.push(b':');
+    response.extend(key.as_bytes());
+}
+
+fn write_int(response: &mut Vec<u8>, key: &str, value: u32) {
+    write_key(response, key);
+    response.push(b'i');
+    response.extend(value.to_string().as_bytes());
+    response.push(b'e');
+}
+
+fn write_bytes(response: &mut Vec<u8>, key: &str, value: &[u8]) {
+    write_key(response, key);
+    response.extend(value.len().to_string().as_bytes());
+    response.push(b':');
+    response.extend(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    fn response() -> AnnounceResponse<'static> {
+        AnnounceResponse {
+            complete: 3,
+            downloaded: 12,
+            incomplete: 1,
+            interval: 1800,
+            min_interval: 1740,
+            peers: &[127, 0, 0, 1, 0x1A, 0xE1],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_minimal_response() {
+        assert_eq!(
+            response().to_bencode(),
+            b"d8:completei3e10:downloadedi12e10:incompletei1e8:intervali1800e12:min intervali1740e5:peers6:\x7f\x00\x00\x01\x1a\xe1e"
+        );
+    }
+
+    #[test]
+    fn test_empty_peer_list() {
+        let response = AnnounceResponse {
+            peers: &[],
+            ..response()
+        };
+
+        assert!(response
+            .to_bencode()
+            .ends_with(b"12:min intervali1740e5:peers0:e"));
+    }
+
+    #[test]
+    fn test_full_response_keys_are_sorted() {
+        let peers6 = [[0; 16].as_slice(), &[0x1A, 0xE1]].concat();
+        let response = AnnounceResponse {
+            peers6: &peers6,
+            ..response()
+        }
+        .warning_message("peer not found")
+        .external_ip(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)))
+        .tracker_id("arcadia");
+
+        let mut expected = b"d8:completei3e10:downloadedi12e11:external ip4:\x0a\x00\x00\x0210:incompletei1e8:intervali1800e12:min intervali1740e5:peers6:\x7f\x00\x00\x01\x1a\xe16:peers618:".to_vec();
+        expected.extend(&peers6);
+        expected.extend(b"10:tracker id7:arcadia15:warning message14:peer not founde");
+
+        assert_eq!(response.to_bencode(), expected);
+    }
+
+    #[test]
+    fn test_ipv6_external_ip() {
+        let response = response().external_ip(IpAddr::V6(Ipv6Addr::LOCALHOST));
+
+        let mut expected = b"11:external ip16:".to_vec();
+        expected.extend(Ipv6Addr::LOCALHOST.octets());
+        expected.extend(b"10:incomplete");
+
+        assert!(response
+            .to_bencode()
+            .windows(expected.len())
+            .any(|window| window == expected));
+    }
+}
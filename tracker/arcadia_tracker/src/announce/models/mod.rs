@@ -1 +1,2 @@
 pub mod announce;
+pub mod announce_response;
@@ -1,12 +1,9 @@
 use envconfig::Envconfig;
-use std::{collections::HashSet, str::FromStr};
 
 #[derive(Debug, Envconfig, Clone)]
 pub struct Env {
     #[envconfig(from = "API_KEY")]
     pub api_key: String,
-    #[envconfig(from = "ALLOWED_TORRENT_CLIENTS")]
-    pub allowed_torrent_clients: AllowedTorrentClientSet,
     #[envconfig(from = "NUMWANT_DEFAULT")]
     pub numwant_default: usize,
     #[envconfig(from = "NUMWANT_MAX")]
@@ -29,6 +26,16 @@ pub struct Env {
     pub inactive_peer_ttl: u64,
     #[envconfig(from = "ACTIVE_PEER_TTL")]
     pub active_peer_ttl: u64,
+    #[envconfig(from = "TRACKER_ID")]
+    pub tracker_id: Option<String>,
+    #[envconfig(from = "METRICS_BIND_ADDRESS")]
+    pub metrics_bind_address: Option<String>,
+    #[envconfig(from = "MAX_UPLOAD_SPEED", default = "0")]
+    pub max_upload_speed: u64,
+    #[envconfig(from = "MIN_FLAGGED_UPLOAD", default = "1073741824")]
+    pub min_flagged_upload: u64,
+    #[envconfig(from = "WITHHOLD_FLAGGED_UPLOAD_CREDIT", default = "false")]
+    pub withhold_flagged_upload_credit: bool,
     // Those are accessed with a request to the backend
     #[envconfig(default = "100")]
     pub global_upload_factor: i16,
@@ -41,21 +48,3 @@ pub enum Error {
     #[error("env variable parse error '{0}'")]
     EnvVariableParseError(String),
 }
-
-#[derive(Debug, Clone)]
-pub struct AllowedTorrentClientSet {
-    pub clients: HashSet<Vec<u8>>,
-}
-
-impl FromStr for AllowedTorrentClientSet {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let clients = s
-            .split(',')
-            .map(|s| s.trim().as_bytes().to_vec())
-            .collect::<HashSet<Vec<u8>>>();
-
-        Ok(Self { clients })
-    }
-}
@@ -0,0 +1,16 @@
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+use log::info;
+
+use crate::Tracker;
+
+pub async fn exec(arc: Data<Tracker>, id: Path<u32>) -> HttpResponse {
+    let id = id.into_inner();
+    info!("Removing ip ban with id {}.", id);
+
+    arc.ip_bans.write().remove(id);
+
+    HttpResponse::Ok().finish()
+}
@@ -0,0 +1,16 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_shared::tracker::models::ip_ban::APIInsertIpBan;
+use log::info;
+
+use crate::Tracker;
+
+pub async fn exec(arc: Data<Tracker>, ban: Json<APIInsertIpBan>) -> HttpResponse {
+    info!("Banning ip range {} (id {}).", ban.ip_range, ban.id);
+
+    arc.ip_bans.write().insert(ban.id, ban.ip_range);
+
+    HttpResponse::Ok().finish()
+}
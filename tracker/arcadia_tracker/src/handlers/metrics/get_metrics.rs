@@ -0,0 +1,9 @@
+use actix_web::{web::Data, HttpResponse};
+
+use crate::Tracker;
+
+pub async fn exec(arc: Data<Tracker>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(arc.metrics.render(&arc))
+}
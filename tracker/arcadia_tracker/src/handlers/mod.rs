@@ -1,2 +1,5 @@
+pub mod ip_bans;
+pub mod metrics;
+pub mod torrent_clients;
 pub mod torrents;
 pub mod users;
@@ -0,0 +1,2 @@
+pub mod delete_torrent_client;
+pub mod upsert_torrent_client;
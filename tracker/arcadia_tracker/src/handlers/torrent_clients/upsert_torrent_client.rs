@@ -0,0 +1,24 @@
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use arcadia_shared::tracker::models::torrent_client::{APIInsertTorrentClient, TorrentClient};
+use log::{debug, info};
+
+use crate::Tracker;
+
+pub async fn exec(arc: Data<Tracker>, client: Json<APIInsertTorrentClient>) -> HttpResponse {
+    info!("Inserting torrent client with id {}.", client.id);
+
+    let client = client.into_inner();
+    let id = client.id;
+
+    match TorrentClient::try_from(client) {
+        Ok(client) => {
+            debug!("inserted torrent client: {:?}", client);
+            arc.torrent_clients.write().insert(id, client);
+            HttpResponse::Ok().finish()
+        }
+        Err(e) => HttpResponse::BadRequest().body(e.to_string()),
+    }
+}
@@ -13,7 +13,7 @@ use crate::Tracker;
 pub async fn exec(arc: Data<Tracker>, torrent: Json<APIInsertTorrent>) -> HttpResponse {
     info!("Inserting torrent with id {}.", torrent.id);
 
-    arc.torrents.lock().insert(
+    let replaced_torrent = arc.torrents.lock().insert(
         torrent.id,
         Torrent {
             is_deleted: torrent.is_deleted,
@@ -25,10 +25,18 @@ pub async fn exec(arc: Data<Tracker>, torrent: Json<APIInsertTorrent>) -> HttpRe
             peers: peer::Map::new(),
         },
     );
+    // the peers of the replaced torrent are dropped with it
+    if let Some(replaced_torrent) = replaced_torrent {
+        arc.metrics.peers.sub(replaced_torrent.peers.len() as i64);
+    }
 
-    arc.infohash2id
-        .write()
-        .insert(torrent.info_hash, torrent.id);
+    let mut infohash2id = arc.infohash2id.write();
+    infohash2id.insert(torrent.info_hash, torrent.id);
+    // hybrid torrents are announced with either info hash
+    if let Some(info_hash_v2) = torrent.info_hash_v2 {
+        infohash2id.insert(info_hash_v2, torrent.id);
+    }
+    drop(infohash2id);
 
     debug!("inserted torrent: {:?}", torrent);
 
@@ -1,19 +1,21 @@
 use arcadia_shared::tracker::models::{
     peer_update::{self, PeerUpdate},
     torrent_update::{self, TorrentUpdate},
+    tracker_flag_update::{self, TrackerFlagUpdate},
     user_update::{self, UserUpdate},
     Queue,
 };
 use parking_lot::{Mutex, RwLock};
 use sqlx::{postgres::PgPoolOptions, PgPool};
 
-use crate::env::Env;
+use crate::{env::Env, metrics::Metrics};
 use std::{io::Write, ops::Deref, time::Duration};
 
 pub mod announce;
 pub mod api_doc;
 pub mod env;
 pub mod handlers;
+pub mod metrics;
 pub mod middleware;
 pub mod routes;
 pub mod scheduler;
@@ -29,9 +31,14 @@ pub struct Tracker {
     pub passkey2id: RwLock<arcadia_shared::tracker::models::passkey_2_id::Map>,
     pub infohash2id: RwLock<arcadia_shared::tracker::models::infohash_2_id::Map>,
     pub torrents: Mutex<arcadia_shared::tracker::models::torrent::Map>,
+    pub torrent_clients: RwLock<arcadia_shared::tracker::models::torrent_client::Map>,
+    pub ip_bans: RwLock<arcadia_shared::tracker::models::ip_ban::Map>,
     pub user_updates: Mutex<Queue<user_update::Index, UserUpdate>>,
     pub torrent_updates: Mutex<Queue<torrent_update::Index, TorrentUpdate>>,
     pub peer_updates: Mutex<Queue<peer_update::Index, PeerUpdate>>,
+    pub tracker_flag_updates: Mutex<Queue<tracker_flag_update::Index, TrackerFlagUpdate>>,
+
+    pub metrics: Metrics,
 }
 
 impl Deref for Tracker {
@@ -79,6 +86,25 @@ impl Tracker {
         let torrents = arcadia_shared::tracker::models::torrent::Map::from_database(&pool).await;
         log::info!("[Setup] Got {:?} torrents", torrents.len());
 
+        log::info!("[Setup] Getting torrent clients...");
+        std::io::stdout().flush().unwrap();
+        let torrent_clients =
+            arcadia_shared::tracker::models::torrent_client::Map::from_database(&pool).await;
+        log::info!("[Setup] Got {:?} torrent clients", torrent_clients.len());
+
+        log::info!("[Setup] Getting ip bans...");
+        std::io::stdout().flush().unwrap();
+        let ip_bans = arcadia_shared::tracker::models::ip_ban::Map::from_database(&pool).await;
+        log::info!("[Setup] Got {:?} ip bans", ip_bans.len());
+
+        let metrics = Metrics::default();
+        metrics.peers.set(
+            torrents
+                .values()
+                .map(|torrent| torrent.peers.len() as i64)
+                .sum(),
+        );
+
         Self {
             env,
             pool,
@@ -86,9 +112,15 @@ impl Tracker {
             passkey2id: RwLock::new(passkey2id),
             infohash2id: RwLock::new(infohash2id),
             torrents: Mutex::new(torrents),
+            torrent_clients: RwLock::new(torrent_clients),
+            ip_bans: RwLock::new(ip_bans),
             user_updates: Mutex::new(Queue::<user_update::Index, UserUpdate>::default()),
             torrent_updates: Mutex::new(Queue::<torrent_update::Index, TorrentUpdate>::default()),
             peer_updates: Mutex::new(Queue::<peer_update::Index, PeerUpdate>::default()),
+            tracker_flag_updates: Mutex::new(
+                Queue::<tracker_flag_update::Index, TrackerFlagUpdate>::default(),
+            ),
+            metrics,
         }
     }
 }
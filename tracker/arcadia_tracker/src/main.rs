@@ -1,5 +1,10 @@
 use actix_web::{middleware, web::Data, App, HttpServer};
-use arcadia_tracker::{api_doc::ApiDoc, env::Env, routes::init, scheduler, Tracker};
+use arcadia_tracker::{
+    api_doc::ApiDoc,
+    env::Env,
+    routes::{init, init_metrics},
+    scheduler, Tracker,
+};
 use envconfig::Envconfig;
 use std::env;
 use utoipa::OpenApi;
@@ -32,6 +37,18 @@ async fn main() -> std::io::Result<()> {
         }
     });
 
+    if let Some(metrics_bind_address) = arc.env.metrics_bind_address.clone() {
+        println!("Metrics served at http://{metrics_bind_address}/metrics");
+        let metrics_server = HttpServer::new({
+            let arc = arc.clone();
+            move || App::new().app_data(arc.clone()).configure(init_metrics)
+        })
+        .workers(1)
+        .bind(metrics_bind_address)?
+        .run();
+        tokio::spawn(metrics_server);
+    }
+
     let server = HttpServer::new(move || {
         App::new()
             .wrap(middleware::Logger::default())
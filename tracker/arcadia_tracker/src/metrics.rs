@@ -0,0 +1,193 @@
+use std::{str::FromStr, time::Instant};
+
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web::Data,
+    Error,
+};
+use prometheus::{
+    exponential_buckets, Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter,
+    IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+
+use crate::{
+    announce::{error::AnnounceError, models::announce::AnnounceEvent},
+    Tracker,
+};
+
+#[derive(Debug)]
+pub struct Metrics {
+    registry: Registry,
+    pub announces: IntCounterVec,
+    pub announce_errors: IntCounterVec,
+    pub announce_duration: Histogram,
+    pub queue_depth: IntGaugeVec,
+    pub flush_duration: HistogramVec,
+    pub in_memory: IntGaugeVec,
+    // the peers of in_memory, kept up to date where peers are added and removed
+    // so that scrapes don't have to count them under the torrents lock
+    pub peers: IntGauge,
+    pub reap_duration: Histogram,
+    pub reaped_peers: IntCounter,
+    pub deactivated_peers: IntCounter,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        let registry = Registry::new_custom(Some("arcadia_tracker".into()), None).unwrap();
+
+        let announces = IntCounterVec::new(
+            Opts::new("announces_total", "Announces received, by event"),
+            &["event"],
+        )
+        .unwrap();
+        let announce_errors = IntCounterVec::new(
+            Opts::new("announce_errors_total", "Announces rejected, by error"),
+            &["error"],
+        )
+        .unwrap();
+        let announce_duration = Histogram::with_opts(
+            HistogramOpts::new("announce_duration_seconds", "Time spent handling announces")
+                .buckets(exponential_buckets(0.00005, 2.0, 16).unwrap()),
+        )
+        .unwrap();
+        let queue_depth = IntGaugeVec::new(
+            Opts::new("queue_depth", "Updates waiting to be flushed, by queue"),
+            &["queue"],
+        )
+        .unwrap();
+        let flush_duration = HistogramVec::new(
+            HistogramOpts::new("flush_duration_seconds", "Time spent flushing a queue")
+                .buckets(exponential_buckets(0.001, 2.0, 14).unwrap()),
+            &["queue"],
+        )
+        .unwrap();
+        let in_memory = IntGaugeVec::new(
+            Opts::new("in_memory", "Records held in memory, by kind"),
+            &["kind"],
+        )
+        .unwrap();
+        let peers = in_memory.with_label_values(&["peers"]);
+        let reap_duration = Histogram::with_opts(
+            HistogramOpts::new("reap_duration_seconds", "Time spent reaping peers")
+                .buckets(exponential_buckets(0.001, 2.0, 14).unwrap()),
+        )
+        .unwrap();
+        let reaped_peers = IntCounter::new(
+            "reaped_peers_total",
+            "Peers erased from memory by the reaper",
+        )
+        .unwrap();
+        let deactivated_peers = IntCounter::new(
+            "deactivated_peers_total",
+            "Peers marked as inactive by the reaper",
+        )
+        .unwrap();
+
+        registry.register(Box::new(announces.clone())).unwrap();
+        registry
+            .register(Box::new(announce_errors.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(announce_duration.clone()))
+            .unwrap();
+        registry.register(Box::new(queue_depth.clone())).unwrap();
+        registry.register(Box::new(flush_duration.clone())).unwrap();
+        registry.register(Box::new(in_memory.clone())).unwrap();
+        registry.register(Box::new(reap_duration.clone())).unwrap();
+        registry.register(Box::new(reaped_peers.clone())).unwrap();
+        registry
+            .register(Box::new(deactivated_peers.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            announces,
+            announce_errors,
+            announce_duration,
+            queue_depth,
+            flush_duration,
+            in_memory,
+            peers,
+            reap_duration,
+            reaped_peers,
+            deactivated_peers,
+        }
+    }
+}
+
+impl Metrics {
+    /// Encodes every metric in the prometheus text format, after refreshing
+    /// the gauges which are only computed when scraped
+    pub fn render(&self, tracker: &Tracker) -> String {
+        self.queue_depth
+            .with_label_values(&["user_updates"])
+            .set(tracker.user_updates.lock().records.len() as i64);
+        self.queue_depth
+            .with_label_values(&["torrent_updates"])
+            .set(tracker.torrent_updates.lock().records.len() as i64);
+        self.queue_depth
+            .with_label_values(&["peer_updates"])
+            .set(tracker.peer_updates.lock().records.len() as i64);
+        self.queue_depth
+            .with_label_values(&["tracker_flag_updates"])
+            .set(tracker.tracker_flag_updates.lock().records.len() as i64);
+
+        let torrents = tracker.torrents.lock().len();
+        self.in_memory
+            .with_label_values(&["torrents"])
+            .set(torrents as i64);
+        self.in_memory
+            .with_label_values(&["users"])
+            .set(tracker.users.read().len() as i64);
+
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("could not encode metrics");
+
+        String::from_utf8(buffer).expect("metrics are not valid utf-8")
+    }
+}
+
+/// Counts announces and their errors, and measures how long they take
+pub async fn record_announce(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let tracker = req
+        .app_data::<Data<Tracker>>()
+        .expect("app data set")
+        .clone();
+    let event: &'static str = req
+        .query_string()
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("event="))
+        .map_or(Ok(AnnounceEvent::Empty), AnnounceEvent::from_str)
+        .map_or("invalid", |event| event.into());
+
+    let start = Instant::now();
+    let res = next.call(req).await?;
+
+    tracker
+        .metrics
+        .announce_duration
+        .observe(start.elapsed().as_secs_f64());
+    tracker.metrics.announces.with_label_values(&[event]).inc();
+    if let Some(error) = res
+        .response()
+        .error()
+        .and_then(|error| error.as_error::<AnnounceError>())
+    {
+        let error: &'static str = error.into();
+        tracker
+            .metrics
+            .announce_errors
+            .with_label_values(&[error])
+            .inc();
+    }
+
+    Ok(res)
+}
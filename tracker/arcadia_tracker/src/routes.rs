@@ -1,8 +1,14 @@
-use actix_web::web::{self, put, resource, scope};
+use actix_web::web::{self, delete, get, put, resource, scope};
 
 use crate::{
     announce::handlers::announce::config as AnnouncesConfig,
-    handlers::{torrents::upsert_torrent, users::upsert_user},
+    handlers::{
+        ip_bans::{delete_ip_ban, upsert_ip_ban},
+        metrics::get_metrics,
+        torrent_clients::{delete_torrent_client, upsert_torrent_client},
+        torrents::upsert_torrent,
+        users::upsert_user,
+    },
     middleware::authenticate_backend,
 };
 use actix_web_httpauth::middleware::HttpAuthentication;
@@ -13,7 +19,23 @@ pub fn init(cfg: &mut web::ServiceConfig) {
         web::scope("/api")
             .wrap(HttpAuthentication::with_fn(authenticate_backend))
             .service(resource("/torrents").route(put().to(upsert_torrent::exec)))
-            .service(resource("/users").route(put().to(upsert_user::exec))),
+            .service(resource("/users").route(put().to(upsert_user::exec)))
+            .service(resource("/torrent-clients").route(put().to(upsert_torrent_client::exec)))
+            .service(
+                resource("/torrent-clients/{id}").route(delete().to(delete_torrent_client::exec)),
+            )
+            .service(resource("/ip-bans").route(put().to(upsert_ip_ban::exec)))
+            .service(resource("/ip-bans/{id}").route(delete().to(delete_ip_ban::exec))),
+    );
+    cfg.service(
+        resource("/metrics")
+            .wrap(HttpAuthentication::with_fn(authenticate_backend))
+            .route(get().to(get_metrics::exec)),
     );
     cfg.service(scope("{passkey}").configure(AnnouncesConfig));
 }
+
+/// Unauthenticated routes, only served on `METRICS_BIND_ADDRESS`
+pub fn init_metrics(cfg: &mut web::ServiceConfig) {
+    cfg.service(resource("/metrics").route(get().to(get_metrics::exec)));
+}
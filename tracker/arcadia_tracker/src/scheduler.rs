@@ -6,6 +6,8 @@ use arcadia_shared::tracker::models::{
     Flushable,
 };
 use chrono::{Duration, Utc};
+use prometheus::Histogram;
+use std::{future::Future, time::Instant};
 use tokio::join;
 
 use crate::Tracker;
@@ -29,15 +31,38 @@ pub async fn handle(arc: &Data<Tracker>) {
 }
 
 pub async fn flush(arc: &Data<Tracker>) {
+    let flush_duration = |queue| arc.metrics.flush_duration.with_label_values(&[queue]);
+
     join!(
-        arc.user_updates.flush_to_database(&arc.pool),
-        arc.torrent_updates.flush_to_database(&arc.pool),
-        arc.peer_updates.flush_to_database(&arc.pool)
+        timed(
+            flush_duration("user_updates"),
+            arc.user_updates.flush_to_database(&arc.pool)
+        ),
+        timed(
+            flush_duration("torrent_updates"),
+            arc.torrent_updates.flush_to_database(&arc.pool)
+        ),
+        timed(
+            flush_duration("peer_updates"),
+            arc.peer_updates.flush_to_database(&arc.pool)
+        ),
+        timed(
+            flush_duration("tracker_flag_updates"),
+            arc.tracker_flag_updates.flush_to_database(&arc.pool)
+        )
     );
 }
 
+async fn timed<F: Future>(histogram: Histogram, future: F) -> F::Output {
+    let start = Instant::now();
+    let output = future.await;
+    histogram.observe(start.elapsed().as_secs_f64());
+    output
+}
+
 /// Remove peers that have not announced for some time
 pub async fn reap(arc: &Data<Tracker>) {
+    let start = Instant::now();
     let ttl = Duration::seconds(arc.env.active_peer_ttl.try_into().unwrap());
     let active_cutoff = Utc::now().checked_sub_signed(ttl).unwrap();
     let ttl = Duration::seconds(arc.env.inactive_peer_ttl.try_into().unwrap());
@@ -74,6 +99,7 @@ pub async fn reap(arc: &Data<Tracker>) {
             // count are updated to reflect.
             if peer.updated_at < active_cutoff && peer.is_active {
                 peer.is_active = false;
+                arc.metrics.deactivated_peers.inc();
 
                 arc.users.write().entry(index.user_id).and_modify(|user| {
                     if peer.is_seeder {
@@ -107,5 +133,14 @@ pub async fn reap(arc: &Data<Tracker>) {
         }
     }
 
+    arc.metrics
+        .reaped_peers
+        .inc_by(all_removed_peers.len() as u64);
+    arc.metrics.peers.sub(all_removed_peers.len() as i64);
+
     remove_peers_from_database(&arc.pool, &all_removed_peers).await;
+
+    arc.metrics
+        .reap_duration
+        .observe(start.elapsed().as_secs_f64());
 }
@@ -0,0 +1,293 @@
+use arcadia_shared::tracker::models::{
+    peer, peer_id::PeerId, tracker_flag_update::TrackerFlagKind,
+};
+use chrono::{DateTime, Utc};
+
+use crate::env::Env;
+
+/// What the tracker knows about an announce, used to decide if it is suspicious
+pub struct AnnounceStats {
+    pub uploaded_delta: u64,
+    /// Seconds since the peer's previous announce, if it announced before
+    pub announce_interval: Option<u64>,
+    /// Leechers in the swarm, excluding the announcing user's peers
+    pub other_leechers: u32,
+    /// Sum of what the other users' peers currently in the swarm reported
+    /// downloading between their last two announces, for the peers whose last
+    /// announce came after this peer's previous one. Older deltas were already
+    /// compared with this peer's previous upload, so they aren't counted again
+    pub swarm_downloaded_delta: u64,
+    pub is_new_peer: bool,
+}
+
+/// Returns the leechers of the swarm which don't belong to `user_id`, and the sum of
+/// the download deltas the other users' peers reported on announces made after `since`
+pub fn inspect_swarm(peers: &peer::Map, user_id: u32, since: DateTime<Utc>) -> (u32, u64) {
+    peers
+        .iter()
+        .filter(|(index, peer)| index.user_id != user_id && peer.is_active)
+        .fold((0, 0u64), |(leechers, downloaded), (_index, peer)| {
+            let downloaded_delta = if peer.updated_at > since {
+                peer.downloaded_delta
+            } else {
+                0
+            };
+            (
+                leechers + !peer.is_seeder as u32,
+                downloaded.saturating_add(downloaded_delta),
+            )
+        })
+}
+
+// azureus-style client codes and the name their user agent is expected to contain
+const KNOWN_USER_AGENTS: &[(&[u8], &str)] = &[
+    (b"BI", "biglybt"),
+    (b"DE", "deluge"),
+    (b"KT", "ktorrent"),
+    (b"lt", "rtorrent"),
+    (b"qB", "qbittorrent"),
+    (b"TR", "transmission"),
+];
+
+pub fn detect_cheating(
+    env: &Env,
+    peer_id: &PeerId,
+    user_agent: &str,
+    stats: &AnnounceStats,
+) -> Vec<TrackerFlagKind> {
+    let mut flags = Vec::new();
+
+    if env.max_upload_speed != 0
+        && stats
+            .announce_interval
+            .is_some_and(|interval| stats.uploaded_delta / interval.max(1) > env.max_upload_speed)
+    {
+        flags.push(TrackerFlagKind::ExcessiveUploadSpeed);
+    }
+
+    if stats.uploaded_delta >= env.min_flagged_upload {
+        if stats.other_leechers == 0 {
+            flags.push(TrackerFlagKind::UploadWithoutLeechers);
+        }
+        if stats.uploaded_delta > stats.swarm_downloaded_delta {
+            flags.push(TrackerFlagKind::UploadExceedingSwarmDownload);
+        }
+    }
+
+    // only checked once per peer, the peer_id and user agent don't change during a session
+    if stats.is_new_peer && !peer_id_matches_user_agent(peer_id, user_agent) {
+        flags.push(TrackerFlagKind::PeerIdUserAgentMismatch);
+    }
+
+    flags
+}
+
+fn peer_id_matches_user_agent(peer_id: &PeerId, user_agent: &str) -> bool {
+    if peer_id.0[0] != b'-' {
+        return true;
+    }
+
+    let user_agent = user_agent.to_lowercase();
+    KNOWN_USER_AGENTS
+        .iter()
+        .find(|(code, _)| peer_id.0[1..3] == **code)
+        .is_none_or(|(_, name)| user_agent.contains(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1 << 30;
+
+    fn env() -> Env {
+        Env {
+            api_key: String::new(),
+            numwant_default: 50,
+            numwant_max: 200,
+            announce_min: 1800,
+            announce_min_enforced: 0,
+            announce_max: 7200,
+            max_peers_per_torrent_per_user: 10,
+            flush_interval_milliseconds: 60000,
+            peer_expiry_interval: 600,
+            reverse_proxy_client_ip_header_name: None,
+            inactive_peer_ttl: 300,
+            active_peer_ttl: 3600,
+            tracker_id: None,
+            metrics_bind_address: None,
+            // 10 MiB/s
+            max_upload_speed: 10 << 20,
+            min_flagged_upload: GIB,
+            withhold_flagged_upload_credit: false,
+            global_upload_factor: 100,
+            global_download_factor: 100,
+        }
+    }
+
+    fn peer_id(prefix: &[u8]) -> PeerId {
+        let mut peer_id = [b'1'; 20];
+        peer_id[..prefix.len()].copy_from_slice(prefix);
+        PeerId(peer_id)
+    }
+
+    /// A seeder which uploaded what the swarm's leechers downloaded since its last announce
+    fn seeder_stats() -> AnnounceStats {
+        AnnounceStats {
+            uploaded_delta: 2 * GIB,
+            announce_interval: Some(1800),
+            other_leechers: 3,
+            swarm_downloaded_delta: 3 * GIB,
+            is_new_peer: false,
+        }
+    }
+
+    fn detect(stats: &AnnounceStats) -> Vec<TrackerFlagKind> {
+        detect_cheating(&env(), &peer_id(b"-qB4620-"), "qBittorrent/4.6.2", stats)
+    }
+
+    #[test]
+    fn test_seeder_is_not_flagged() {
+        assert!(detect(&seeder_stats()).is_empty());
+    }
+
+    #[test]
+    fn test_long_running_seeder_is_not_flagged_after_leechers_left() {
+        let stats = AnnounceStats {
+            uploaded_delta: 0,
+            other_leechers: 0,
+            swarm_downloaded_delta: 0,
+            ..seeder_stats()
+        };
+
+        assert!(detect(&stats).is_empty());
+    }
+
+    #[test]
+    fn test_excessive_upload_speed() {
+        let stats = AnnounceStats {
+            announce_interval: Some(60),
+            ..seeder_stats()
+        };
+
+        assert_eq!(detect(&stats), [TrackerFlagKind::ExcessiveUploadSpeed]);
+    }
+
+    #[test]
+    fn test_upload_speed_is_not_checked_when_disabled() {
+        let env = Env {
+            max_upload_speed: 0,
+            ..env()
+        };
+        let stats = AnnounceStats {
+            announce_interval: Some(1),
+            ..seeder_stats()
+        };
+
+        assert!(
+            detect_cheating(&env, &peer_id(b"-qB4620-"), "qBittorrent/4.6.2", &stats).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_upload_without_leechers() {
+        let stats = AnnounceStats {
+            other_leechers: 0,
+            ..seeder_stats()
+        };
+
+        assert_eq!(detect(&stats), [TrackerFlagKind::UploadWithoutLeechers]);
+    }
+
+    #[test]
+    fn test_upload_exceeding_swarm_download() {
+        let stats = AnnounceStats {
+            swarm_downloaded_delta: GIB,
+            ..seeder_stats()
+        };
+
+        assert_eq!(
+            detect(&stats),
+            [TrackerFlagKind::UploadExceedingSwarmDownload]
+        );
+    }
+
+    #[test]
+    fn test_small_uploads_are_not_compared_with_the_swarm() {
+        let stats = AnnounceStats {
+            uploaded_delta: GIB - 1,
+            other_leechers: 0,
+            swarm_downloaded_delta: 0,
+            ..seeder_stats()
+        };
+
+        assert!(detect(&stats).is_empty());
+    }
+
+    #[test]
+    fn test_swarm_download_only_counts_announces_since_the_previous_upload() {
+        let now = Utc::now();
+        let since = now - chrono::Duration::minutes(30);
+        let mut peers = peer::Map::new();
+        for (user_id, is_seeder, minutes_ago, downloaded_delta) in [
+            // the uploader's own leeching peer is never counted
+            (1, false, 10, 4 * GIB),
+            (2, false, 10, GIB),
+            // announced before the uploader's previous announce
+            (3, false, 45, 2 * GIB),
+            (4, true, 5, 0),
+        ] {
+            peers.insert(
+                peer::Index {
+                    user_id,
+                    peer_id: peer_id(&[b'0' + user_id as u8]),
+                },
+                peer::Peer {
+                    ip_address: std::net::Ipv4Addr::LOCALHOST.into(),
+                    port: 6881,
+                    is_seeder,
+                    is_active: true,
+                    has_sent_completed: false,
+                    updated_at: now - chrono::Duration::minutes(minutes_ago),
+                    uploaded: 0,
+                    downloaded: downloaded_delta,
+                    downloaded_delta,
+                },
+            );
+        }
+
+        assert_eq!(inspect_swarm(&peers, 1, since), (2, GIB));
+    }
+
+    #[test]
+    fn test_peer_id_user_agent_mismatch() {
+        let stats = AnnounceStats {
+            is_new_peer: true,
+            ..seeder_stats()
+        };
+
+        assert_eq!(
+            detect_cheating(&env(), &peer_id(b"-qB4620-"), "Transmission/4.0.5", &stats),
+            [TrackerFlagKind::PeerIdUserAgentMismatch]
+        );
+        assert!(detect(&stats).is_empty());
+        // unknown clients and non azureus-style peer_ids can't be checked
+        assert!(
+            detect_cheating(&env(), &peer_id(b"-XX0100-"), "Transmission/4.0.5", &stats).is_empty()
+        );
+        assert!(
+            detect_cheating(&env(), &peer_id(b"M7-2-2--"), "Transmission/4.0.5", &stats).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_peer_id_user_agent_mismatch_is_only_checked_for_new_peers() {
+        assert!(detect_cheating(
+            &env(),
+            &peer_id(b"-qB4620-"),
+            "Transmission/4.0.5",
+            &seeder_stats()
+        )
+        .is_empty());
+    }
+}
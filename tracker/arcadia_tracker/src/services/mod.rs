@@ -1 +1,2 @@
 pub mod announce_service;
+pub mod cheat_detection_service;
@@ -4,12 +4,10 @@ use actix_web::{
     dev::{Service, ServiceResponse},
     test, web, App, Error,
 };
-use arcadia_shared::tracker::models::{infohash_2_id, passkey_2_id, torrent, user};
-use arcadia_tracker::{
-    env::{AllowedTorrentClientSet, Env},
-    routes::init,
-    Tracker,
+use arcadia_shared::tracker::models::{
+    infohash_2_id, ip_ban, passkey_2_id, torrent, torrent_client, user,
 };
+use arcadia_tracker::{env::Env, routes::init, Tracker};
 use parking_lot::{Mutex, RwLock};
 use serde::de::DeserializeOwned;
 use sqlx::PgPool;
@@ -20,11 +18,6 @@ pub async fn create_test_app(
     // Create a default env for testing
     let env = Env {
         api_key: "amazing_api_key".to_owned(),
-        allowed_torrent_clients: AllowedTorrentClientSet {
-            clients: vec![b"lt0F01-".to_vec(), b"qB".to_vec(), b"UTorrent".to_vec()]
-                .into_iter()
-                .collect(),
-        },
         numwant_default: 50,
         numwant_max: 200,
         announce_min: 1800,
@@ -36,6 +29,11 @@ pub async fn create_test_app(
         reverse_proxy_client_ip_header_name: None,
         inactive_peer_ttl: 300,
         active_peer_ttl: 3600,
+        tracker_id: None,
+        metrics_bind_address: None,
+        max_upload_speed: 0,
+        min_flagged_upload: 1073741824,
+        withhold_flagged_upload_credit: false,
         global_upload_factor: 100,
         global_download_factor: 100,
     };
@@ -47,6 +45,8 @@ pub async fn create_test_app(
     let passkey2id = passkey_2_id::Map::from_database(&pool).await;
     let infohash2id = infohash_2_id::Map::from_database(&pool).await;
     let torrents = torrent::Map::from_database(&pool).await;
+    let torrent_clients = torrent_client::Map::from_database(&pool).await;
+    let ip_bans = ip_ban::Map::from_database(&pool).await;
 
     let tracker = Tracker {
         env,
@@ -55,9 +55,13 @@ pub async fn create_test_app(
         passkey2id: RwLock::new(passkey2id),
         infohash2id: RwLock::new(infohash2id),
         torrents: Mutex::new(torrents),
+        torrent_clients: RwLock::new(torrent_clients),
+        ip_bans: RwLock::new(ip_bans),
         user_updates: Mutex::new(Default::default()),
         torrent_updates: Mutex::new(Default::default()),
         peer_updates: Mutex::new(Default::default()),
+        tracker_flag_updates: Mutex::new(Default::default()),
+        metrics: Default::default(),
     };
 
     test::init_service(App::new().app_data(web::Data::new(tracker)).configure(init)).await
@@ -0,0 +1,4 @@
+INSERT INTO
+    torrent_clients (created_by_id, name, rule, peer_id_prefix, user_agent_pattern)
+VALUES
+    (1, 'rTorrent', 'deny', 'lt0F', '^test-agent/');
@@ -0,0 +1,4 @@
+INSERT INTO
+    ip_bans (created_by_id, ip_range, reason)
+VALUES
+    (1, '127.0.0.0/8', 'test ban');
@@ -2,7 +2,7 @@ mod common;
 
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
-use actix_web::test;
+use actix_web::{http::StatusCode, test};
 use common::read_body_bencode;
 use serde::Deserialize;
 use sqlx::PgPool;
@@ -521,3 +521,280 @@ async fn test_announce_missing_user_agent(pool: PgPool) {
         .expect("Failed to decode error");
     assert_eq!(error.failure_reason, "user-agent is missing");
 }
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent",
+        "with_test_denied_torrent_client"
+    ),
+    migrations = "../../backend/storage/migrations"
+)]
+async fn test_announce_denied_torrent_client(pool: PgPool) {
+    let service = common::create_test_app(pool).await;
+
+    let valid_passkey = "d2037c66dd3e13044e0d2f9b891c3837";
+    let info_hash_bytes = [
+        0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+        0x00, 0x11, 0x22, 0x33, 0x44,
+    ];
+    let info_hash_encoded = url_encode_info_hash(&info_hash_bytes);
+    let peer_id = test_peer_id();
+    let peer_id_encoded =
+        percent_encoding::percent_encode(&peer_id, percent_encoding::NON_ALPHANUMERIC).to_string();
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/{}/announce?info_hash={}&peer_id={}&port=6969&uploaded=0&downloaded=0&left=1000&event=started",
+            valid_passkey, info_hash_encoded, peer_id_encoded
+        ))
+        .insert_header(("User-Agent", "test-agent/1.0"))
+        .peer_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 0))
+        .to_request();
+
+    let resp = test::call_service(&service, req).await;
+
+    assert!(
+        resp.status().is_client_error(),
+        "Expected client error for denied torrent client, got status {}",
+        resp.status()
+    );
+
+    let error: WrappedError = read_body_bencode(resp)
+        .await
+        .expect("Failed to decode error");
+    assert_eq!(error.failure_reason, "torrent client not in whitelist");
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent",
+        "with_test_ip_ban"
+    ),
+    migrations = "../../backend/storage/migrations"
+)]
+async fn test_announce_banned_ip(pool: PgPool) {
+    let service = common::create_test_app(pool).await;
+
+    let valid_passkey = "d2037c66dd3e13044e0d2f9b891c3837";
+    let info_hash_bytes = [
+        0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+        0x00, 0x11, 0x22, 0x33, 0x44,
+    ];
+    let info_hash_encoded = url_encode_info_hash(&info_hash_bytes);
+    let peer_id = test_peer_id();
+    let peer_id_encoded =
+        percent_encoding::percent_encode(&peer_id, percent_encoding::NON_ALPHANUMERIC).to_string();
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/{}/announce?info_hash={}&peer_id={}&port=6969&uploaded=0&downloaded=0&left=1000&event=started",
+            valid_passkey, info_hash_encoded, peer_id_encoded
+        ))
+        .insert_header(("User-Agent", "test-agent/1.0"))
+        .peer_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 0))
+        .to_request();
+
+    let resp = test::call_service(&service, req).await;
+
+    assert!(
+        resp.status().is_client_error(),
+        "Expected client error for banned ip, got status {}",
+        resp.status()
+    );
+
+    let error: WrappedError = read_body_bencode(resp)
+        .await
+        .expect("Failed to decode error");
+    assert_eq!(error.failure_reason, "your ip address is banned");
+}
+
+#[sqlx::test(migrations = "../../backend/storage/migrations")]
+async fn test_metrics_require_api_key(pool: PgPool) {
+    let service = common::create_test_app(pool).await;
+
+    let req = test::TestRequest::get().uri("/metrics").to_request();
+    let resp = test::call_service(&service, req).await;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test(
+    fixtures("with_test_user"),
+    migrations = "../../backend/storage/migrations"
+)]
+async fn test_metrics_count_announce_errors(pool: PgPool) {
+    let service = common::create_test_app(pool).await;
+
+    // Note: No User-Agent header
+    let req = test::TestRequest::get()
+        .uri("/d2037c66dd3e13044e0d2f9b891c3837/announce?event=started")
+        .peer_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 0))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert!(resp.status().is_client_error());
+
+    let req = test::TestRequest::get()
+        .uri("/metrics")
+        .insert_header(("x-api-key", "amazing_api_key"))
+        .to_request();
+    let resp = test::call_service(&service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body = test::read_body(resp).await;
+    let body = String::from_utf8_lossy(&body);
+    assert!(body.contains(r#"arcadia_tracker_announces_total{event="started"} 1"#));
+    assert!(body.contains(r#"arcadia_tracker_announce_errors_total{error="no_user_agent"} 1"#));
+    assert!(body.contains(r#"arcadia_tracker_queue_depth{queue="peer_updates"} 0"#));
+}
+
+async fn announce_upload<S>(service: &S, uploaded: u64, user_agent: &str)
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let info_hash_bytes = [
+        0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+        0x00, 0x11, 0x22, 0x33, 0x44,
+    ];
+    let peer_id_encoded =
+        percent_encoding::percent_encode(&test_peer_id(), percent_encoding::NON_ALPHANUMERIC)
+            .to_string();
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/d2037c66dd3e13044e0d2f9b891c3837/announce?info_hash={}&peer_id={}&port=6969&uploaded={}&downloaded=0&left=0&compact=1",
+            url_encode_info_hash(&info_hash_bytes), peer_id_encoded, uploaded
+        ))
+        .insert_header(("User-Agent", user_agent))
+        .peer_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 0))
+        .to_request();
+
+    let resp = test::call_service(service, req).await;
+    assert!(resp.status().is_success());
+}
+
+async fn tracker_flag_queue_depth<S>(service: &S) -> String
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    metric(
+        service,
+        r#"arcadia_tracker_queue_depth{queue="tracker_flag_updates"}"#,
+    )
+    .await
+}
+
+async fn metric<S>(service: &S, name: &str) -> String
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let req = test::TestRequest::get()
+        .uri("/metrics")
+        .insert_header(("x-api-key", "amazing_api_key"))
+        .to_request();
+    let resp = test::call_service(service, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body = test::read_body(resp).await;
+    String::from_utf8_lossy(&body)
+        .lines()
+        .find_map(|line| line.strip_prefix(&format!("{name} ")).map(str::to_owned))
+        .unwrap_or_else(|| panic!("{name} is exported"))
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../../backend/storage/migrations"
+)]
+async fn test_metrics_count_peers_in_memory(pool: PgPool) {
+    let service = common::create_test_app(pool).await;
+    let peers = r#"arcadia_tracker_in_memory{kind="peers"}"#;
+
+    assert_eq!(metric(&service, peers).await, "0");
+
+    // the second announce updates the peer of the first one
+    announce_upload(&service, 0, "rtorrent/0.9.8").await;
+    announce_upload(&service, 0, "rtorrent/0.9.8").await;
+
+    assert_eq!(metric(&service, peers).await, "1");
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../../backend/storage/migrations"
+)]
+async fn test_announce_flags_peer_id_user_agent_mismatch(pool: PgPool) {
+    let service = common::create_test_app(pool).await;
+
+    // the peer_id is rtorrent's
+    announce_upload(&service, 0, "qBittorrent/4.6.2").await;
+
+    assert_eq!(tracker_flag_queue_depth(&service).await, "1");
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../../backend/storage/migrations"
+)]
+async fn test_announce_flags_upload_without_swarm_download(pool: PgPool) {
+    let service = common::create_test_app(pool).await;
+
+    announce_upload(&service, 0, "rtorrent/0.9.8").await;
+    assert_eq!(tracker_flag_queue_depth(&service).await, "0");
+
+    // 2 GiB uploaded to a swarm without any leecher
+    announce_upload(&service, 2 << 30, "rtorrent/0.9.8").await;
+
+    // upload_without_leechers and upload_exceeding_swarm_download
+    assert_eq!(tracker_flag_queue_depth(&service).await, "2");
+}
+
+#[sqlx::test(
+    fixtures(
+        "with_test_user",
+        "with_test_title_group",
+        "with_test_edition_group",
+        "with_test_torrent"
+    ),
+    migrations = "../../backend/storage/migrations"
+)]
+async fn test_announce_does_not_flag_small_seeder_uploads(pool: PgPool) {
+    let service = common::create_test_app(pool).await;
+
+    announce_upload(&service, 0, "rtorrent/0.9.8").await;
+    announce_upload(&service, 1 << 20, "rtorrent/0.9.8").await;
+
+    assert_eq!(tracker_flag_queue_depth(&service).await, "0");
+}